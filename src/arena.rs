@@ -0,0 +1,440 @@
+
+/*!
+This module provides the [`DataArena`] bump allocator and all it's associated
+functions, methods and items.
+
+A [`DataArena`] hands out the bytes of a [`DataSlice`] one allocation after another,
+each aligned for it's type, and only gives them all back at once with [`DataArena::reset`].
+Allocating only needs a shared refrence, so many allocations can be used at the same time.
+
+The arena owns (or borrows) the data structure it allocates in (any data structure
+that gives out a [`DataSlice`] can be used), and the allocations borrow the arena, so they never outlive it.
+
+The allocated values are never dropped, a value that needs to be dropped has to be
+dropped by hand (eg: with [`ptr::drop_in_place`](core::ptr::drop_in_place)) before the arena is reset.
+
+With the `allocator_api` feature a `&DataArena` is also an [`Allocator`](core::alloc::Allocator),
+so collections like `Vec` and `Box` can allocate in it, their memory being given back only once the arena is reset.
+
+A [`DoubleEnded`] allocates from both ends of the data toward the middle, so two kinds of allocations
+(eg: the ones that live long and the temporary ones) can share the same bytes, each end being reset on it's own.
+It gives out the offsets of the allocations instead of refrences, aligned relative to the start of the data.
+ */
+
+use crate::slice::DataSlice;
+use core::cell::{
+    Cell,
+    UnsafeCell,
+};
+use core::ops::DerefMut;
+use core::ptr::NonNull;
+
+/// The error given when an allocation does not fit in the remaining bytes of a [`DataArena`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArenaError {
+    /// The size of the allocation.
+    pub size: usize,
+    /// The alignment of the allocation.
+    pub align: usize,
+    /// The amount of bytes that were left in the arena.
+    pub remaining: usize,
+}
+
+impl core::error::Error for ArenaError {}
+impl core::fmt::Display for ArenaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Can not allocate `{size}` bytes aligned to `{align}` in an arena with `{remaining}` bytes left.",
+            size = self.size,
+            align = self.align,
+            remaining = self.remaining,
+        )
+    }
+}
+
+/// The usage of an arena, a heap or a pool, for planning how much memory they need.
+/// 
+/// Get's given by the `report` method of the [`DataArena`], the [`DataHeap`](crate::heap::DataHeap)
+/// and the [`DataPool`](crate::pool::DataPool).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArenaStats {
+    /// The amount of bytes allocated right now, bookkeeping and padding included.
+    pub allocated: usize,
+    /// The largest amount of bytes that were allocated at the same time.
+    pub peak: usize,
+    /// The amount of allocations right now.
+    pub allocations: usize,
+    /// The size of the largest allocation that would still fit.
+    pub largest_free: usize,
+}
+
+/// A bump allocator over the bytes of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DataArena<D: DerefMut<Target = DataSlice>> {
+    data: UnsafeCell<D>,
+    size: usize,
+    // The first byte of the data and the address of the arena when it was taken,
+    // as data that is stored inline moves together with the arena.
+    base: Cell<Option<(NonNull<u8>, *const ())>>,
+    pos: Cell<usize>,
+    peak: Cell<usize>,
+    allocations: Cell<usize>,
+}
+
+impl<D: DerefMut<Target = DataSlice>> DataArena<D> {
+    /// Constructs a new empty [`DataArena`] that allocates in the given data.
+    #[inline]
+    pub fn new(data: D) -> DataArena<D> {
+        DataArena {
+            size: data.size(),
+            data: UnsafeCell::new(data),
+            base: Cell::new(None),
+            pos: Cell::new(0),
+            peak: Cell::new(0),
+            allocations: Cell::new(0),
+        }
+    }
+
+    /// Get's a pointer to the first byte of the data.
+    fn base(&self) -> NonNull<u8> {
+        let at: *const () = core::ptr::from_ref(self).cast();
+
+        match self.base.get() {
+            Some((base, taken_at)) if taken_at == at => base,
+            _ => {
+                // SAFETY: The arena was not used since it was constructed or moved,
+                // and moving it needs every allocation (which borrows it) to be gone,
+                // so nothing else refers to the data.
+                // The pointer of a slice is never null.
+                let base: NonNull<u8> = unsafe { NonNull::new_unchecked((*self.data.get()).as_mut_ptr()) };
+                self.base.set(Some((base, at)));
+                base
+            },
+        }
+    }
+
+    /// Get's the amount of bytes the arena can allocate in.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.size
+    }
+
+    /// Get's the amount of bytes used so far, padding included.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.pos.get()
+    }
+
+    /// Get's the amount of bytes that are not used yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.size - self.pos.get()
+    }
+
+    /// Allocates `size` bytes aligned to `align` (which must be a power of two)
+    /// and gives back a pointer to them.
+    /// 
+    /// The bytes keep whatever they held before.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation does not fit in the remaining bytes
+    /// or if `align` is not a power of two.
+    pub fn alloc_bytes(&self, size: usize, align: usize) -> Result<NonNull<u8>, ArenaError> {
+        let err: ArenaError = ArenaError { size, align, remaining: self.remaining() };
+
+        if !align.is_power_of_two() {
+            return Err(err)
+        }
+
+        let base: NonNull<u8> = self.base();
+        let pos: usize = self.pos.get();
+        let start: usize = match pos.checked_add(crate::layout::padding_needed(base.addr().get().wrapping_add(pos), align)) {
+            Some(start) => start,
+            None => return Err(err),
+        };
+
+        match start.checked_add(size) {
+            Some(end) if end <= self.size => self.pos.set(end),
+            _ => return Err(err),
+        }
+
+        self.peak.set(self.peak.get().max(self.pos.get()));
+        self.allocations.set(self.allocations.get() + 1);
+
+        // SAFETY: The allocation is inside of the slice.
+        Ok(unsafe { base.add(start) })
+    }
+
+    /// Moves the value into the arena and gives back a mutable refrence to it.
+    /// 
+    /// The value is never dropped.
+    /// 
+    /// # ERRORS
+    /// Will return an error (dropping the value) if it does not fit in the remaining bytes.
+    // Every allocation gets it's own bytes, so the mutable refrences never overlap.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_value<T>(&self, value: T) -> Result<&mut T, ArenaError> {
+        let ptr: NonNull<T> = self.alloc_bytes(core::mem::size_of::<T>(), core::mem::align_of::<T>())?.cast::<T>();
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(ptr.as_ptr().cast::<u8>(), core::mem::size_of::<T>());
+
+        // SAFETY: The allocation is aligned, inside of the slice and not used by any other allocation.
+        unsafe {
+            ptr.write(value);
+            Ok(&mut *ptr.as_ptr())
+        }
+    }
+
+    /// Copies the values of the slice into the arena and gives back a mutable refrence to the copy.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the copy does not fit in the remaining bytes.
+    // Every allocation gets it's own bytes, so the mutable refrences never overlap.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, values: &[T]) -> Result<&mut [T], ArenaError> {
+        let size: usize = core::mem::size_of_val(values);
+        let ptr: NonNull<T> = self.alloc_bytes(size, core::mem::align_of::<T>())?.cast::<T>();
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(ptr.as_ptr().cast::<u8>(), size);
+
+        // SAFETY: The allocation is aligned, inside of the slice and not used by any other allocation.
+        unsafe {
+            ptr.as_ptr().copy_from_nonoverlapping(values.as_ptr(), values.len());
+            Ok(core::slice::from_raw_parts_mut(ptr.as_ptr(), values.len()))
+        }
+    }
+
+    /// Gives back all the allocated bytes, so they can be allocated again.
+    /// 
+    /// This takes a mutable refrence, so no allocation can still be in use.
+    /// The peak of the [`report`](DataArena::report) is kept.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pos.set(0);
+        self.allocations.set(0);
+    }
+
+    /// Get's the usage of the arena since it was constructed.
+    /// 
+    /// The largest free allocation is the amount of remaining bytes, ignoring any padding it might need.
+    #[inline]
+    pub fn report(&self) -> ArenaStats {
+        ArenaStats {
+            allocated: self.used(),
+            peak: self.peak.get(),
+            allocations: self.allocations.get(),
+            largest_free: self.remaining(),
+        }
+    }
+
+    /// Get's a mutable refrence to the underlying data.
+    /// 
+    /// This takes a mutable refrence, so no allocation can still be in use.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut DataSlice {
+        self.base.set(None);
+        self.data.get_mut()
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data.into_inner()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+// SAFETY: Every allocation gets it's own bytes of the slice, which stay valid
+// until the arena is reset, moved or dropped, and that needs every refrence to the arena to be gone.
+unsafe impl<D: DerefMut<Target = DataSlice>> core::alloc::Allocator for &DataArena<D> {
+    #[inline] fn allocate(&self, layout: core::alloc::Layout) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+        self.alloc_bytes(layout.size(), layout.align())
+            .map(#[inline] |ptr| NonNull::slice_from_raw_parts(ptr, layout.size()))
+            .map_err(#[inline] |_| core::alloc::AllocError)
+    }
+
+    // The bytes are only given back when the arena is reset.
+    #[inline] unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: core::alloc::Layout) {}
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for DataArena<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataArena")
+            .field("capacity", &self.capacity())
+            .field("used", &self.used())
+            .finish()
+    }
+}
+
+/// A region that allocates from both it's front and it's back toward the middle.
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DoubleEnded<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    front: usize,
+    back: usize,
+}
+
+impl<D: DerefMut<Target = DataSlice>> DoubleEnded<D> {
+    /// Constructs a new empty [`DoubleEnded`] region over the given data.
+    #[inline]
+    pub fn new(data: D) -> DoubleEnded<D> {
+        DoubleEnded { back: data.size(), data, front: 0 }
+    }
+
+    /// Get's the amount of bytes the region can allocate in.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Get's the offset the front allocations end at, which can be given to [`reset_front_to`](DoubleEnded::reset_front_to).
+    #[inline]
+    pub fn front(&self) -> usize {
+        self.front
+    }
+
+    /// Get's the offset the back allocations start at, which can be given to [`reset_back_to`](DoubleEnded::reset_back_to).
+    #[inline]
+    pub fn back(&self) -> usize {
+        self.back
+    }
+
+    /// Get's the amount of free bytes between the front and the back allocations.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.back - self.front
+    }
+
+    /// Get's the offset an allocation at the front would start at and the offset it would end at,
+    /// or [None] if it would overlap the back allocations.
+    #[inline]
+    fn fit_front(&self, size: usize, align: usize) -> Option<(usize, usize)> {
+        let start: usize = crate::layout::next_aligned(self.front, align)?;
+
+        match start.checked_add(size) {
+            Some(end) if end <= self.back => Some((start, end)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn fit_back(&self, size: usize, align: usize) -> Option<usize> {
+        let start: usize = self.back.checked_sub(size)?;
+        let start: usize = start - start % align;
+
+        if start >= self.front { Some(start) } else { None }
+    }
+
+    /// Checks weather an allocation of `size` bytes aligned to `align` fits at the front,
+    /// without overlapping the back allocations.
+    #[inline]
+    pub fn can_alloc_front(&self, size: usize, align: usize) -> bool {
+        align.is_power_of_two() && self.fit_front(size, align).is_some()
+    }
+
+    /// Checks weather an allocation of `size` bytes aligned to `align` fits at the back,
+    /// without overlapping the front allocations.
+    #[inline]
+    pub fn can_alloc_back(&self, size: usize, align: usize) -> bool {
+        align.is_power_of_two() && self.fit_back(size, align).is_some()
+    }
+
+    /// Allocates `size` bytes aligned to `align` (which must be a power of two) right after
+    /// the front allocations and gives back their offset.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation would overlap the back allocations
+    /// or if `align` is not a power of two.
+    pub fn alloc_front(&mut self, size: usize, align: usize) -> Result<usize, ArenaError> {
+        let err: ArenaError = ArenaError { size, align, remaining: self.remaining() };
+
+        if !align.is_power_of_two() {
+            return Err(err)
+        }
+
+        let (start, end): (usize, usize) = self.fit_front(size, align).ok_or(err)?;
+        self.front = end;
+        Ok(start)
+    }
+
+    /// Allocates `size` bytes aligned to `align` (which must be a power of two) right before
+    /// the back allocations and gives back their offset.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation would overlap the front allocations
+    /// or if `align` is not a power of two.
+    pub fn alloc_back(&mut self, size: usize, align: usize) -> Result<usize, ArenaError> {
+        let err: ArenaError = ArenaError { size, align, remaining: self.remaining() };
+
+        if !align.is_power_of_two() {
+            return Err(err)
+        }
+
+        let start: usize = self.fit_back(size, align).ok_or(err)?;
+        self.back = start;
+        Ok(start)
+    }
+
+    /// Gives back the front allocations made after the front was at the given offset.
+    /// 
+    /// Does nothing if the offset is after the current front.
+    #[inline]
+    pub fn reset_front_to(&mut self, front: usize) {
+        self.front = self.front.min(front);
+    }
+
+    /// Gives back the back allocations made after the back was at the given offset.
+    /// 
+    /// Does nothing if the offset is before the current back.
+    #[inline]
+    pub fn reset_back_to(&mut self, back: usize) {
+        self.back = self.back.max(back).min(self.data.size());
+    }
+
+    /// Gives back all the front allocations.
+    #[inline]
+    pub fn reset_front(&mut self) {
+        self.front = 0;
+    }
+
+    /// Gives back all the back allocations.
+    #[inline]
+    pub fn reset_back(&mut self) {
+        self.back = self.data.size();
+    }
+
+    /// Get's a refrence to the underlying data.
+    #[inline]
+    pub fn get_ref(&self) -> &DataSlice {
+        &self.data
+    }
+
+    /// Get's a mutable refrence to the underlying data, so the allocations can be written.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut DataSlice {
+        &mut self.data
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for DoubleEnded<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoubleEnded")
+            .field("capacity", &self.capacity())
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
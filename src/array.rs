@@ -90,6 +90,28 @@ impl<const SIZE: usize> DataArray<SIZE> {
         Ok(data)
     }
 
+    /// Constructs a new [`DataArray`] structure from the given hex text, two digits per byte.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the text is not valid hex or if it does not decode into exactly `SIZE` bytes.
+    pub const fn from_hex(hex: &str) -> Result<Self, crate::hex::HexError> {
+        let mut data: DataArray<SIZE> = DataArray::zeroed();
+
+        match crate::hex::decode_into(hex, &mut data.inner) {
+            Ok(()) => Ok(data),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Constructs a new [`DataArray`] structure filled with random bytes from the given random number generator.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> DataArray<SIZE> {
+        let mut data: DataArray<SIZE> = DataArray::zeroed();
+        rng.fill_bytes(&mut data.inner);
+        data
+    }
+
     /// Clones the entire chunk of data.
     /// 
     /// # SAFETY
@@ -120,7 +142,7 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
     #[inline]
-    pub const unsafe fn write<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<(), (ManuallyDrop<T>, idx::IdxError)> {
+    pub const unsafe fn write<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<(), (ManuallyDrop<T>, idx::TypedIdxError)> {
         self.deref_mut().write(idx, value)
     }
 
@@ -209,7 +231,7 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// The pointer is guaranteed to ne non-null.
     // Not using NonNull is intentional
     #[inline]
-    pub const fn read<T: Sized>(&self, idx: usize) -> Result<*const T, idx::IdxError> {
+    pub const fn read<T: Sized>(&self, idx: usize) -> Result<*const T, idx::TypedIdxError> {
         self.deref().read(idx)
     }
 
@@ -230,7 +252,7 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// The pointer is guaranteed to ne non-null.
     // Not using NonNull is intentional
     #[inline]
-    pub const fn read_mut<T: Sized>(&mut self, idx: usize) -> Result<*mut T, idx::IdxError> {
+    pub const fn read_mut<T: Sized>(&mut self, idx: usize) -> Result<*mut T, idx::TypedIdxError> {
         self.deref_mut().read_mut(idx)
     }
 
@@ -246,6 +268,40 @@ impl<const SIZE: usize> DataArray<SIZE> {
         self.deref_mut().read_mut_unchecked(idx)
     }
 
+    /// Get's a pointer to the first byte of the data structure.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.deref().as_ptr()
+    }
+
+    /// Get's a mutable pointer to the first byte of the data structure.
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.deref_mut().as_mut_ptr()
+    }
+
+    /// Get's the range of pointers the data structure spans,
+    /// the end pointer being one past the last byte.
+    #[inline]
+    pub const fn ptr_range(&self) -> core::ops::Range<*const u8> {
+        self.deref().ptr_range()
+    }
+
+    /// Returns a [`NonNull`](core::ptr::NonNull) pointer to the specified data region.
+    /// 
+    /// Since the pointer comes from a shared refrence it must NOT be written through,
+    /// use [read_mut_nonnull](DataArray::read_mut_nonnull) for that.
+    #[inline]
+    pub const fn read_nonnull<T: Sized>(&self, idx: usize) -> Result<core::ptr::NonNull<T>, idx::TypedIdxError> {
+        self.deref().read_nonnull(idx)
+    }
+
+    /// Returns a mutable [`NonNull`](core::ptr::NonNull) pointer to the specified data region.
+    #[inline]
+    pub const fn read_mut_nonnull<T: Sized>(&mut self, idx: usize) -> Result<core::ptr::NonNull<T>, idx::TypedIdxError> {
+        self.deref_mut().read_mut_nonnull(idx)
+    }
+
     /// Returns a pointer to the specified data region with the provided metadata.
     /// 
     /// If you know T is sized use [read_unchecked](DataSlice::read_unchecked) instead.
@@ -253,7 +309,6 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     #[inline]
     pub const unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T
     {
@@ -267,7 +322,6 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     #[inline]
     pub const unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T
     {
@@ -283,7 +337,7 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// ownership and borrowing rules and guarantees.
     /// - Make sure the data gotten from inside is a valid T
     #[inline]
-    pub const unsafe fn take<T: Sized>(&self, idx: usize) -> Result<T, idx::IdxError> {
+    pub const unsafe fn take<T: Sized>(&self, idx: usize) -> Result<T, idx::TypedIdxError> {
         self.deref().take(idx)
     }
 
@@ -294,10 +348,73 @@ impl<const SIZE: usize> DataArray<SIZE> {
     /// ownership and borrowing rules and guarantees.
     /// - Make sure the data gotten from inside is a valid T
     #[inline]
-    pub const unsafe fn replace<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<T, idx::IdxError> {
+    pub const unsafe fn replace<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<T, idx::TypedIdxError> {
         self.deref_mut().replace(idx, value)
     }
 
+    /// Takes the value from the specified region and writes a new value in it's palce.
+    /// 
+    /// The old bytes are copied into `out` and the new value is written
+    /// in their place, the size of the region beeing the size of the given value.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [ManuallyDrop])
+    /// - Make sure `out` is valid for writes of as many bytes as the given value has.
+    #[inline]
+    pub const unsafe fn replace_unsized<T: ?Sized>(&mut self, idx: usize, value: *const ManuallyDrop<T>, out: *mut T) -> Result<(), idx::IdxError> {
+        self.deref_mut().replace_unsized(idx, value, out)
+    }
+
+    /// Takes the value from the specified region and writes a new value in it's palce.
+    /// 
+    /// The old bytes are copied into `out` and the new value is written
+    /// in their place, the size of the region beeing the size of the given value.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [ManuallyDrop])
+    /// - Make sure `out` is valid for writes of as many bytes as the given value has.
+    /// - Make sure data isn't taken from outside the data structure.
+    #[inline]
+    pub const unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const ManuallyDrop<T>, out: *mut T) {
+        self.deref_mut().replace_unsized_unchecked(idx, value, out)
+    }
+
+    /// Get's the nibble (4 bits) at the given nibble index.
+    /// 
+    /// Go to [DataSlice::get_nibble] for how the nibbles are ordered.
+    /// 
+    /// Returns [None] if the index is out of bounds.
+    #[inline]
+    pub const fn get_nibble(&self, idx: usize) -> Option<u8> {
+        self.deref().get_nibble(idx)
+    }
+
+    /// Set's the nibble (4 bits) at the given nibble index.
+    /// 
+    /// Only the lowest 4 bits of `value` are used.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is out of bounds,
+    /// the index and sizes of the error are in nibbles.
+    #[inline]
+    pub const fn set_nibble(&mut self, idx: usize, value: u8) -> Result<(), idx::IdxError> {
+        self.deref_mut().set_nibble(idx, value)
+    }
+
     #[inline]
     /// Get's a subslice of the data structure in a const context.
     pub const fn get_const(&self, start: core::ops::Bound<usize>, end: core::ops::Bound<usize>) -> Option<&DataSlice> {
@@ -329,6 +446,14 @@ use core::convert::TryFrom;
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DiferentSizesError<const SIZE: usize> { gotten_size: usize }
 
+impl<const SIZE: usize> DiferentSizesError<SIZE> {
+    /// Get's the size of the data that was given to the conversion.
+    #[inline]
+    pub const fn gotten_size(&self) -> usize {
+        self.gotten_size
+    }
+}
+
 impl<const SIZE: usize> core::error::Error for DiferentSizesError<SIZE> {}
 impl<const SIZE: usize> core::fmt::Display for DiferentSizesError<SIZE> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -395,12 +520,57 @@ impl<const SIZE: usize> Default for DataArray<SIZE> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<const SIZE: usize> zeroize::Zeroize for DataArray<SIZE> {
+    #[inline] fn zeroize(&mut self) {
+        self.secure_erase()
+    }
+}
+
 impl<const SIZE: usize> core::fmt::Debug for DataArray<SIZE> {
     #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         <crate::slice::DataSlice as core::fmt::Debug>::fmt(self, f)
     }
 }
 
+impl<const SIZE: usize> core::fmt::Display for DataArray<SIZE> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+impl<const SIZE: usize> core::fmt::LowerHex for DataArray<SIZE> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+impl<const SIZE: usize> core::fmt::UpperHex for DataArray<SIZE> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::UpperHex>::fmt(self, f)
+    }
+}
+
+impl<const SIZE: usize> core::fmt::Binary for DataArray<SIZE> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Binary>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<const SIZE: usize> ufmt::uDebug for DataArray<SIZE> {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDebug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<const SIZE: usize> ufmt::uDisplay for DataArray<SIZE> {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
 impl<'data, const SIZE: usize> IntoIterator for &'data DataArray<SIZE> {
     type Item = u8;
     type IntoIter = core::iter::Copied<core::slice::Iter<'data, u8>>;
@@ -511,12 +681,12 @@ unsafe impl<const SIZE: usize> crate::RawDataStructure for DataArray<SIZE> {
 
     fn read_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
         if match idx.checked_add(size) {
-            Some(size) => size < self.size(),
+            Some(size) => size <= self.size(),
             None => false,
         } {
             Ok(())
         } else {
-            Err(idx::IdxError { idx, data_size: self.size(), type_size: size })
+            Err(idx::IdxError::new(idx, self.size(), size))
         }
     }
 
@@ -526,24 +696,22 @@ unsafe impl<const SIZE: usize> crate::RawDataStructure for DataArray<SIZE> {
     }
 
     unsafe fn clone_from_unchecked(&mut self, data: &Self) {
-        for (idx, byte) in self.iter_mut().enumerate() {
-            *byte = data.inner[idx]
-        }
+        <DataSlice as crate::RawDataStructure>::clone_from_unchecked(self.deref_mut(), data.deref())
     }
     
     #[inline]
     unsafe fn write_zeroes_unchecked(&mut self, idx: usize, size: usize) {
-        self.deref_mut().write_zeroes_unchecked(idx, size)
+        <DataSlice as crate::RawDataStructure>::write_zeroes_unchecked(self.deref_mut(), idx, size)
     }
 
     #[inline]
     unsafe fn write_ones_unchecked(&mut self, idx: usize, size: usize) {
-        self.deref_mut().write_ones_unchecked(idx, size)
+        <DataSlice as crate::RawDataStructure>::write_ones_unchecked(self.deref_mut(), idx, size)
     }
 
     #[inline]
     unsafe fn write_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) {
-        self.deref_mut().write_unsized_unchecked(idx, value)
+        <DataSlice as crate::RawDataStructure>::write_unsized_unchecked(self.deref_mut(), idx, value)
     }
 
     #[inline]
@@ -553,7 +721,7 @@ unsafe impl<const SIZE: usize> crate::RawDataStructure for DataArray<SIZE> {
 
     #[inline]
     unsafe fn read_mut_unchecked<T: Sized>(&mut self, idx: usize) -> *mut T {
-        self.deref_mut().read_mut_unchecked(idx)
+        <DataSlice as crate::RawDataStructure>::read_mut_unchecked(self.deref_mut(), idx)
     }
 
     #[inline]
@@ -570,7 +738,12 @@ unsafe impl<const SIZE: usize> crate::RawDataStructure for DataArray<SIZE> {
 
     #[inline]
     unsafe fn take_unchecked<T: Sized>(&self, idx: usize) -> T {
-        self.deref().take_unchecked(idx)
+        <DataSlice as crate::RawDataStructure>::take_unchecked(self.deref(), idx)
+    }
+
+    #[inline]
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        <DataSlice as crate::RawDataStructure>::replace_unsized_unchecked(self.deref_mut(), idx, value, out)
     }
 
     type DataByte = u8;
@@ -582,7 +755,7 @@ unsafe impl<const SIZE: usize> crate::RawDataStructure for DataArray<SIZE> {
 
     #[inline]
     unsafe fn set_at_idx(&mut self, idx: usize, byte: u8) {
-        self.inner[idx] = byte;
+        <DataSlice as crate::RawDataStructure>::set_at_idx(self.deref_mut(), idx, byte)
     }
 }
 
@@ -0,0 +1,243 @@
+
+/*!
+This module provides the [`BitSliceView`] and all it's associated
+functions, methods and items.
+
+A [`BitSliceView`] is a [`DataSlice`] looked at as a list of bits, fit for flag tables
+and occupancy bitmaps, it can be gotten with [`DataSlice::bits`] and [`DataSlice::bits_mut`].
+
+Bit `i` of the view is the bit `i % 8` (counting from the least significant bit)
+of the byte `i / 8` of the slice.
+ */
+
+use crate::{
+    idx,
+    slice::DataSlice,
+};
+
+/// A [`DataSlice`] indexed by bits instead of bytes.
+/// 
+/// This struct is just a [`DataSlice`] underneeth the hood.
+#[must_use]
+#[repr(transparent)]
+pub struct BitSliceView {
+    inner: DataSlice,
+}
+
+impl BitSliceView {
+    /// Turns a [`&DataSlice`](DataSlice) into a [`&BitSliceView`](BitSliceView).
+    #[inline]
+    pub const fn from_data_slice(data: &DataSlice) -> &BitSliceView {
+        unsafe {
+            // SAFETY: The underlying data is the same for both a DataSlice and a BitSliceView
+            core::mem::transmute(data)
+        }
+    }
+
+    /// Turns a [`&mut DataSlice`](DataSlice) into a [`&mut BitSliceView`](BitSliceView).
+    #[inline]
+    pub const fn from_data_slice_mut(data: &mut DataSlice) -> &mut BitSliceView {
+        unsafe {
+            // SAFETY: The underlying data is the same for both a DataSlice and a BitSliceView
+            core::mem::transmute(data)
+        }
+    }
+
+    /// Get's the underlying [`DataSlice`].
+    #[inline]
+    pub const fn as_data_slice(&self) -> &DataSlice {
+        &self.inner
+    }
+
+    /// Get's the underlying mutable [`DataSlice`].
+    #[inline]
+    pub const fn as_data_slice_mut(&mut self) -> &mut DataSlice {
+        &mut self.inner
+    }
+
+    /// Get's the amount of bits in the view.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.inner.size() * 8
+    }
+
+    /// Checks weather the view has no bits.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.size() == 0
+    }
+
+    /// Get's the bit at the given index.
+    /// 
+    /// Returns [None] if the index is out of bounds.
+    #[inline]
+    pub const fn get_bit(&self, idx: usize) -> Option<bool> {
+        if idx >= self.len() {
+            return None
+        }
+
+        Some(self.inner.inner[idx / 8] & (1 << (idx % 8)) != 0)
+    }
+
+    /// Set's the bit at the given index.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is out of bounds,
+    /// the index and sizes of the error are in bits.
+    #[inline]
+    pub const fn set_bit(&mut self, idx: usize, bit: bool) -> Result<(), idx::IdxError> {
+        if idx >= self.len() {
+            return Err(idx::IdxError::new(idx, self.len(), 1))
+        }
+
+        if bit {
+            self.inner.inner[idx / 8] |= 1 << (idx % 8);
+        } else {
+            self.inner.inner[idx / 8] &= !(1 << (idx % 8));
+        }
+
+        Ok(())
+    }
+
+    /// Flips the bit at the given index.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is out of bounds,
+    /// the index and sizes of the error are in bits.
+    #[inline]
+    pub const fn toggle_bit(&mut self, idx: usize) -> Result<(), idx::IdxError> {
+        if idx >= self.len() {
+            return Err(idx::IdxError::new(idx, self.len(), 1))
+        }
+
+        self.inner.inner[idx / 8] ^= 1 << (idx % 8);
+        Ok(())
+    }
+
+    /// Set's all the bits in the given range.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the range gets out of bounds,
+    /// the index and sizes of the error are in bits.
+    pub fn set_range(&mut self, range: impl idx::Idx, bit: bool) -> Result<(), idx::IdxError> {
+        let core::ops::Range { start, end } = range.resolve(self.len())?;
+
+        let mut at: usize = start;
+
+        // Leading bits until the first whole byte.
+        while at < end && !at.is_multiple_of(8) {
+            let _ = self.set_bit(at, bit);
+            at += 1;
+        }
+
+        // Whole bytes.
+        while at + 8 <= end {
+            self.inner.inner[at / 8] = if bit { 0xFF } else { 0x00 };
+            at += 8;
+        }
+
+        // Trailing bits.
+        while at < end {
+            let _ = self.set_bit(at, bit);
+            at += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Counts the bits set to `1`.
+    pub const fn count_ones(&self) -> usize {
+        let mut count: usize = 0;
+        let mut at: usize = 0;
+
+        while at < self.inner.size() {
+            count += self.inner.inner[at].count_ones() as usize;
+            at += 1;
+        }
+
+        count
+    }
+
+    /// Counts the bits set to `0`.
+    #[inline]
+    pub const fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    /// Counts the bits set to `1` strictly before the given index.
+    /// 
+    /// If the index is past the end of the view all the bits are counted.
+    pub const fn rank(&self, idx: usize) -> usize {
+        let idx: usize = if idx < self.len() { idx } else { self.len() };
+        let mut count: usize = 0;
+        let mut at: usize = 0;
+
+        while at < idx / 8 {
+            count += self.inner.inner[at].count_ones() as usize;
+            at += 1;
+        }
+
+        if !idx.is_multiple_of(8) {
+            count += (self.inner.inner[idx / 8] & ((1u16 << (idx % 8)) - 1) as u8).count_ones() as usize;
+        }
+
+        count
+    }
+
+    /// Finds the index of the `nth` bit set to `1` (counting from `0`).
+    /// 
+    /// Returns [None] if there are not enough bits set to `1`.
+    pub const fn select(&self, nth: usize) -> Option<usize> {
+        let mut left: usize = nth;
+        let mut at: usize = 0;
+
+        while at < self.inner.size() {
+            let byte: u8 = self.inner.inner[at];
+            let ones: usize = byte.count_ones() as usize;
+
+            if left < ones {
+                let mut bit: usize = 0;
+                while bit < 8 {
+                    if byte & (1 << bit) != 0 {
+                        if left == 0 {
+                            return Some(at * 8 + bit);
+                        }
+                        left -= 1;
+                    }
+                    bit += 1;
+                }
+            }
+
+            left -= ones;
+            at += 1;
+        }
+
+        None
+    }
+
+    /// Get's an iterator over all the bits of the view.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len()).map(
+            #[inline] move |idx| self.inner.inner[idx / 8] & (1 << (idx % 8)) != 0
+        )
+    }
+
+    /// Get's an iterator over the indecies of all the bits set to `1`.
+    #[inline]
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(
+            #[inline] move |&idx| self.inner.inner[idx / 8] & (1 << (idx % 8)) != 0
+        )
+    }
+}
+
+impl core::fmt::Debug for BitSliceView {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+
+        Ok(())
+    }
+}
@@ -78,6 +78,15 @@ impl DataBoxed {
         DataBoxed { inner: Box::new([]) }
     }
 
+    /// Takes out the underlying boxed slice, without stopping the tracking of it's bytes.
+    #[inline]
+    #[cfg(not(feature = "allocator_api"))]
+    pub(crate) fn into_inner(self) -> Box<[u8]> {
+        let this: core::mem::ManuallyDrop<DataBoxed> = core::mem::ManuallyDrop::new(self);
+        // SAFETY: The box is only read once and `this` is never dropped.
+        unsafe { core::ptr::read(&this.inner) }
+    }
+
     /// Constructs a new [DataBoxed] structure without touching the underling data.
     /// 
     /// Depeanding on if you have the `allocator_api` feature this will:
@@ -97,12 +106,17 @@ impl DataBoxed {
         return DataBoxed::uninit_in(size, Global);
 
         #[cfg(not(feature = "allocator_api"))]
-        return Ok(
-            DataBoxed {
+        {
+            let data: DataBoxed = DataBoxed {
                 // SAFETY: The data is ment to be uninitialized.
                 inner: unsafe { Box::new_uninit_slice(size).assume_init() }
-            }
-        );
+            };
+
+            #[cfg(feature = "init-tracking")]
+            crate::init::track(&data);
+
+            Ok(data)
+        }
     }
 
     #[inline]
@@ -135,8 +149,79 @@ impl DataBoxed {
     pub fn filled(size: usize, byte: u8) -> Result<DataBoxed, TryReserveError> {
         let mut data = DataBoxed::uninit(size)?;
         data.inner.fill(byte);
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::forget(&data);
+
+        Ok(data)
+    }
+
+    /// Constructs a new [DataBoxed] structure from the given hex text, two digits per byte.
+    /// 
+    /// # ERRORS
+    /// Will return an [`Hex`](crate::Error::Hex) error if the text is not valid hex,
+    /// or an [`Alloc`](crate::Error::Alloc) error if the allocation fails.
+    pub fn from_hex(hex: &str) -> Result<DataBoxed, crate::Error> {
+        if !hex.len().is_multiple_of(2) {
+            return Err(crate::hex::HexError::OddLength { len: hex.len() }.into())
+        }
+
+        let mut data: DataBoxed = DataBoxed::uninit(hex.len() / 2)?;
+        crate::hex::decode_into(hex, &mut data.inner)?;
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::forget(&data);
+
+        Ok(data)
+    }
+
+    /// Constructs a new [DataBoxed] structure filled with random bytes from the
+    /// entropy source of the system (the OS or the hardware).
+    /// 
+    /// # ERRORS
+    /// Will return an [`Random`](crate::Error::Random) error if the entropy source could not be used,
+    /// or an [`Alloc`](crate::Error::Alloc) error if the allocation fails.
+    #[cfg(feature = "getrandom")]
+    pub fn random(size: usize) -> Result<DataBoxed, crate::Error> {
+        let mut data: DataBoxed = DataBoxed::uninit(size)?;
+        getrandom::fill(&mut data.inner)?;
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::forget(&data);
+
+        Ok(data)
+    }
+
+    /// Constructs a new [DataBoxed] structure filled with random bytes from the given random number generator.
+    /// 
+    /// Depeanding on if you have the `allocator_api` feature this will:
+    /// - (no) Panic if an allocation fails, never returns an error.
+    /// - (yes) Returns an error if the allocation fails.
+    #[cfg(feature = "rand")]
+    pub fn random_with<R: rand::Rng + ?Sized>(size: usize, rng: &mut R) -> Result<DataBoxed, TryReserveError> {
+        let mut data: DataBoxed = DataBoxed::uninit(size)?;
+        rng.fill_bytes(&mut data.inner);
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::forget(&data);
+
         Ok(data)
     }
+
+    /// Constructs a new [DataBoxed] structure without touching the underling data,
+    /// allocated with inaccessible guard pages right before and after it,
+    /// so any access out of it's bounds faults right away.
+    /// 
+    /// This is made for debugging, go to the [`guard_pages`](crate::guard_pages)
+    /// module for the details.
+    /// 
+    /// Returns an error if the allocation fails.
+    #[inline]
+    #[cfg(all(feature = "guard-pages", any(unix, windows)))]
+    #[cfg(feature = "allocator_api")]
+    pub fn uninit_guarded(size: usize) -> Result<DataBoxed<crate::guard_pages::GuardPages>, TryReserveError> {
+        DataBoxed::uninit_in(size, crate::guard_pages::GuardPages)
+    }
 }
 
 #[cfg(feature = "allocator_api")]
@@ -147,6 +232,14 @@ impl<A: Allocator> DataBoxed<A> {
         DataBoxed { inner: Box::new_in([], alloc) }
     }
 
+    /// Takes out the underlying boxed slice, without stopping the tracking of it's bytes.
+    #[inline]
+    pub(crate) fn into_inner(self) -> Box<[u8], A> {
+        let this: core::mem::ManuallyDrop<DataBoxed<A>> = core::mem::ManuallyDrop::new(self);
+        // SAFETY: The box is only read once and `this` is never dropped.
+        unsafe { core::ptr::read(&this.inner) }
+    }
+
     /// Constructs a new [DataBoxed] structure without touching the underling data.
     /// 
     /// This method is safe because reading in it'self from the data structure is
@@ -158,12 +251,17 @@ impl<A: Allocator> DataBoxed<A> {
     /// is stable and can be cosntructed from an [AllocError] (in the current version)
     pub fn uninit_in(size: usize, alloc: A) -> Result<DataBoxed<A>, TryReserveError> {
         match Box::try_new_uninit_slice_in(size, alloc) {
-            Ok(data) => Ok(
-                DataBoxed {
+            Ok(data) => {
+                let data: DataBoxed<A> = DataBoxed {
                     // SAFETY: The data is ment to be uninitialized.
                     inner: unsafe { data.assume_init() }
-                }
-            ),
+                };
+
+                #[cfg(feature = "init-tracking")]
+                crate::init::track(&data);
+
+                Ok(data)
+            },
             Err(AllocError) => Err(
                 match Layout::array::<u8>(size) {
                     Ok(layout) => TryReserveErrorKind::AllocError {
@@ -182,12 +280,17 @@ impl<A: Allocator> DataBoxed<A> {
     /// is stable and can be cosntructed from an [AllocError] (in the current version)
     pub fn zeroed_in(size: usize, alloc: A) -> Result<DataBoxed<A>, TryReserveError> {
         match Box::try_new_zeroed_slice_in(size, alloc) {
-            Ok(data) => Ok(
-                DataBoxed {
+            Ok(data) => {
+                let data: DataBoxed<A> = DataBoxed {
                     // SAFETY: The data is ment to be zeroed.
                     inner: unsafe { data.assume_init() }
-                }
-            ),
+                };
+
+                #[cfg(feature = "init-tracking")]
+                crate::init::forget(&data);
+
+                Ok(data)
+            },
             Err(AllocError) => Err(
                 match Layout::array::<u8>(size) {
                     Ok(layout) => TryReserveErrorKind::AllocError {
@@ -207,6 +310,10 @@ impl<A: Allocator> DataBoxed<A> {
     pub fn filled_in(size: usize, byte: u8, alloc: A) -> Result<DataBoxed<A>, TryReserveError> {
         let mut data = DataBoxed::uninit_in(size, alloc)?;
         data.inner.fill(byte);
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::forget(&data);
+
         Ok(data)
     }
 
@@ -306,6 +413,26 @@ impl DataBoxed {
     }
 }
 
+/// Stops tracking the bytes, so the memory can be reused by other allocations without
+/// them being reported as never written.
+#[cfg(feature = "init-tracking")]
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> Drop for DataBoxed<A> {
+    #[inline] fn drop(&mut self) {
+        crate::init::forget(self);
+    }
+}
+
+/// Stops tracking the bytes, so the memory can be reused by other allocations without
+/// them being reported as never written.
+#[cfg(feature = "init-tracking")]
+#[cfg(not(feature = "allocator_api"))]
+impl Drop for DataBoxed {
+    #[inline] fn drop(&mut self) {
+        crate::init::forget(self);
+    }
+}
+
 #[cfg(feature = "allocator_api")]
 impl<A: Allocator> core::ops::Deref for DataBoxed<A> {
     type Target = crate::slice::DataSlice;
@@ -352,6 +479,90 @@ impl core::fmt::Debug for DataBoxed {
     }
 }
 
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::Display for DataBoxed<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::Display for DataBoxed {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::LowerHex for DataBoxed<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::LowerHex for DataBoxed {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::UpperHex for DataBoxed<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::UpperHex>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::UpperHex for DataBoxed {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::UpperHex>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::Binary for DataBoxed<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Binary>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::Binary for DataBoxed {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Binary>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", feature = "allocator_api"))]
+impl<A: Allocator> ufmt::uDebug for DataBoxed<A> {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDebug>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", not(feature = "allocator_api")))]
+impl ufmt::uDebug for DataBoxed {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDebug>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", feature = "allocator_api"))]
+impl<A: Allocator> ufmt::uDisplay for DataBoxed<A> {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", not(feature = "allocator_api")))]
+impl ufmt::uDisplay for DataBoxed {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
 #[cfg(feature = "allocator_api")]
 impl<A: Allocator + Default> Default for DataBoxed<A> {
     #[inline] fn default() -> Self { DataBoxed::empty_in(A::default()) }
@@ -362,8 +573,46 @@ impl Default for DataBoxed {
     #[inline] fn default() -> Self { DataBoxed::empty() }
 }
 
+#[cfg(feature = "zeroize")]
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> zeroize::Zeroize for DataBoxed<A> {
+    #[inline] fn zeroize(&mut self) {
+        self.secure_erase()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg(not(feature = "allocator_api"))]
+impl zeroize::Zeroize for DataBoxed {
+    #[inline] fn zeroize(&mut self) {
+        self.secure_erase()
+    }
+}
+
 #[cfg(feature = "std")]
 impl<'mutex> DerefDataSlice for crate::std::sync::MutexGuard<'mutex, crate::slice::DataSlice> {}
+#[cfg(feature = "std")]
+impl<'lock> DerefDataSlice for crate::std::sync::RwLockWriteGuard<'lock, crate::slice::DataSlice> {}
+
+impl<'cell> DerefDataSlice for core::cell::RefMut<'cell, crate::slice::DataSlice> {}
+
+#[cfg(feature = "parking_lot")]
+impl<'mutex> DerefDataSlice for parking_lot::MutexGuard<'mutex, crate::slice::DataSlice> {}
+#[cfg(feature = "parking_lot")]
+impl<'mutex> DerefDataSlice for parking_lot::FairMutexGuard<'mutex, crate::slice::DataSlice> {}
+#[cfg(feature = "parking_lot")]
+impl<'lock> DerefDataSlice for parking_lot::RwLockWriteGuard<'lock, crate::slice::DataSlice> {}
+
+impl<D: core::ops::DerefMut<Target = crate::slice::DataSlice>> DerefDataSlice for crate::guarded::GuardedData<D> {}
+
+#[cfg(all(feature = "shm", any(unix, windows)))]
+impl DerefDataSlice for crate::shm::DataShm {}
+
+#[cfg(all(feature = "guard-pages", any(unix, windows)))]
+impl DerefDataSlice for crate::guard_pages::GuardPagedData {}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl DerefDataSlice for crate::wasm::DataWasm {}
 
 trait DerefDataSlice: core::ops::DerefMut<Target = crate::slice::DataSlice> {}
 
@@ -380,6 +629,7 @@ mod alloc_api_impl {
 
     impl<A: Allocator> DerefDataSlice for DataBoxed<A> {}
     impl<A: Allocator> DerefDataSlice for Box<DataSlice, A> {}
+    impl<A: Allocator> DerefDataSlice for crate::vec::DataVec<A> {}
 }
 
 #[cfg(not(feature = "allocator_api"))]
@@ -390,6 +640,7 @@ mod alloc_api_impl {
 
     impl DerefDataSlice for DataBoxed {}
     impl DerefDataSlice for Box<DataSlice> {}
+    impl DerefDataSlice for crate::vec::DataVec {}
 }
 
 unsafe impl<D: DerefDataSlice> crate::RawDataStructure for D {
@@ -428,11 +679,13 @@ unsafe impl<D: DerefDataSlice> crate::RawDataStructure for D {
         <crate::slice::DataSlice as crate::RawDataStructure>::read_mut_unchecked(self, idx)
     }
 
+    #[cfg(feature = "ptr_metadata")]
     #[inline]
     unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T {
         <crate::slice::DataSlice as crate::RawDataStructure>::read_unsized_unchecked(self, idx, meta)
     }
 
+    #[cfg(feature = "ptr_metadata")]
     #[inline]
     unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T {
         <crate::slice::DataSlice as crate::RawDataStructure>::read_unsized_mut_unchecked(self, idx, meta)
@@ -443,6 +696,11 @@ unsafe impl<D: DerefDataSlice> crate::RawDataStructure for D {
         <crate::slice::DataSlice as crate::RawDataStructure>::take_unchecked(self, idx)
     }
 
+    #[inline]
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        <crate::slice::DataSlice as crate::RawDataStructure>::replace_unsized_unchecked(self, idx, value, out)
+    }
+
     #[inline]
     unsafe fn clone_from_unchecked(&mut self, data: &Self) {
         <crate::slice::DataSlice as crate::RawDataStructure>::clone_from_unchecked(self, data)
@@ -519,12 +777,12 @@ impl crate::DataStructureAllocConstructor for Box<crate::slice::DataSlice> {
 
     #[inline]
     fn uninit(size: usize) -> Result<Self, Self::ConstructorError> where Self: Sized {
-        Ok(DataSlice::from_boxed_slice(DataBoxed::uninit(size)?.inner))
+        Ok(DataSlice::from_boxed_slice(DataBoxed::uninit(size)?.into_inner()))
     }
 
     #[inline]
     fn filled(size: usize, byte: u8) -> Result<Self, Self::ConstructorError> where Self: Sized {
-        Ok(DataSlice::from_boxed_slice(DataBoxed::filled(size, byte)?.inner))
+        Ok(DataSlice::from_boxed_slice(DataBoxed::filled(size, byte)?.into_inner()))
     }
 
     #[inline]
@@ -0,0 +1,278 @@
+
+/*!
+This module provides the [`BuddyAlloc`] allocator and all it's associated
+functions, methods and items.
+
+A [`BuddyAlloc`] is an in-buffer allocator like the [`DataHeap`](crate::heap::DataHeap),
+with the same [`SubAllocator`] interface, that only gives out blocks whose size is a power of two.
+A block is split in two halves (buddies) to get smaller blocks, and two free buddies are merged
+back together, so both allocating and freeing take `O(log n)` steps no matter how many
+allocations there are, at the cost of rounding every allocation up to a power of two.
+
+Every block starts at a multiple of it's size, so every allocation is aligned
+(relative to the start of the data) to the size of it's block.
+
+The blocks come from the largest power of two bytes at the start of the data that still leaves
+room for the bookkeeping after it, which is stored in the data it'self (so it can be opened again
+with [`BuddyAlloc::open`]): a word with the first free block of each size, followed by
+a byte for each [`MIN_BLOCK`] bytes of blocks telling weather a block starts there, how big it is
+and weather it is used. Every free block holds the offsets of the next and the previous
+free block of the same size.
+ */
+
+use crate::heap::{
+    read_word,
+    write_word,
+    FreeError,
+    OomError,
+    SubAllocator,
+    NONE,
+    WORD,
+};
+use crate::slice::DataSlice;
+use core::ops::DerefMut;
+
+/// The size of the smallest block.
+pub const MIN_BLOCK: usize = WORD * 2;
+
+const FREE: u8 = 0x80;
+const USED: u8 = 0x40;
+const ORDER: u8 = 0x3F;
+
+/// A buddy allocator that manages allocations inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct BuddyAlloc<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    pool: usize,
+}
+
+#[inline]
+const fn orders(pool: usize) -> usize {
+    (pool / MIN_BLOCK).trailing_zeros() as usize + 1
+}
+
+/// Get's the size of the pool of the blocks for data of `size` bytes.
+const fn pool_size(size: usize) -> Option<usize> {
+    if size < MIN_BLOCK {
+        return None
+    }
+
+    let mut pool: usize = 1 << (usize::BITS - 1 - size.leading_zeros());
+
+    while pool >= MIN_BLOCK {
+        if pool.saturating_add(orders(pool) * WORD + pool / MIN_BLOCK) <= size {
+            return Some(pool)
+        }
+
+        pool /= 2;
+    }
+
+    None
+}
+
+impl<D: DerefMut<Target = DataSlice>> BuddyAlloc<D> {
+    /// Constructs a new empty [`BuddyAlloc`] in the given data, overwriting it's bookkeeping.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if it is too small to hold a single block and the bookkeeping.
+    pub fn new(mut data: D) -> Result<BuddyAlloc<D>, D> {
+        let pool: usize = match pool_size(data.size()) {
+            Some(pool) => pool,
+            None => return Err(data),
+        };
+
+        for order in 0..orders(pool) {
+            write_word(&mut data, pool + order * WORD, NONE);
+        }
+
+        let table: usize = pool + orders(pool) * WORD;
+        data.inner[table..table + pool / MIN_BLOCK].fill(0);
+
+        let mut this: BuddyAlloc<D> = BuddyAlloc { data, pool };
+        this.push(orders(pool) - 1, 0);
+
+        Ok(this)
+    }
+
+    /// Opens an allocator that was constructed with [`new`](BuddyAlloc::new) in the given data before.
+    /// 
+    /// The bookkeeping is only checked while it is used, an allocator in corrupted data
+    /// never touches bytes outside of the data but may give wrong results.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if it is too small to hold a single block and the bookkeeping.
+    pub fn open(data: D) -> Result<BuddyAlloc<D>, D> {
+        match pool_size(data.size()) {
+            Some(pool) => Ok(BuddyAlloc { data, pool }),
+            None => Err(data),
+        }
+    }
+
+    /// Get's the amount of bytes the blocks are made from, which are at the start of the data.
+    #[inline]
+    pub fn pool_size(&self) -> usize {
+        self.pool
+    }
+
+    /// Get's the amount of bytes in free blocks.
+    pub fn free_bytes(&self) -> usize {
+        (0..self.pool / MIN_BLOCK)
+            .map(#[inline] |at| self.state(at * MIN_BLOCK))
+            .filter(#[inline] |state| state & FREE == FREE)
+            .map(#[inline] |state| MIN_BLOCK << (state & ORDER))
+            .sum()
+    }
+
+    /// Get's a refrence to the underlying data.
+    #[inline]
+    pub fn get_ref(&self) -> &DataSlice {
+        &self.data
+    }
+
+    /// Get's a mutable refrence to the underlying data, so the allocations can be written.
+    /// 
+    /// Writing outside of the allocations corrupts the allocator.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut DataSlice {
+        &mut self.data
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    #[inline]
+    fn head(&self, order: usize) -> usize {
+        self.pool + order * WORD
+    }
+
+    #[inline]
+    fn state(&self, block: usize) -> u8 {
+        self.data.inner.get(self.pool + orders(self.pool) * WORD + block / MIN_BLOCK).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    fn set_state(&mut self, block: usize, state: u8) {
+        let at: usize = self.pool + orders(self.pool) * WORD + block / MIN_BLOCK;
+
+        if let Some(byte) = self.data.inner.get_mut(at) {
+            *byte = state;
+        }
+    }
+
+    fn push(&mut self, order: usize, block: usize) {
+        let next: usize = read_word(&self.data, self.head(order)).unwrap_or(NONE);
+
+        write_word(&mut self.data, block, next);
+        write_word(&mut self.data, block + WORD, NONE);
+        if next != NONE {
+            write_word(&mut self.data, next.saturating_add(WORD), block);
+        }
+
+        let head: usize = self.head(order);
+        write_word(&mut self.data, head, block);
+        self.set_state(block, order as u8 | FREE);
+    }
+
+    fn remove(&mut self, order: usize, block: usize) {
+        let next: usize = read_word(&self.data, block).unwrap_or(NONE);
+        let prev: usize = read_word(&self.data, block + WORD).unwrap_or(NONE);
+
+        match prev {
+            NONE => {
+                let head: usize = self.head(order);
+                write_word(&mut self.data, head, next);
+            },
+            prev => write_word(&mut self.data, prev, next),
+        }
+
+        if next != NONE {
+            write_word(&mut self.data, next.saturating_add(WORD), prev);
+        }
+
+        self.set_state(block, 0);
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> SubAllocator for BuddyAlloc<D> {
+    fn alloc(&mut self, size: usize, align: usize) -> Result<usize, OomError> {
+        let err: OomError = OomError { size, align };
+
+        if !align.is_power_of_two() {
+            return Err(err)
+        }
+
+        let needed: usize = size.max(align).max(MIN_BLOCK).checked_next_power_of_two().ok_or(err)?;
+        let order: usize = (needed / MIN_BLOCK).trailing_zeros() as usize;
+        let top: usize = orders(self.pool) - 1;
+
+        if order > top {
+            return Err(err)
+        }
+
+        let mut from: usize = order;
+        let block: usize = loop {
+            let block: usize = read_word(&self.data, self.head(from)).unwrap_or(NONE);
+
+            if block != NONE && block < self.pool {
+                break block
+            }
+
+            if from == top {
+                return Err(err)
+            }
+
+            from += 1;
+        };
+
+        self.remove(from, block);
+
+        while from > order {
+            from -= 1;
+            self.push(from, block + (MIN_BLOCK << from));
+        }
+
+        self.set_state(block, order as u8 | USED);
+        Ok(block)
+    }
+
+    fn free(&mut self, offset: usize) -> Result<(), FreeError> {
+        let state: u8 = self.state(offset);
+
+        if !offset.is_multiple_of(MIN_BLOCK) || offset >= self.pool || state & USED != USED {
+            return Err(FreeError { offset })
+        }
+
+        let top: usize = orders(self.pool) - 1;
+        let mut order: usize = (state & ORDER) as usize;
+        let mut block: usize = offset;
+
+        while order < top {
+            let buddy: usize = block ^ (MIN_BLOCK << order);
+
+            if self.state(buddy) != order as u8 | FREE {
+                break
+            }
+
+            self.remove(order, buddy);
+            block = block.min(buddy);
+            order += 1;
+        }
+
+        self.set_state(offset, 0);
+        self.push(order, block);
+        Ok(())
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for BuddyAlloc<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BuddyAlloc")
+            .field("pool_size", &self.pool)
+            .field("free_bytes", &self.free_bytes())
+            .finish()
+    }
+}
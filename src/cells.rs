@@ -0,0 +1,290 @@
+
+/*!
+This module provides the [`DataCells`] data structure and all it's associated
+functions, methods and items.
+
+Most of the reading methods of the data structures give out refrences as unsafe,
+since nothing stops the caller from making a mutable refrence that overlaps
+with another refrence to the same bytes.
+
+[`DataCells`] moves those aliasing rules to runtime, just like a [`RefCell`](core::cell::RefCell)
+does for a single value. The data is split into regions each with it's own borrow counter,
+and every borrow marks all the regions it touches, so two borrows of regions that have
+nothing in common never get in each other's way.
+
+The borrowing methods are still unsafe, but only because the bytes have to be a valid
+value of the borrowed type, the bounds, the alignment and the aliasing are all checked.
+ */
+
+use crate::{
+    idx,
+    boxed::DataBoxed,
+    slice::DataSlice,
+};
+use crate::alloc::boxed::Box;
+use core::cell::{
+    Cell,
+    UnsafeCell,
+};
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// The error given when a value can not be borrowed from a [`DataCells`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorrowError {
+    /// The value gets out of the data structure.
+    OutOfBounds(idx::IdxError),
+    /// The value would not be aligned.
+    Unaligned {
+        /// The index of the value.
+        idx: usize,
+        /// The alignment the type of the value needs.
+        align: usize,
+    },
+    /// A mutable borrow was asked for, but some of the regions are already borrowed.
+    AlreadyBorrowed,
+    /// A borrow was asked for, but some of the regions are already mutably borrowed.
+    AlreadyMutablyBorrowed,
+}
+
+impl core::error::Error for BorrowError {}
+impl core::fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BorrowError::OutOfBounds(err) => core::fmt::Display::fmt(err, f),
+            BorrowError::Unaligned { idx, align } => write!(f, "Can not borrow a value at idx `{idx}` since it is not aligned to `{align}`."),
+            BorrowError::AlreadyBorrowed => f.write_str("Can not mutably borrow a region that is already borrowed."),
+            BorrowError::AlreadyMutablyBorrowed => f.write_str("Can not borrow a region that is already mutably borrowed."),
+        }
+    }
+}
+
+impl From<idx::IdxError> for BorrowError {
+    #[inline] fn from(err: idx::IdxError) -> BorrowError {
+        BorrowError::OutOfBounds(err)
+    }
+}
+
+/// An owned typeless chunk of data that checks the borrowing rules at runtime.
+/// 
+/// Each region keeps a counter of the borrows that touch it:
+/// a positive number is the amount of shared borrows and `-1` is a mutable borrow.
+pub struct DataCells {
+    data: Box<[UnsafeCell<u8>]>,
+    borrows: Box<[Cell<isize>]>,
+    region_size: usize,
+}
+
+impl DataCells {
+    /// Constructs a new [`DataCells`] from the given data split into (at most) `regions` regions.
+    /// 
+    /// There is always at least one region and never more regions then bytes.
+    pub fn new(data: DataBoxed, regions: usize) -> DataCells {
+        let size: usize = data.size();
+        let region_size: usize = size.div_ceil(regions.max(1)).max(1);
+        let regions: usize = size.div_ceil(region_size).max(1);
+
+        DataCells {
+            data: unsafe {
+                // SAFETY: UnsafeCell<u8> has the same layout as u8.
+                Box::from_raw(Box::into_raw(data.into_inner()) as *mut [UnsafeCell<u8>])
+            },
+            borrows: (0..regions).map(#[inline] |_| Cell::new(0)).collect(),
+            region_size,
+        }
+    }
+
+    /// Get's the size of the data structure.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Get's the amount of regions the data structure is split into.
+    #[inline]
+    pub fn regions(&self) -> usize {
+        self.borrows.len()
+    }
+
+    /// Get's the amount of bytes in each region (the last one may be smaller).
+    #[inline]
+    pub fn region_size(&self) -> usize {
+        self.region_size
+    }
+
+    /// Checks the bounds and the alignment of a `T` at `idx`
+    /// and gives back the regions it touches.
+    fn regions_of<T>(&self, idx: usize) -> Result<Range<usize>, BorrowError> {
+        let size: usize = core::mem::size_of::<T>();
+
+        match idx.checked_add(size) {
+            Some(end) if end <= self.size() => {},
+            _ => return Err(BorrowError::OutOfBounds(idx::IdxError::new(idx, self.size(), size))),
+        }
+
+        if !self.data.as_ptr().wrapping_add(idx).cast::<T>().is_aligned() {
+            return Err(BorrowError::Unaligned { idx, align: core::mem::align_of::<T>() })
+        }
+
+        Ok(match size {
+            0 => 0..0,
+            size => idx / self.region_size..(idx + size - 1) / self.region_size + 1,
+        })
+    }
+
+    /// Borrows the `T` at the given index.
+    /// 
+    /// The borrow lasts until the returned guard is dropped.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the data structure, if it is not aligned
+    /// or if any of the regions it touches is mutably borrowed.
+    /// 
+    /// # SAFETY
+    /// Make sure the data gotten from inside is a valid T
+    pub unsafe fn borrow<T>(&self, idx: usize) -> Result<CellRef<'_, T>, BorrowError> {
+        let regions: Range<usize> = self.regions_of::<T>(idx)?;
+        let borrows: &[Cell<isize>] = &self.borrows[regions.clone()];
+
+        if borrows.iter().any(#[inline] |borrow| borrow.get() < 0) {
+            return Err(BorrowError::AlreadyMutablyBorrowed)
+        }
+
+        for borrow in borrows {
+            borrow.set(borrow.get() + 1);
+        }
+
+        Ok(CellRef {
+            borrows,
+            // SAFETY: The value is inside of the data and none of it's bytes are mutably borrowed.
+            value: &*UnsafeCell::raw_get(self.data.as_ptr().add(idx)).cast::<T>(),
+        })
+    }
+
+    /// Mutably borrows the `T` at the given index.
+    /// 
+    /// The borrow lasts until the returned guard is dropped.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the data structure, if it is not aligned
+    /// or if any of the regions it touches is borrowed.
+    /// 
+    /// # SAFETY
+    /// Make sure the data gotten from inside is a valid T
+    pub unsafe fn borrow_mut<T>(&self, idx: usize) -> Result<CellRefMut<'_, T>, BorrowError> {
+        let regions: Range<usize> = self.regions_of::<T>(idx)?;
+        let borrows: &[Cell<isize>] = &self.borrows[regions.clone()];
+
+        if borrows.iter().any(#[inline] |borrow| borrow.get() != 0) {
+            return Err(BorrowError::AlreadyBorrowed)
+        }
+
+        for borrow in borrows {
+            borrow.set(-1);
+        }
+
+        Ok(CellRefMut {
+            borrows,
+            // SAFETY: The value is inside of the data and none of it's bytes are borrowed.
+            value: UnsafeCell::raw_get(self.data.as_ptr().add(idx)).cast::<T>(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get's the whole data structure without checking any borrows,
+    /// since the mutable borrow already guarantees there are none.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut DataSlice {
+        DataSlice::from_slice_mut(unsafe {
+            // SAFETY: The mutable borrow guarantees exclusive access.
+            core::slice::from_raw_parts_mut(UnsafeCell::raw_get(self.data.as_ptr()), self.data.len())
+        })
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> DataBoxed {
+        DataBoxed {
+            inner: unsafe {
+                // SAFETY: UnsafeCell<u8> has the same layout as u8.
+                Box::from_raw(Box::into_raw(self.data) as *mut [u8])
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for DataCells {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataCells")
+            .field("size", &self.size())
+            .field("regions", &self.regions())
+            .field("region_size", &self.region_size)
+            .finish()
+    }
+}
+
+/// A guard of a shared borrow of a value inside of a [`DataCells`].
+pub struct CellRef<'cells, T> {
+    borrows: &'cells [Cell<isize>],
+    value: &'cells T,
+}
+
+impl<'cells, T> core::ops::Deref for CellRef<'cells, T> {
+    type Target = T;
+
+    #[inline] fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<'cells, T> Drop for CellRef<'cells, T> {
+    #[inline] fn drop(&mut self) {
+        for borrow in self.borrows {
+            borrow.set(borrow.get() - 1);
+        }
+    }
+}
+
+impl<'cells, T: core::fmt::Debug> core::fmt::Debug for CellRef<'cells, T> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <T as core::fmt::Debug>::fmt(self.value, f)
+    }
+}
+
+/// A guard of a mutable borrow of a value inside of a [`DataCells`].
+pub struct CellRefMut<'cells, T> {
+    borrows: &'cells [Cell<isize>],
+    value: *mut T,
+    _marker: PhantomData<&'cells mut T>,
+}
+
+impl<'cells, T> core::ops::Deref for CellRefMut<'cells, T> {
+    type Target = T;
+
+    #[inline] fn deref(&self) -> &T {
+        // SAFETY: The guard is the only borrow of the value.
+        unsafe { &*self.value }
+    }
+}
+
+impl<'cells, T> core::ops::DerefMut for CellRefMut<'cells, T> {
+    #[inline] fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The guard is the only borrow of the value.
+        unsafe { &mut *self.value }
+    }
+}
+
+impl<'cells, T> Drop for CellRefMut<'cells, T> {
+    #[inline] fn drop(&mut self) {
+        for borrow in self.borrows {
+            borrow.set(0);
+        }
+    }
+}
+
+impl<'cells, T: core::fmt::Debug> core::fmt::Debug for CellRefMut<'cells, T> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <T as core::fmt::Debug>::fmt(self, f)
+    }
+}
@@ -0,0 +1,647 @@
+
+/*!
+This module provides the [`DataChain`] data structure and all it's associated
+functions, methods and items.
+
+A [`DataChain`] puts a list of segments (any data structures that give out a [`DataSlice`],
+owned like a [`DataBoxed`](crate::boxed::DataBoxed) or borrowed like a `&mut DataSlice`)
+one after another, so they can be used as a single space of bytes, like a list of
+pooled packet buffers that together hold one message.
+
+The byte methods of the chain ([`read_bytes`](DataChain::read_bytes), [`write_bytes`](DataChain::write_bytes),
+[`read_value`](DataChain::read_value) and [`write_value`](DataChain::write_value)) copy across the boundaries
+of the segments. The chain is also a [`RawDataStructure`], but since the methods of that trait
+give out pointers into the data, a region is only valid for them if it is inside of a single segment,
+so [`read_validity`](RawDataStructure::read_validity) gives an error for any region that straddles
+the boundary of two segments, only the unchecked fills with zeroes or ones work across them.
+
+A [`GatherView`] does the same for borrowed parts, shared ones (`&DataSlice`) which can only be read
+and unique ones (`&mut DataSlice`), so a protocol can be parsed from memory that is not contiguous
+(eg: a header in one buffer and a body in another) without copying it first.
+Besides the byte methods, values can be taken out of it across the boundaries of the parts
+with [`take_value`](GatherView::take_value), and [`write_validity`](RawDataStructure::write_validity)
+gives an error for any region in a shared part.
+ */
+
+use crate::{
+    idx,
+    slice::DataSlice,
+    RawDataStructure,
+};
+use crate::alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::DerefMut;
+
+/// A list of segments used as a single data structure.
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DataChain<S: DerefMut<Target = DataSlice>> {
+    segments: Vec<S>,
+    /// The offset each segment starts at, followed by the size of the chain.
+    starts: Vec<usize>,
+}
+
+/// Get's the segment the byte at `idx` is in and the offset of the byte in it.
+/// 
+/// The end of the chain is in the last segment.
+#[inline]
+fn locate(starts: &[usize], idx: usize) -> Option<(usize, usize)> {
+    let segments: &[usize] = &starts[..starts.len() - 1];
+
+    if segments.is_empty() || idx > starts[starts.len() - 1] {
+        return None
+    }
+
+    let segment: usize = segments.partition_point(#[inline] |start| *start <= idx) - 1;
+    Some((segment, idx - segments[segment]))
+}
+
+/// Get's an iterator over the parts of the region in each of the segments it touches,
+/// as the segment, the offset in the segment and the amount of bytes.
+#[inline]
+fn pieces(starts: &[usize], idx: usize, size: usize) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+    let first: usize = locate(starts, idx).map_or(0, #[inline] |(segment, _)| segment);
+    let end: usize = idx.saturating_add(size);
+
+    (first..starts.len() - 1)
+        .take_while(#[inline] move |segment| starts[*segment] < end)
+        .map(
+            #[inline] move |segment| {
+                let start: usize = starts[segment].max(idx);
+                (segment, start - starts[segment], starts[segment + 1].min(end).saturating_sub(start))
+            }
+        )
+        .filter(#[inline] |(_, _, len)| *len != 0)
+}
+
+impl<S: DerefMut<Target = DataSlice>> DataChain<S> {
+    /// Constructs a new [`DataChain`] with no segments.
+    #[inline]
+    pub fn new() -> DataChain<S> {
+        DataChain { segments: Vec::new(), starts: crate::alloc::vec![0] }
+    }
+
+    /// Adds the segment at the end of the chain.
+    pub fn push(&mut self, segment: S) {
+        let end: usize = self.size().checked_add(segment.size()).expect("The size of a `DataChain` overflowed.");
+
+        self.segments.push(segment);
+        self.starts.push(end);
+    }
+
+    /// Get's the amount of bytes in all the segments.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.starts[self.starts.len() - 1]
+    }
+
+    /// Get's the amount of segments.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Checks weather the chain has no segments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Get's the segment at the given position in the chain.
+    #[inline]
+    pub fn segment(&self, segment: usize) -> Option<&DataSlice> {
+        self.segments.get(segment).map(#[inline] |segment| &**segment)
+    }
+
+    /// Get's the segment at the given position in the chain mutably.
+    #[inline]
+    pub fn segment_mut(&mut self, segment: usize) -> Option<&mut DataSlice> {
+        self.segments.get_mut(segment).map(#[inline] |segment| &mut **segment)
+    }
+
+    /// Get's the position of the segment the byte at `idx` is in, and the offset of the byte in that segment.
+    #[inline]
+    pub fn locate(&self, idx: usize) -> Option<(usize, usize)> {
+        locate(&self.starts, idx).filter(#[inline] |_| idx < self.size())
+    }
+
+    #[inline]
+    fn bounds(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        match idx.checked_add(size) {
+            Some(end) if end <= self.size() => Ok(()),
+            _ => Err(idx::IdxError::new(idx, self.size(), size)),
+        }
+    }
+
+    /// Copies the bytes starting at `idx` into `out`, across the boundaries of the segments.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes get out of the chain.
+    pub fn read_bytes(&self, idx: usize, out: &mut [u8]) -> Result<(), idx::IdxError> {
+        self.bounds(idx, out.len())?;
+
+        let mut done: usize = 0;
+        for (segment, offset, len) in pieces(&self.starts, idx, out.len()) {
+            out[done..done + len].copy_from_slice(&self.segments[segment].inner[offset..offset + len]);
+            done += len;
+        }
+
+        Ok(())
+    }
+
+    /// Copies the bytes into the chain starting at `idx`, across the boundaries of the segments.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes get out of the chain.
+    pub fn write_bytes(&mut self, idx: usize, bytes: &[u8]) -> Result<(), idx::IdxError> {
+        self.bounds(idx, bytes.len())?;
+
+        let mut done: usize = 0;
+        for (segment, offset, len) in pieces(&self.starts, idx, bytes.len()) {
+            self.segments[segment].inner[offset..offset + len].copy_from_slice(&bytes[done..done + len]);
+            done += len;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a copy of the value starting at `idx`, across the boundaries of the segments.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the chain.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data gotten from inside is a valid T
+    pub unsafe fn read_value<T: Copy>(&self, idx: usize) -> Result<T, idx::IdxError> {
+        let mut value: core::mem::MaybeUninit<T> = core::mem::MaybeUninit::zeroed();
+
+        // SAFETY: The bytes of the value are zeroed, so they are valid bytes.
+        self.read_bytes(idx, core::slice::from_raw_parts_mut(value.as_mut_ptr().cast::<u8>(), core::mem::size_of::<T>()))?;
+
+        Ok(value.assume_init())
+    }
+
+    /// Writes the value starting at `idx`, across the boundaries of the segments.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the chain.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    pub unsafe fn write_value<T: Copy>(&mut self, idx: usize, value: T) -> Result<(), idx::IdxError> {
+        self.bounds(idx, core::mem::size_of::<T>())?;
+
+        // The bytes are copied as they are, since the padding of the value may not be valid bytes.
+        let mut done: usize = 0;
+        for (segment, offset, len) in pieces(&self.starts, idx, core::mem::size_of::<T>()) {
+            core::ptr::copy_nonoverlapping((&value as *const T).cast::<u8>().add(done), self.segments[segment].as_mut_ptr().add(offset), len);
+            done += len;
+        }
+
+        Ok(())
+    }
+
+    /// Gives back the segments of the chain.
+    #[inline]
+    pub fn into_segments(self) -> Vec<S> {
+        self.segments
+    }
+
+    /// Get's the segment and the offset in it of a region that is inside of a single segment.
+    #[inline]
+    fn inside(&self, idx: usize) -> (usize, usize) {
+        locate(&self.starts, idx).expect("An unchecked function was given a region outside of the data structure.")
+    }
+}
+
+impl<S: DerefMut<Target = DataSlice>> Default for DataChain<S> {
+    #[inline] fn default() -> DataChain<S> {
+        DataChain::new()
+    }
+}
+
+impl<S: DerefMut<Target = DataSlice>> FromIterator<S> for DataChain<S> {
+    fn from_iter<I: IntoIterator<Item = S>>(segments: I) -> DataChain<S> {
+        let mut chain: DataChain<S> = DataChain::new();
+
+        for segment in segments {
+            chain.push(segment);
+        }
+
+        chain
+    }
+}
+
+/// A region is only valid for the chain if it is inside of a single segment,
+/// go to the [module level documentation](self) for more details.
+unsafe impl<S: DerefMut<Target = DataSlice>> RawDataStructure for DataChain<S> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.size()
+    }
+
+    fn read_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        self.bounds(idx, size)?;
+
+        match locate(&self.starts, idx) {
+            Some((segment, offset)) if offset + size <= self.segments[segment].size() => Ok(()),
+            None if size == 0 => Ok(()),
+            _ => Err(idx::IdxError::new(idx, self.size(), size)),
+        }
+    }
+
+    unsafe fn clone_from_unchecked(&mut self, data: &Self) {
+        for (segment, start) in self.segments.iter_mut().zip(self.starts.iter()) {
+            data.read_bytes(*start, &mut segment.inner).ok();
+        }
+    }
+
+    unsafe fn write_zeroes_unchecked(&mut self, idx: usize, size: usize) {
+        for (segment, offset, len) in pieces(&self.starts, idx, size) {
+            <DataSlice as RawDataStructure>::write_zeroes_unchecked(&mut self.segments[segment], offset, len);
+        }
+    }
+
+    unsafe fn write_ones_unchecked(&mut self, idx: usize, size: usize) {
+        for (segment, offset, len) in pieces(&self.starts, idx, size) {
+            <DataSlice as RawDataStructure>::write_ones_unchecked(&mut self.segments[segment], offset, len);
+        }
+    }
+
+    #[inline]
+    unsafe fn write_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::write_unsized_unchecked(&mut self.segments[segment], offset, value)
+    }
+
+    #[inline]
+    unsafe fn read_unchecked<T: Sized>(&self, idx: usize) -> *const T {
+        match locate(&self.starts, idx) {
+            Some((segment, offset)) => <DataSlice as RawDataStructure>::read_unchecked(&self.segments[segment], offset),
+            None => core::ptr::NonNull::dangling().as_ptr(),
+        }
+    }
+
+    #[inline]
+    unsafe fn read_mut_unchecked<T: Sized>(&mut self, idx: usize) -> *mut T {
+        match locate(&self.starts, idx) {
+            Some((segment, offset)) => <DataSlice as RawDataStructure>::read_mut_unchecked(&mut self.segments[segment], offset),
+            None => core::ptr::NonNull::dangling().as_ptr(),
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::read_unsized_unchecked(&self.segments[segment], offset, meta)
+    }
+
+    #[inline]
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::read_unsized_mut_unchecked(&mut self.segments[segment], offset, meta)
+    }
+
+    #[inline]
+    unsafe fn take_unchecked<T: Sized>(&self, idx: usize) -> T {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::take_unchecked(&self.segments[segment], offset)
+    }
+
+    #[inline]
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::replace_unsized_unchecked(&mut self.segments[segment], offset, value, out)
+    }
+
+    type DataByte = u8;
+
+    #[inline]
+    unsafe fn get_at_idx(&self, idx: usize) -> u8 {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::get_at_idx(&self.segments[segment], offset)
+    }
+
+    #[inline]
+    unsafe fn set_at_idx(&mut self, idx: usize, byte: u8) {
+        let (segment, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::set_at_idx(&mut self.segments[segment], offset, byte)
+    }
+}
+
+impl<S: DerefMut<Target = DataSlice>> core::fmt::Debug for DataChain<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataChain")
+            .field("segments", &self.segments.len())
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+/// A part of a [`GatherView`].
+enum Part<'a> {
+    Shared(&'a DataSlice),
+    Unique(&'a mut DataSlice),
+}
+
+impl Part<'_> {
+    #[inline]
+    fn get(&self) -> &DataSlice {
+        match self {
+            Part::Shared(part) => part,
+            Part::Unique(part) => part,
+        }
+    }
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut DataSlice {
+        match self {
+            Part::Shared(_) => panic!("A shared part of a `GatherView` was written to."),
+            Part::Unique(part) => part,
+        }
+    }
+}
+
+/// A list of borrowed parts used as a single data structure.
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct GatherView<'a> {
+    parts: Vec<Part<'a>>,
+    /// The offset each part starts at, followed by the size of the view.
+    starts: Vec<usize>,
+}
+
+impl<'a> GatherView<'a> {
+    /// Constructs a new [`GatherView`] with no parts.
+    #[inline]
+    pub fn new() -> GatherView<'a> {
+        GatherView { parts: Vec::new(), starts: crate::alloc::vec![0] }
+    }
+
+    fn push_part(&mut self, part: Part<'a>) {
+        let end: usize = self.size().checked_add(part.get().size()).expect("The size of a `GatherView` overflowed.");
+
+        self.parts.push(part);
+        self.starts.push(end);
+    }
+
+    /// Adds a part that can only be read at the end of the view.
+    #[inline]
+    pub fn push(&mut self, part: &'a DataSlice) {
+        self.push_part(Part::Shared(part));
+    }
+
+    /// Adds a part that can be read and written at the end of the view.
+    #[inline]
+    pub fn push_mut(&mut self, part: &'a mut DataSlice) {
+        self.push_part(Part::Unique(part));
+    }
+
+    /// Get's the amount of bytes in all the parts.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.starts[self.starts.len() - 1]
+    }
+
+    /// Get's the amount of parts.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Checks weather the view has no parts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Get's the part at the given position in the view.
+    #[inline]
+    pub fn part(&self, part: usize) -> Option<&DataSlice> {
+        self.parts.get(part).map(Part::get)
+    }
+
+    /// Get's the position of the part the byte at `idx` is in, and the offset of the byte in that part.
+    #[inline]
+    pub fn locate(&self, idx: usize) -> Option<(usize, usize)> {
+        locate(&self.starts, idx).filter(#[inline] |_| idx < self.size())
+    }
+
+    #[inline]
+    fn bounds(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        match idx.checked_add(size) {
+            Some(end) if end <= self.size() => Ok(()),
+            _ => Err(idx::IdxError::new(idx, self.size(), size)),
+        }
+    }
+
+    /// Copies the bytes starting at `idx` into `out`, across the boundaries of the parts.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes get out of the view.
+    pub fn read_bytes(&self, idx: usize, out: &mut [u8]) -> Result<(), idx::IdxError> {
+        self.bounds(idx, out.len())?;
+
+        let mut done: usize = 0;
+        for (part, offset, len) in pieces(&self.starts, idx, out.len()) {
+            out[done..done + len].copy_from_slice(&self.parts[part].get().inner[offset..offset + len]);
+            done += len;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a copy of the value starting at `idx`, across the boundaries of the parts.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the view.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data gotten from inside is a valid T
+    #[inline]
+    pub unsafe fn read_value<T: Copy>(&self, idx: usize) -> Result<T, idx::IdxError> {
+        self.take_value::<T>(idx)
+    }
+
+    /// Takes the value starting at `idx` out of the view, across the boundaries of the parts.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the view.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure the value gotten is not used anymore in the data,
+    ///   since the value gotten is not a copy.
+    pub unsafe fn take_value<T>(&self, idx: usize) -> Result<T, idx::IdxError> {
+        self.bounds(idx, core::mem::size_of::<T>())?;
+
+        let mut value: core::mem::MaybeUninit<T> = core::mem::MaybeUninit::uninit();
+        let mut done: usize = 0;
+        for (part, offset, len) in pieces(&self.starts, idx, core::mem::size_of::<T>()) {
+            core::ptr::copy_nonoverlapping(self.parts[part].get().as_ptr().add(offset), value.as_mut_ptr().cast::<u8>().add(done), len);
+            done += len;
+        }
+
+        Ok(value.assume_init())
+    }
+
+    #[inline]
+    fn inside(&self, idx: usize) -> (usize, usize) {
+        locate(&self.starts, idx).expect("An unchecked function was given a region outside of the data structure.")
+    }
+}
+
+impl Default for GatherView<'_> {
+    #[inline] fn default() -> Self {
+        GatherView::new()
+    }
+}
+
+impl<'a> FromIterator<&'a DataSlice> for GatherView<'a> {
+    fn from_iter<I: IntoIterator<Item = &'a DataSlice>>(parts: I) -> GatherView<'a> {
+        let mut view: GatherView<'a> = GatherView::new();
+
+        for part in parts {
+            view.push(part);
+        }
+
+        view
+    }
+}
+
+impl<'a> FromIterator<&'a mut DataSlice> for GatherView<'a> {
+    fn from_iter<I: IntoIterator<Item = &'a mut DataSlice>>(parts: I) -> GatherView<'a> {
+        let mut view: GatherView<'a> = GatherView::new();
+
+        for part in parts {
+            view.push_mut(part);
+        }
+
+        view
+    }
+}
+
+/// A region is only valid for the view if it is inside of a single part,
+/// and only writable if that part is unique.
+/// 
+/// The unchecked methods that write panic if they are given a region in a shared part,
+/// and cloning into a view skips it's shared parts.
+unsafe impl RawDataStructure for GatherView<'_> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.size()
+    }
+
+    fn read_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        self.bounds(idx, size)?;
+
+        match locate(&self.starts, idx) {
+            Some((part, offset)) if offset + size <= self.parts[part].get().size() => Ok(()),
+            None if size == 0 => Ok(()),
+            _ => Err(idx::IdxError::new(idx, self.size(), size)),
+        }
+    }
+
+    fn write_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        self.read_validity(idx, size)?;
+
+        match locate(&self.starts, idx) {
+            Some((part, _)) if matches!(self.parts[part], Part::Shared(_)) && size != 0 => Err(idx::IdxError::new(idx, self.size(), size)),
+            _ => Ok(()),
+        }
+    }
+
+    unsafe fn clone_from_unchecked(&mut self, data: &Self) {
+        for (part, start) in self.parts.iter_mut().zip(self.starts.iter()) {
+            if let Part::Unique(part) = part {
+                data.read_bytes(*start, &mut part.inner).ok();
+            }
+        }
+    }
+
+    unsafe fn write_zeroes_unchecked(&mut self, idx: usize, size: usize) {
+        for (part, offset, len) in pieces(&self.starts, idx, size) {
+            <DataSlice as RawDataStructure>::write_zeroes_unchecked(self.parts[part].get_mut(), offset, len);
+        }
+    }
+
+    unsafe fn write_ones_unchecked(&mut self, idx: usize, size: usize) {
+        for (part, offset, len) in pieces(&self.starts, idx, size) {
+            <DataSlice as RawDataStructure>::write_ones_unchecked(self.parts[part].get_mut(), offset, len);
+        }
+    }
+
+    #[inline]
+    unsafe fn write_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::write_unsized_unchecked(self.parts[part].get_mut(), offset, value)
+    }
+
+    #[inline]
+    unsafe fn read_unchecked<T: Sized>(&self, idx: usize) -> *const T {
+        match locate(&self.starts, idx) {
+            Some((part, offset)) => <DataSlice as RawDataStructure>::read_unchecked(self.parts[part].get(), offset),
+            None => core::ptr::NonNull::dangling().as_ptr(),
+        }
+    }
+
+    #[inline]
+    unsafe fn read_mut_unchecked<T: Sized>(&mut self, idx: usize) -> *mut T {
+        match locate(&self.starts, idx) {
+            Some((part, offset)) => <DataSlice as RawDataStructure>::read_mut_unchecked(self.parts[part].get_mut(), offset),
+            None => core::ptr::NonNull::dangling().as_ptr(),
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::read_unsized_unchecked(self.parts[part].get(), offset, meta)
+    }
+
+    #[inline]
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::read_unsized_mut_unchecked(self.parts[part].get_mut(), offset, meta)
+    }
+
+    #[inline]
+    unsafe fn take_unchecked<T: Sized>(&self, idx: usize) -> T {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::take_unchecked(self.parts[part].get(), offset)
+    }
+
+    #[inline]
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::replace_unsized_unchecked(self.parts[part].get_mut(), offset, value, out)
+    }
+
+    type DataByte = u8;
+
+    #[inline]
+    unsafe fn get_at_idx(&self, idx: usize) -> u8 {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::get_at_idx(self.parts[part].get(), offset)
+    }
+
+    #[inline]
+    unsafe fn set_at_idx(&mut self, idx: usize, byte: u8) {
+        let (part, offset) = self.inside(idx);
+        <DataSlice as RawDataStructure>::set_at_idx(self.parts[part].get_mut(), offset, byte)
+    }
+}
+
+impl core::fmt::Debug for GatherView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GatherView")
+            .field("parts", &self.parts.len())
+            .field("size", &self.size())
+            .finish()
+    }
+}
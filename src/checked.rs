@@ -0,0 +1,262 @@
+
+/*!
+This module provides the [`CheckedData`] data structure and all it's associated
+functions, methods and items.
+
+[`CheckedData`] is a fully safe mode of this crate: every value is written with a header holding
+it's [`TypeId`] and it's size right before it, and is only read back (as a refrence or by value)
+once the header is checked to be of the type it is read as, so none of it's methods need a safety
+contract, at the cost of the header (and the list of the values stored) for every value.
+Only the methods that give out the bytes of the data structure ([`get_ref`](CheckedData::get_ref) and
+[`into_inner`](CheckedData::into_inner)) are unsafe, since the padding of the values written is uninitialized.
+
+Since the data structure is owned by it, no bytes can be written to it except through it's methods,
+so the header of a value can never be forged (as long as the data structure does not share it's bytes
+with something else that writes to them, like shared memory). A value can not be written over a value that is
+already stored, it must be [taken](CheckedData::take_any) out first.
+
+Values get refrences to them, so the value (right after it's header) must be aligned in memory for it's type
+when it is written, and is checked to still be aligned when it is read, since a data structure
+that holds it's bytes inline (like a [`DataArray`](crate::array::DataArray)) can move in memory.
+
+The values left when it is dropped are never dropped.
+
+Since any `'static` value can be stored, a [`CheckedData`] is neither [`Send`] nor [`Sync`],
+as the values might not be.
+ */
+
+use crate::{
+    idx,
+    RawDataStructure,
+};
+use crate::alloc::vec::Vec;
+use core::any::TypeId;
+use core::marker::PhantomData;
+
+/// The size of the header written before every value.
+pub const HEADER: usize = core::mem::size_of::<TypeId>() + core::mem::size_of::<usize>();
+
+/// The error given when a value can not be written to or read from a [`CheckedData`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnyError {
+    /// The value (with it's header) does not fit in the data structure, or is not aligned in memory.
+    Idx(idx::IdxError),
+    /// The value would overlap the value (with it's header) stored at the given index.
+    Overlap {
+        /// The index of the header of the value it would overlap.
+        idx: usize,
+    },
+    /// No value is stored at the given index.
+    Empty {
+        /// The index that was read.
+        idx: usize,
+    },
+    /// The value stored at the given index is of a diferent type.
+    WrongType {
+        /// The index that was read.
+        idx: usize,
+        /// The size of the value that is stored.
+        size: usize,
+    },
+}
+
+impl core::error::Error for AnyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            AnyError::Idx(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for AnyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AnyError::Idx(err) => core::fmt::Display::fmt(err, f),
+            AnyError::Overlap { idx } => write!(f, "The value would overlap the value stored at `{idx}`."),
+            AnyError::Empty { idx } => write!(f, "No value is stored at `{idx}`."),
+            AnyError::WrongType { idx, size } => write!(f, "The value of size `{size}` stored at `{idx}` is of a diferent type."),
+        }
+    }
+}
+
+impl From<idx::IdxError> for AnyError {
+    #[inline] fn from(err: idx::IdxError) -> AnyError {
+        AnyError::Idx(err)
+    }
+}
+
+/// A data structure that checks the type of every value read from it.
+/// 
+/// Go to the [module level documentation](self) for more details.
+/// 
+/// It can not be sent to another thread, since the values stored might not be [`Send`]:
+/// 
+/// ```compile_fail
+/// use typeless_data::{array::DataArray, checked::CheckedData};
+/// 
+/// fn send<T: Send>(_: T) {}
+/// 
+/// send(CheckedData::new(DataArray::<64>::zeroed()));
+/// ```
+#[must_use]
+pub struct CheckedData<D: RawDataStructure> {
+    data: D,
+    /// The regions of the stored values with their headers, sorted by their start and never overlapping.
+    values: Vec<core::ops::Range<usize>>,
+    /// The values might not be [`Send`] or [`Sync`].
+    _marker: PhantomData<*mut u8>,
+}
+
+impl<D: RawDataStructure> CheckedData<D> {
+    /// Constructs a new [`CheckedData`] with no values in the given data structure.
+    #[inline]
+    pub fn new(data: D) -> CheckedData<D> {
+        CheckedData { data, values: Vec::new(), _marker: PhantomData }
+    }
+
+    /// Get's the amount of values stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks weather no values are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get's the [`TypeId`] of the value stored at the given index, or [None] if no value is stored there.
+    pub fn type_at(&self, idx: usize) -> Option<TypeId> {
+        self.values.binary_search_by_key(&idx, #[inline] |value| value.start).ok()?;
+
+        // SAFETY: The header was written by write_any.
+        unsafe { self.data.read_unaligned_value::<TypeId>(idx).ok() }
+    }
+
+    /// Writes the value with it's header at the given index, so the value it'self starts at `idx + HEADER`.
+    /// 
+    /// # ERRORS
+    /// Will return the value back if it does not fit in the data structure, if it is not aligned in memory
+    /// or if it overlaps a value that is already stored.
+    pub fn write_any<T: 'static>(&mut self, idx: usize, value: T) -> Result<(), (T, AnyError)> {
+        let size: usize = core::mem::size_of::<T>();
+
+        let end: usize = match idx.checked_add(HEADER).and_then(#[inline] |start| start.checked_add(size)) {
+            Some(end) => end,
+            None => return Err((value, AnyError::Idx(idx::IdxError::Overflow { idx, size: HEADER.saturating_add(size) }))),
+        };
+
+        if let Err(err) = self.data.write_validity(idx, end - idx) {
+            return Err((value, err.into()))
+        }
+
+        if let Err(err) = self.data.align_validity(idx + HEADER, core::mem::align_of::<T>()) {
+            return Err((value, err.into()))
+        }
+
+        // The first value that ends after the start of this one must start after the end of this one.
+        let at: usize = self.values.partition_point(#[inline] |stored| stored.end <= idx);
+        if let Some(stored) = self.values.get(at).filter(#[inline] |stored| stored.start < end) {
+            return Err((value, AnyError::Overlap { idx: stored.start }))
+        }
+
+        // SAFETY: The header and the value fit in the data structure and the value is aligned.
+        unsafe {
+            self.data.read_mut_unchecked::<TypeId>(idx).write_unaligned(TypeId::of::<T>());
+            self.data.read_mut_unchecked::<usize>(idx + core::mem::size_of::<TypeId>()).write_unaligned(size);
+            self.data.read_mut_unchecked::<T>(idx + HEADER).write(value);
+        }
+
+        self.values.insert(at, idx..end);
+        Ok(())
+    }
+
+    /// Checks that a `T` is stored at the given index, and gives back the position of it in the list of values.
+    fn check<T: 'static>(&self, idx: usize) -> Result<usize, AnyError> {
+        let at: usize = self.values.binary_search_by_key(&idx, #[inline] |value| value.start)
+            .map_err(#[inline] |_| AnyError::Empty { idx })?;
+
+        // SAFETY: The header was written by write_any.
+        let (id, size): (TypeId, usize) = unsafe {(
+            self.data.read_unaligned_value::<TypeId>(idx).map_err(idx::IdxError::from)?,
+            self.data.read_unaligned_value::<usize>(idx + core::mem::size_of::<TypeId>()).map_err(idx::IdxError::from)?,
+        )};
+
+        if id != TypeId::of::<T>() || size != core::mem::size_of::<T>() {
+            return Err(AnyError::WrongType { idx, size })
+        }
+
+        self.data.align_validity(idx + HEADER, core::mem::align_of::<T>())?;
+        Ok(at)
+    }
+
+    /// Get's a refrence to the `T` stored at the given index.
+    /// 
+    /// # ERRORS
+    /// Will return an error if no value is stored there, if it is not a `T`
+    /// or if it is not aligned in memory anymore.
+    pub fn read_any<T: 'static>(&self, idx: usize) -> Result<&T, AnyError> {
+        self.check::<T>(idx)?;
+
+        // SAFETY: A T was written there by write_any and it is aligned.
+        Ok(unsafe { self.data.read_ref_unchecked::<T>(idx + HEADER) })
+    }
+
+    /// Get's a mutable refrence to the `T` stored at the given index.
+    /// 
+    /// # ERRORS
+    /// Will return an error if no value is stored there, if it is not a `T`
+    /// or if it is not aligned in memory anymore.
+    pub fn read_any_mut<T: 'static>(&mut self, idx: usize) -> Result<&mut T, AnyError> {
+        self.check::<T>(idx)?;
+
+        // SAFETY: A T was written there by write_any and it is aligned.
+        Ok(unsafe { self.data.read_ref_mut_unchecked::<T>(idx + HEADER) })
+    }
+
+    /// Takes the `T` stored at the given index out of the data structure, so the region can be written to again.
+    /// 
+    /// # ERRORS
+    /// Will return an error if no value is stored there, if it is not a `T`
+    /// or if it is not aligned in memory anymore.
+    pub fn take_any<T: 'static>(&mut self, idx: usize) -> Result<T, AnyError> {
+        let at: usize = self.check::<T>(idx)?;
+        self.values.remove(at);
+
+        // SAFETY: A T was written there by write_any, and it is not stored anymore.
+        Ok(unsafe { self.data.take_unchecked::<T>(idx + HEADER) })
+    }
+
+    /// Get's the data structure the values are stored in, which can only be read through this.
+    /// 
+    /// # SAFETY
+    /// - Make sure the padding bytes of the values written (taken out or not) are not read,
+    ///   since they are uninitialized
+    #[inline]
+    pub unsafe fn get_ref(&self) -> &D {
+        &self.data
+    }
+
+    /// Gives back the underlying data structure, without dropping the values stored in it.
+    /// 
+    /// # SAFETY
+    /// - Make sure the padding bytes of the values written (taken out or not) are not read,
+    ///   since they are uninitialized
+    #[inline]
+    pub unsafe fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: RawDataStructure> core::fmt::Debug for CheckedData<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CheckedData")
+            .field("len", &self.values.len())
+            .field("size", &self.data.size())
+            .finish()
+    }
+}
@@ -0,0 +1,147 @@
+
+/*!
+This module provides the CRCs and checksums used to protect the frames of
+protocols built on top of the data structures of this crate.
+
+All of them are `const` and are computed over a [`DataSlice`], so a subrange of a data
+structure can be checked by getting it first (eg: with [`get`](crate::DataStructureSlice::get)).
+
+- [`Crc8`], [`Crc16`] and [`Crc32`] compute a CRC with any polynomial, the common ones being given as constants.
+- [`internet`] computes the ones' complement checksum of IPv4, TCP and UDP (RFC 1071).
+- [`fletcher16`] computes the Fletcher-16 checksum.
+
+The CRC-32 of the bytes before an index can also be written at (and checked against) that
+index with [`DataSlice::write_crc32_at`](crate::slice::DataSlice::write_crc32_at)
+and [`DataSlice::check_crc32_at`](crate::slice::DataSlice::check_crc32_at).
+ */
+
+use crate::slice::DataSlice;
+
+macro_rules! crc {
+    ($(#[$meta:meta])* $name:ident, $int:ty) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            /// The polynomial, without it's highest bit and not reflected.
+            pub poly: $int,
+            /// The value the CRC starts from.
+            pub init: $int,
+            /// Weather the bytes are fed least significant bit first and the result is reflected.
+            pub reflected: bool,
+            /// The value the result is XORed with.
+            pub xor_out: $int,
+        }
+
+        impl $name {
+            /// Constructs a new CRC with the given parameters.
+            #[inline]
+            pub const fn new(poly: $int, init: $int, reflected: bool, xor_out: $int) -> $name {
+                $name { poly, init, reflected, xor_out }
+            }
+
+            /// Computes the CRC of all the bytes of the given data.
+            pub const fn compute(&self, data: &DataSlice) -> $int {
+                const BITS: u32 = <$int>::BITS;
+
+                let poly: $int = if self.reflected { self.poly.reverse_bits() } else { self.poly };
+                let mut crc: $int = if self.reflected { self.init.reverse_bits() } else { self.init };
+                let mut at: usize = 0;
+
+                while at < data.inner.len() {
+                    let byte: $int = data.inner[at] as $int;
+                    crc ^= if self.reflected { byte } else { byte << (BITS - 8) };
+
+                    let mut bit: u32 = 0;
+                    while bit < 8 {
+                        crc = if self.reflected {
+                            if crc & 1 != 0 { (crc >> 1) ^ poly } else { crc >> 1 }
+                        } else {
+                            if crc & (1 << (BITS - 1)) != 0 { (crc << 1) ^ poly } else { crc << 1 }
+                        };
+                        bit += 1;
+                    }
+
+                    at += 1;
+                }
+
+                crc ^ self.xor_out
+            }
+        }
+    };
+}
+
+crc!{
+    /// The parameters of an 8 bit CRC.
+    Crc8, u8
+}
+
+crc!{
+    /// The parameters of a 16 bit CRC.
+    Crc16, u16
+}
+
+crc!{
+    /// The parameters of a 32 bit CRC.
+    Crc32, u32
+}
+
+impl Crc8 {
+    /// CRC-8/SMBUS, the CRC-8 of SMBus and ATM headers.
+    pub const SMBUS: Crc8 = Crc8::new(0x07, 0x00, false, 0x00);
+    /// CRC-8/MAXIM, the CRC-8 of 1-Wire.
+    pub const MAXIM: Crc8 = Crc8::new(0x31, 0x00, true, 0x00);
+}
+
+impl Crc16 {
+    /// CRC-16/ARC, the CRC-16 of Modbus without it's init.
+    pub const ARC: Crc16 = Crc16::new(0x8005, 0x0000, true, 0x0000);
+    /// CRC-16/MODBUS.
+    pub const MODBUS: Crc16 = Crc16::new(0x8005, 0xFFFF, true, 0x0000);
+    /// CRC-16/IBM-3740 (often called CRC-16/CCITT-FALSE).
+    pub const CCITT_FALSE: Crc16 = Crc16::new(0x1021, 0xFFFF, false, 0x0000);
+    /// CRC-16/KERMIT, the CRC-16 of Bluetooth.
+    pub const KERMIT: Crc16 = Crc16::new(0x1021, 0x0000, true, 0x0000);
+}
+
+impl Crc32 {
+    /// CRC-32/ISO-HDLC, the CRC-32 of Ethernet, zip and png.
+    pub const ISO_HDLC: Crc32 = Crc32::new(0x04C11DB7, 0xFFFFFFFF, true, 0xFFFFFFFF);
+    /// CRC-32/ISCSI (CRC-32C), the CRC-32 of iSCSI, SCTP and ext4.
+    pub const ISCSI: Crc32 = Crc32::new(0x1EDC6F41, 0xFFFFFFFF, true, 0xFFFFFFFF);
+}
+
+/// Computes the ones' complement checksum of IPv4, TCP and UDP (RFC 1071) of all the bytes of the given data,
+/// an odd last byte being padded with a `0` byte.
+/// 
+/// The checksum is big endian, so it should be written with [`u16::to_be_bytes`].
+pub const fn internet(data: &DataSlice) -> u16 {
+    let mut sum: u32 = 0;
+    let mut at: usize = 0;
+
+    while at < data.inner.len() {
+        let high: u32 = data.inner[at] as u32;
+        let low: u32 = if at + 1 < data.inner.len() { data.inner[at + 1] as u32 } else { 0 };
+
+        sum += high << 8 | low;
+        // Folding on every step keeps the sum from overflowing.
+        sum = (sum & 0xFFFF) + (sum >> 16);
+        at += 2;
+    }
+
+    !(sum as u16)
+}
+
+/// Computes the Fletcher-16 checksum of all the bytes of the given data.
+pub const fn fletcher16(data: &DataSlice) -> u16 {
+    let mut low: u16 = 0;
+    let mut high: u16 = 0;
+    let mut at: usize = 0;
+
+    while at < data.inner.len() {
+        low = (low + data.inner[at] as u16) % 255;
+        high = (high + low) % 255;
+        at += 1;
+    }
+
+    high << 8 | low
+}
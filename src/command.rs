@@ -0,0 +1,180 @@
+
+/*!
+This module provides the [`CommandBuffer`] data structure and all it's associated
+functions, methods and items.
+
+A [`CommandBuffer`] records commands of diferent types one after another in a [`DataSlice`],
+each one prefixed by a header with it's size and a tag of the choice of the caller (eg: which
+type of command it is), so they can be played back later in the order they were recorded,
+like the commands a game thread hands to a render thread every frame.
+
+The commands are kept as bytes without any padding between them, so they are never aligned
+in memory, [`iter`](CommandBuffer::iter) gives their tags and bytes and [`decode`] reads
+a command back as it's type.
+ */
+
+use crate::slice::DataSlice;
+use core::convert::TryFrom;
+use core::ops::DerefMut;
+
+const HEADER: usize = 8;
+
+/// Reads a copy of the command as a `T`, or [None] if it's tag is not the given one
+/// or it is not of the size of `T`.
+/// 
+/// This is made to be used with the commands given by [`CommandBuffer::iter`].
+/// 
+/// # SAFETY
+/// - Make sure the commands with the given tag are valid T's
+/// - Make sure the value gotten is not dropped more then once if `T` is not [`Copy`],
+///   since it is a bitwise copy of the bytes of the command
+pub unsafe fn decode<T>(tag: u32, command: (u32, &DataSlice)) -> Option<T> {
+    match command {
+        (got, bytes) if got == tag && bytes.size() == core::mem::size_of::<T>() => {
+            Some(bytes.as_ptr().cast::<T>().read_unaligned())
+        },
+        _ => None,
+    }
+}
+
+/// A buffer of commands of any type inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct CommandBuffer<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    end: usize,
+    len: usize,
+}
+
+impl<D: DerefMut<Target = DataSlice>> CommandBuffer<D> {
+    /// Constructs a new empty [`CommandBuffer`] in the given data.
+    #[inline]
+    pub fn new(data: D) -> CommandBuffer<D> {
+        CommandBuffer { data, end: 0, len: 0 }
+    }
+
+    /// Get's the amount of bytes the buffer can use.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Get's the amount of bytes used by the commands, headers included.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.end
+    }
+
+    /// Get's the amount of bytes that are not used yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.size() - self.end
+    }
+
+    /// Get's the amount of commands in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the buffer has no commands.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get's the part of the remaining bytes a command of `size` bytes goes in, after writing it's header.
+    fn reserve(&mut self, tag: u32, size: usize) -> Option<&mut [u8]> {
+        let len: u32 = u32::try_from(size).ok()?;
+        let end: usize = HEADER.checked_add(size).filter(#[inline] |needed| *needed <= self.remaining())? + self.end;
+
+        self.data.inner[self.end..self.end + 4].copy_from_slice(&len.to_le_bytes());
+        self.data.inner[self.end + 4..self.end + HEADER].copy_from_slice(&tag.to_le_bytes());
+
+        let start: usize = self.end + HEADER;
+        self.end = end;
+        self.len += 1;
+        Some(&mut self.data.inner[start..end])
+    }
+
+    /// Records the bytes as a command with the given tag.
+    /// 
+    /// Will return false (recording nothing) if the command with it's header does not fit
+    /// in the remaining bytes, or if it is bigger then [u32::MAX] bytes.
+    pub fn push_bytes(&mut self, tag: u32, bytes: &[u8]) -> bool {
+        match self.reserve(tag, bytes.len()) {
+            Some(command) => {
+                command.copy_from_slice(bytes);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Records the value as a command with the given tag.
+    /// 
+    /// The value is never dropped by the buffer, it is only moved out by [`decode`].
+    /// 
+    /// # ERRORS
+    /// Will return the value back if the command with it's header does not fit in the remaining bytes,
+    /// or if it is bigger then [u32::MAX] bytes.
+    /// 
+    /// # SAFETY
+    /// - Make sure `T` has no padding (or any other uninitialized) bytes,
+    ///   since the bytes of the commands are given out by [`iter`](CommandBuffer::iter)
+    pub unsafe fn push_value<T>(&mut self, tag: u32, value: T) -> Result<(), T> {
+        match self.reserve(tag, core::mem::size_of::<T>()) {
+            Some(command) => {
+                // SAFETY: The command is of the size of a T.
+                command.as_mut_ptr().cast::<T>().write_unaligned(value);
+                Ok(())
+            },
+            None => Err(value),
+        }
+    }
+
+    /// Get's an iterator over the tags and the bytes of the commands, in the order they were recorded.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &DataSlice)> + '_ {
+        let mut pos: usize = 0;
+
+        (0..self.len).map(
+            #[inline] move |_| {
+                let mut word: [u8; 4] = [0; 4];
+
+                word.copy_from_slice(&self.data.inner[pos..pos + 4]);
+                let size: usize = u32::from_le_bytes(word) as usize;
+                word.copy_from_slice(&self.data.inner[pos + 4..pos + HEADER]);
+                let tag: u32 = u32::from_le_bytes(word);
+
+                let start: usize = pos + HEADER;
+                pos = start + size;
+
+                (tag, DataSlice::from_slice(&self.data.inner[start..pos]))
+            }
+        )
+    }
+
+    /// Removes all the commands without dropping them, so the buffer can be recorded again.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.end = 0;
+        self.len = 0;
+    }
+
+    /// Gives back the underlying data, without dropping the commands.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for CommandBuffer<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CommandBuffer")
+            .field("len", &self.len)
+            .field("used", &self.end)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
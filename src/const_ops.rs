@@ -1,5 +1,6 @@
 
 use crate::idx;
+#[cfg(feature = "ptr_metadata")]
 use crate::GetSizeOf;
 
 /// Checks weather an index at a surtun location with a surtun size is readable.
@@ -8,7 +9,7 @@ use crate::GetSizeOf;
 /// this function checks for is that the slice of size `size` starting from
 /// the index `idx` fits fully within the allocated/stored memory region of
 /// the data structure.
-/// (aka: `idx + size < self.size()`)
+/// (aka: `idx + size <= self.size()`)
 /// 
 /// Meaning of each input:
 /// - `idx`: The starting index of the check.
@@ -20,12 +21,12 @@ use crate::GetSizeOf;
 /// safety requirments (if any) are also satisfied.
 pub const fn validity(slice: &[u8], idx: usize, size: usize) -> Result<(), idx::IdxError> {
     if match idx.checked_add(size) {
-        Some(size) => size < slice.len(),
+        Some(size) => size <= slice.len(),
         None => false,
     } {
         Ok(())
     } else {
-        Err(idx::IdxError { idx, data_size: slice.len(), type_size: size })
+        Err(idx::IdxError::new(idx, slice.len(), size))
     }
 }
 
@@ -225,7 +226,7 @@ pub const unsafe fn read_ref<T: Sized>(slice: &[u8], idx: usize) -> Result<&T, c
 pub const unsafe fn read_unchecked<T: Sized>(slice: &[u8], idx: usize) -> *const T {
     unsafe {
         // SAFETY: Must be upheld by the caller.
-        (slice as *const [u8]).cast::<T>().add(idx)
+        slice.as_ptr().add(idx).cast::<T>()
     }
 }
 
@@ -288,7 +289,7 @@ pub const unsafe fn read_ref_mut<T: Sized>(slice: &mut [u8], idx: usize) -> Resu
 pub const unsafe fn read_mut_unchecked<T: Sized>(slice: &mut [u8], idx: usize) -> *mut T {
     unsafe {
         // SAFETY: Must be upheld by the caller.
-        (slice as *mut [u8]).cast::<T>().add(idx)
+        slice.as_mut_ptr().add(idx).cast::<T>()
     }
 }
 
@@ -313,7 +314,6 @@ pub const unsafe fn read_ref_mut_unchecked<T: Sized>(slice: &mut [u8], idx: usiz
 /// 
 /// If you know T is sized use [read](RawDataStructure::read) instead.
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub fn read_unsized<T: ?Sized + core::ptr::Pointee>(slice: &[u8], idx: usize, meta: T::Metadata) -> Result<*const T, idx::IdxError>
 where T::Metadata: GetSizeOf<T> {
     match validity(slice, idx, meta.size()) {
@@ -333,7 +333,6 @@ where T::Metadata: GetSizeOf<T> {
 /// - Make sure the data is aligned
 /// - Make sure the data is valid
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub unsafe fn read_unsized_ref<T: ?Sized + core::ptr::Pointee>(slice: &[u8], idx: usize, meta: T::Metadata) -> Result<&T, idx::IdxError>
 where T::Metadata: crate::GetSizeOf<T> {
     match read_unsized::<T>(slice, idx, meta) {
@@ -354,12 +353,11 @@ where T::Metadata: crate::GetSizeOf<T> {
 /// # SAFETY
 /// Make sure data isn't read from outside the data structure
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub const unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(slice: &[u8], idx: usize, meta: T::Metadata) -> *const T {
     core::ptr::from_raw_parts(
         unsafe {
             // SAFETY: The safety must be upheld by the caller.
-            (slice as *const [u8]).cast::<u8>().add(idx)
+            slice.as_ptr().add(idx)
         },
         meta,
     )
@@ -372,7 +370,6 @@ pub const unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(slice
 /// # SAFETY
 /// Make sure data isn't read from outside the data structure
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub const unsafe fn read_unsized_ref_unchecked<T: ?Sized + core::ptr::Pointee>(slice: &[u8], idx: usize, meta: T::Metadata) -> &T {
     read_unsized_unchecked::<T>(slice, idx, meta)
         .as_ref()
@@ -383,7 +380,6 @@ pub const unsafe fn read_unsized_ref_unchecked<T: ?Sized + core::ptr::Pointee>(s
 /// 
 /// If you know T is sized use [read_mut](RawDataStructure::read_mut) instead.
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub fn read_unsized_mut<T: ?Sized + core::ptr::Pointee>(slice: &mut [u8], idx: usize, meta: T::Metadata) -> Result<*mut T, idx::IdxError>
 where T::Metadata: crate::GetSizeOf<T> {
     validity(slice, idx, meta.size())?;
@@ -402,7 +398,6 @@ where T::Metadata: crate::GetSizeOf<T> {
 /// - Make sure the data is aligned
 /// - Make sure the data is valid
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub unsafe fn read_unsized_ref_mut<T: ?Sized + core::ptr::Pointee>(slice: &mut [u8], idx: usize, meta: T::Metadata) -> Result<&mut T, idx::IdxError>
 where T::Metadata: crate::GetSizeOf<T> {
     match read_unsized_mut::<T>(slice, idx, meta) {
@@ -423,12 +418,11 @@ where T::Metadata: crate::GetSizeOf<T> {
 /// # SAFETY
 /// Make sure data isn't read from outside the data structure
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub const unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(slice: &mut [u8], idx: usize, meta: T::Metadata) -> *mut T {
     core::ptr::from_raw_parts_mut(
         unsafe {
             // SAFETY: The safety must be upheld by the caller.
-            (slice as *mut [u8]).cast::<u8>().add(idx)
+            slice.as_mut_ptr().add(idx)
         },
         meta,
     )
@@ -443,7 +437,6 @@ pub const unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(s
 /// - Make sure the data is aligned
 /// - Make sure the data is valid
 #[cfg(feature = "ptr_metadata")]
-#[allow(private_bounds)]
 pub const unsafe fn read_unsized_ref_mut_unchecked<T: ?Sized + core::ptr::Pointee>(slice: &mut [u8], idx: usize, meta: T::Metadata) -> &mut T {
     read_unsized_mut_unchecked::<T>(slice, idx, meta) // SAFETY: Up to the caller to uphold this safety contract
         .as_mut() // SAFETY: Up to the caller to uphold this safety contract
@@ -476,7 +469,7 @@ pub const unsafe fn take<T: Sized>(slice: &[u8], idx: usize) -> Result<T, idx::I
 /// - Make sure data isn't taken from outside the slice.
 pub const unsafe fn take_unchecked<T: Sized>(slice: &[u8], idx: usize) -> T {
     unsafe {
-        (slice as *const [u8]).cast::<T>().add(idx).read()
+        slice.as_ptr().add(idx).cast::<T>().read()
     }
 }
 
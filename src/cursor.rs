@@ -0,0 +1,584 @@
+
+/*!
+This module provides the [`DataCursor`] and all it's associated
+functions, methods and items.
+
+A [`DataCursor`] is a [`DataSlice`] paired with a position, so values can be
+written and read one after another without keeping track of the index by hand.
+
+Every operation on the cursor advances the position by the amount of bytes it used,
+and if the operation would get out of the slice the position is left untouched
+and an [`IdxError`](idx::IdxError) is returned.
+
+For formats that are not byte aligned there are also the [`BitReader`] and [`BitWriter`]
+cursors, whose position is counted in bits instead of bytes.
+ */
+
+use crate::{
+    idx,
+    slice::DataSlice,
+};
+use core::mem::ManuallyDrop;
+
+/// A sequential reader and writer over a [`DataSlice`].
+/// 
+/// The position always stays in the range `0..=size`.
+#[must_use]
+pub struct DataCursor<'data> {
+    data: &'data mut DataSlice,
+    pos: usize,
+}
+
+impl<'data> DataCursor<'data> {
+    /// Constructs a new [`DataCursor`] starting at the begining of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data mut DataSlice) -> DataCursor<'data> {
+        DataCursor { data, pos: 0 }
+    }
+
+    /// Get's the current position of the cursor.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Get's the current position of the cursor as a [`ByteOffset`](crate::layout::ByteOffset).
+    #[inline]
+    pub const fn offset(&self) -> crate::layout::ByteOffset {
+        crate::layout::ByteOffset(self.pos)
+    }
+
+    /// Set's the current position of the cursor.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the position is greater then the size of the underlying slice.
+    pub const fn set_position(&mut self, pos: usize) -> Result<(), idx::IdxError> {
+        if pos > self.data.size() {
+            return Err(idx::IdxError::new(pos, self.data.size(), 0))
+        }
+
+        self.pos = pos;
+        Ok(())
+    }
+
+    /// Get's the amount of bytes left between the position and the end of the slice.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.data.size() - self.pos
+    }
+
+    /// Get's a refrence to the underlying slice.
+    #[inline]
+    pub const fn get_ref(&self) -> &DataSlice {
+        self.data
+    }
+
+    /// Get's a mutable refrence to the underlying slice.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut DataSlice {
+        self.data
+    }
+
+    /// Gives back the underlying slice.
+    #[inline]
+    pub const fn into_inner(self) -> &'data mut DataSlice {
+        self.data
+    }
+
+    /// Moves the position forward by `size` bytes.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the new position would be past the end of the slice.
+    pub const fn skip(&mut self, size: usize) -> Result<(), idx::IdxError> {
+        match self.claim(size) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Moves the position forward by `size` bytes, giving back the position those bytes start at
+    /// so they can be filled in by hand.
+    /// 
+    /// This is what the writers built on top of the cursor (like the
+    /// [`TlvWriter`](crate::tlv::TlvWriter) and the [`PacketBuilder`](crate::packet::PacketBuilder)) use.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the new position would be past the end of the slice.
+    pub const fn claim(&mut self, size: usize) -> Result<usize, idx::IdxError> {
+        if size > self.remaining() {
+            return Err(idx::IdxError::new(self.pos, self.data.size(), size))
+        }
+
+        let at: usize = self.pos;
+        self.pos += size;
+        Ok(at)
+    }
+
+    /// Get's the bytes before the current position.
+    #[inline]
+    pub fn written(&self) -> &DataSlice {
+        DataSlice::from_slice(&self.data.inner[..self.pos])
+    }
+
+    /// Get's the bytes before the current position mutably.
+    #[inline]
+    pub fn written_mut(&mut self) -> &mut DataSlice {
+        DataSlice::from_slice_mut(&mut self.data.inner[..self.pos])
+    }
+
+    /// Moves the position forward to the next multiple of `align`.
+    /// 
+    /// The alignment is relative to the start of the slice, not to the address in memory.
+    /// 
+    /// # PANICS
+    /// Will panic if `align` is `0`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the new position would be past the end of the slice.
+    pub const fn align_to(&mut self, align: usize) -> Result<(), idx::IdxError> {
+        self.skip(crate::layout::padding_needed(self.pos, align))
+    }
+
+    /// Writes the given value at the current position and moves past it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit in the remaining bytes.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    pub const unsafe fn write_value<T: Sized>(&mut self, value: ManuallyDrop<T>) -> Result<(), (ManuallyDrop<T>, idx::TypedIdxError)> {
+        match self.data.write(self.pos, value) {
+            Ok(()) => {
+                self.pos += core::mem::size_of::<T>();
+                Ok(())
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Takes the value at the current position and moves past it.
+    /// 
+    /// Note: This does NOT zero out the specified region
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit in the remaining bytes.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    pub unsafe fn read_value<T: Sized>(&mut self) -> Result<T, idx::TypedIdxError> {
+        let value: T = self.data.take::<T>(self.pos)?;
+        self.pos += core::mem::size_of::<T>();
+        Ok(value)
+    }
+
+    /// Writes the given value as a LEB128 variable length integer and moves past it.
+    /// 
+    /// Returns the amount of bytes written.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not fit in the remaining bytes.
+    pub const fn write_varint_u64(&mut self, value: u64) -> Result<usize, idx::IdxError> {
+        match self.data.write_varint_u64(self.pos, value) {
+            Ok(len) => {
+                self.pos += len;
+                Ok(len)
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads a LEB128 variable length integer and moves past it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not end before the end
+    /// of the slice or if it does not fit in a [u64].
+    pub const fn read_varint_u64(&mut self) -> Result<u64, crate::varint::VarintError> {
+        match self.data.read_varint_u64(self.pos) {
+            Ok((value, len)) => {
+                self.pos += len;
+                Ok(value)
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the given value as a zig-zag encoded LEB128 variable length integer and moves past it.
+    /// 
+    /// Returns the amount of bytes written.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not fit in the remaining bytes.
+    #[inline]
+    pub const fn write_varint_i64(&mut self, value: i64) -> Result<usize, idx::IdxError> {
+        self.write_varint_u64(crate::varint::zigzag_encode(value))
+    }
+
+    /// Reads a zig-zag encoded LEB128 variable length integer and moves past it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not end before the end
+    /// of the slice or if it does not fit in an [i64].
+    #[inline]
+    pub const fn read_varint_i64(&mut self) -> Result<i64, crate::varint::VarintError> {
+        match self.read_varint_u64() {
+            Ok(value) => Ok(crate::varint::zigzag_decode(value)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+impl DataCursor<'_> {
+    /// Moves the cursor to the given absolute position if it is inside of `0..=size`.
+    fn seek_to(&mut self, pos: Option<u64>) -> Option<u64> {
+        match pos {
+            Some(pos) if pos <= self.data.size() as u64 => {
+                self.pos = pos as usize;
+                Some(pos)
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<'data> From<&'data mut DataSlice> for DataCursor<'data> {
+    #[inline] fn from(data: &'data mut DataSlice) -> DataCursor<'data> {
+        DataCursor::new(data)
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::std::io::Read for DataCursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> crate::std::io::Result<usize> {
+        let size: usize = core::cmp::min(buf.len(), self.remaining());
+
+        buf[..size].copy_from_slice(&self.data.inner[self.pos..self.pos + size]);
+        self.pos += size;
+        Ok(size)
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::std::io::Write for DataCursor<'_> {
+    fn write(&mut self, buf: &[u8]) -> crate::std::io::Result<usize> {
+        let size: usize = core::cmp::min(buf.len(), self.remaining());
+
+        self.data.inner[self.pos..self.pos + size].copy_from_slice(&buf[..size]);
+        self.pos += size;
+        Ok(size)
+    }
+
+    #[inline] fn flush(&mut self) -> crate::std::io::Result<()> { Ok(()) }
+}
+
+/// Seeking outside of `0..=size` returns an [`InvalidInput`](crate::std::io::ErrorKind::InvalidInput) error,
+/// since the cursor can never be past the end of the slice.
+#[cfg(feature = "std")]
+impl crate::std::io::Seek for DataCursor<'_> {
+    fn seek(&mut self, pos: crate::std::io::SeekFrom) -> crate::std::io::Result<u64> {
+        use crate::std::io::SeekFrom;
+
+        let pos: Option<u64> = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (self.data.size() as u64).checked_add_signed(offset),
+            SeekFrom::Current(offset) => (self.pos as u64).checked_add_signed(offset),
+        };
+
+        self.seek_to(pos).ok_or_else(|| crate::std::io::Error::new(
+            crate::std::io::ErrorKind::InvalidInput,
+            "Tried to seek outside of the DataCursor's slice.",
+        ))
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for DataCursor<'_> {
+    type Error = embedded_io::ErrorKind;
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Read for DataCursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let size: usize = core::cmp::min(buf.len(), self.remaining());
+
+        buf[..size].copy_from_slice(&self.data.inner[self.pos..self.pos + size]);
+        self.pos += size;
+        Ok(size)
+    }
+}
+
+/// Writing into a full cursor returns a [`WriteZero`](embedded_io::ErrorKind::WriteZero) error,
+/// as [`embedded_io::Write`] does not allow returning `Ok(0)` for non empty buffers.
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Write for DataCursor<'_> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let size: usize = core::cmp::min(buf.len(), self.remaining());
+
+        if size == 0 && !buf.is_empty() {
+            return Err(embedded_io::ErrorKind::WriteZero)
+        }
+
+        self.data.inner[self.pos..self.pos + size].copy_from_slice(&buf[..size]);
+        self.pos += size;
+        Ok(size)
+    }
+
+    #[inline] fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+}
+
+/// Seeking outside of `0..=size` returns an [`InvalidInput`](embedded_io::ErrorKind::InvalidInput) error,
+/// since the cursor can never be past the end of the slice.
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Seek for DataCursor<'_> {
+    fn seek(&mut self, pos: embedded_io::SeekFrom) -> Result<u64, Self::Error> {
+        let pos: Option<u64> = match pos {
+            embedded_io::SeekFrom::Start(offset) => Some(offset),
+            embedded_io::SeekFrom::End(offset) => (self.data.size() as u64).checked_add_signed(offset),
+            embedded_io::SeekFrom::Current(offset) => (self.pos as u64).checked_add_signed(offset),
+        };
+
+        self.seek_to(pos).ok_or(embedded_io::ErrorKind::InvalidInput)
+    }
+}
+
+impl core::fmt::Debug for DataCursor<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataCursor")
+            .field("position", &self.pos)
+            .field("size", &self.data.size())
+            .finish()
+    }
+}
+
+/// Masks the lowest `bits` bits (`bits` must be at most 8).
+#[inline(always)]
+const fn low_mask(bits: usize) -> u8 {
+    ((1u16 << bits) - 1) as u8
+}
+
+/// A sequential bit level reader over a [`DataSlice`].
+/// 
+/// Bits are read starting from the most significant bit of each byte,
+/// and the first bit read ends up being the most significant bit of the returned value.
+/// 
+/// The [`IdxError`](idx::IdxError)'s given by this reader count the index and sizes in bits, not bytes.
+#[must_use]
+#[derive(Clone, Copy)]
+pub struct BitReader<'data> {
+    data: &'data DataSlice,
+    pos: usize,
+}
+
+impl<'data> BitReader<'data> {
+    /// Constructs a new [`BitReader`] starting at the first bit of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data DataSlice) -> BitReader<'data> {
+        BitReader { data, pos: 0 }
+    }
+
+    /// Get's the current position of the reader in bits.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Get's the amount of bits left to be read.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.data.size() * 8 - self.pos
+    }
+
+    /// Get's a refrence to the underlying slice.
+    #[inline]
+    pub const fn get_ref(&self) -> &'data DataSlice {
+        self.data
+    }
+
+    /// Checks weather the reader is at the start of a byte.
+    #[inline]
+    pub const fn is_byte_aligned(&self) -> bool {
+        self.pos.is_multiple_of(8)
+    }
+
+    /// Reads the next `bits` bits without moving the position.
+    /// 
+    /// # PANICS
+    /// Will panic if `bits` is greater then `64`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there are not enough bits left.
+    pub const fn peek_bits(&self, bits: usize) -> Result<u64, idx::IdxError> {
+        assert!(bits <= 64, "Can not read more then 64 bits at once.");
+
+        if bits > self.remaining() {
+            return Err(idx::IdxError::new(self.pos, self.data.size() * 8, bits))
+        }
+
+        let mut value: u64 = 0;
+        let mut pos: usize = self.pos;
+        let mut left: usize = bits;
+
+        while left > 0 {
+            let avail: usize = 8 - pos % 8;
+            let take: usize = if avail < left { avail } else { left };
+            let chunk: u8 = (self.data.inner[pos / 8] >> (avail - take)) & low_mask(take);
+
+            value = (value << take) | chunk as u64;
+            pos += take;
+            left -= take;
+        }
+
+        Ok(value)
+    }
+
+    /// Reads the next `bits` bits and moves past them.
+    /// 
+    /// # PANICS
+    /// Will panic if `bits` is greater then `64`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there are not enough bits left.
+    pub const fn read_bits(&mut self, bits: usize) -> Result<u64, idx::IdxError> {
+        match self.peek_bits(bits) {
+            Ok(value) => {
+                self.pos += bits;
+                Ok(value)
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads a single bit and moves past it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there are no bits left.
+    #[inline]
+    pub const fn read_bit(&mut self) -> Result<bool, idx::IdxError> {
+        match self.read_bits(1) {
+            Ok(bit) => Ok(bit == 1),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Moves the position forward by `bits` bits.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the new position would be past the end of the slice.
+    pub const fn skip_bits(&mut self, bits: usize) -> Result<(), idx::IdxError> {
+        if bits > self.remaining() {
+            return Err(idx::IdxError::new(self.pos, self.data.size() * 8, bits))
+        }
+
+        self.pos += bits;
+        Ok(())
+    }
+
+    /// Moves the position forward to the start of the next byte.
+    /// 
+    /// Does nothing if the reader is already at the start of a byte.
+    #[inline]
+    pub const fn align_to_byte(&mut self) {
+        // Can never get out of the slice since the slice is made of whole bytes.
+        self.pos = self.pos.next_multiple_of(8);
+    }
+}
+
+/// A sequential bit level writer over a [`DataSlice`].
+/// 
+/// Bits are written starting from the most significant bit of each byte,
+/// in the same order a [`BitReader`] would read them.
+/// 
+/// The [`IdxError`](idx::IdxError)'s given by this writer count the index and sizes in bits, not bytes.
+#[must_use]
+pub struct BitWriter<'data> {
+    data: &'data mut DataSlice,
+    pos: usize,
+}
+
+impl<'data> BitWriter<'data> {
+    /// Constructs a new [`BitWriter`] starting at the first bit of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data mut DataSlice) -> BitWriter<'data> {
+        BitWriter { data, pos: 0 }
+    }
+
+    /// Get's the current position of the writer in bits.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Get's the amount of bits left to be written.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.data.size() * 8 - self.pos
+    }
+
+    /// Gives back the underlying slice.
+    #[inline]
+    pub const fn into_inner(self) -> &'data mut DataSlice {
+        self.data
+    }
+
+    /// Checks weather the writer is at the start of a byte.
+    #[inline]
+    pub const fn is_byte_aligned(&self) -> bool {
+        self.pos.is_multiple_of(8)
+    }
+
+    /// Writes the lowest `bits` bits of `value` and moves past them.
+    /// 
+    /// Only the written bits are changed, the rest of the bits in the touched bytes are kept.
+    /// 
+    /// # PANICS
+    /// Will panic if `bits` is greater then `64`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there are not enough bits left.
+    pub const fn write_bits(&mut self, value: u64, bits: usize) -> Result<(), idx::IdxError> {
+        assert!(bits <= 64, "Can not write more then 64 bits at once.");
+
+        if bits > self.remaining() {
+            return Err(idx::IdxError::new(self.pos, self.data.size() * 8, bits))
+        }
+
+        let mut left: usize = bits;
+
+        while left > 0 {
+            let avail: usize = 8 - self.pos % 8;
+            let take: usize = if avail < left { avail } else { left };
+            let shift: usize = avail - take;
+            let chunk: u8 = (value >> (left - take)) as u8 & low_mask(take);
+            let mask: u8 = low_mask(take) << shift;
+            let byte: &mut u8 = &mut self.data.inner[self.pos / 8];
+
+            *byte = (*byte & !mask) | (chunk << shift);
+            self.pos += take;
+            left -= take;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single bit and moves past it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there are no bits left.
+    #[inline]
+    pub const fn write_bit(&mut self, bit: bool) -> Result<(), idx::IdxError> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    /// Moves the position forward to the start of the next byte.
+    /// 
+    /// The skipped bits are left untouched.
+    /// Does nothing if the writer is already at the start of a byte.
+    #[inline]
+    pub const fn align_to_byte(&mut self) {
+        // Can never get out of the slice since the slice is made of whole bytes.
+        self.pos = self.pos.next_multiple_of(8);
+    }
+}
@@ -0,0 +1,271 @@
+
+/*!
+This module provides the type erased containers and all their associated
+functions, methods and items.
+
+A [`Column`] is a growable array of values of a single type that is only known at runtime
+by it's [`Layout`] and it's drop function, like the component storage of an ECS where every
+archetype has a column for each of it's components. The values are kept one after another
+(aligned for their layout) in a [`DataBoxed`] that is reallocated when it is full.
+
+Values are moved in and out of a column as raw bytes, and removing a value moves the last one
+into it's place, so the order of the values is not kept.
+
+Since the type of the values is not known, a [`Column`] is neither [`Send`] nor [`Sync`],
+as the values might not be.
+ */
+
+use crate::{
+    boxed::DataBoxed,
+    layout,
+};
+use crate::alloc::collections::TryReserveError;
+use core::alloc::Layout;
+use core::marker::PhantomData;
+
+/// The function a [`Column`] drops it's values with.
+pub type DropFn = unsafe fn(*mut u8);
+
+/// A growable array of values of a type that is only known by it's layout.
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct Column {
+    data: DataBoxed,
+    /// The offset of the first value in the data, so it is aligned in memory.
+    offset: usize,
+    layout: Layout,
+    drop: Option<DropFn>,
+    len: usize,
+    capacity: usize,
+    /// The values might not be [`Send`] or [`Sync`].
+    _marker: PhantomData<*mut u8>,
+}
+
+impl Column {
+    /// Constructs a new empty [`Column`] for values of the given layout, dropped with the given function
+    /// (or never dropped if it is [None]).
+    /// 
+    /// The size of the layout is padded to it's alignment, like the size of a Rust type is.
+    /// 
+    /// # SAFETY
+    /// - Make sure the drop function can drop any value pushed to the column
+    #[inline]
+    pub unsafe fn new(layout: Layout, drop: Option<DropFn>) -> Column {
+        let layout: Layout = layout.pad_to_align();
+
+        Column {
+            data: DataBoxed::empty(),
+            offset: 0,
+            capacity: if layout.size() == 0 { usize::MAX } else { 0 },
+            layout,
+            drop,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Constructs a new empty [`Column`] for values of the type `T`.
+    #[inline]
+    pub fn of<T>() -> Column {
+        unsafe fn drop<T>(ptr: *mut u8) {
+            core::ptr::drop_in_place(ptr.cast::<T>());
+        }
+
+        // SAFETY: The drop function drops a T.
+        unsafe { Column::new(Layout::new::<T>(), if core::mem::needs_drop::<T>() { Some(drop::<T>) } else { None }) }
+    }
+
+    /// Get's the layout of the values (with it's size padded to it's alignment).
+    #[inline]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Get's the amount of values in the column.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the column has no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get's the amount of values the column can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get's a pointer to the first value, which is dangling (but aligned) for zero sized values.
+    #[inline]
+    fn base(&self) -> *const u8 {
+        match self.layout.size() {
+            0 => core::ptr::without_provenance(self.layout.align()),
+            _ => self.data.as_ptr().wrapping_add(self.offset),
+        }
+    }
+
+    #[inline]
+    fn base_mut(&mut self) -> *mut u8 {
+        match self.layout.size() {
+            0 => core::ptr::without_provenance_mut(self.layout.align()),
+            _ => self.data.as_mut_ptr().wrapping_add(self.offset),
+        }
+    }
+
+    /// Makes sure the column can hold at least `additional` more values without reallocating.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation fails (always panics without the `allocator_api` feature).
+    /// 
+    /// # PANICS
+    /// Will panic if the size of the column overflows an [usize].
+    pub fn reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let needed: usize = self.len.checked_add(additional).expect("The capacity of a `Column` overflowed.");
+
+        if needed <= self.capacity {
+            return Ok(())
+        }
+
+        let capacity: usize = needed.max(self.capacity.saturating_mul(2)).max(4);
+        let size: usize = capacity.checked_mul(self.layout.size())
+            .and_then(#[inline] |size| size.checked_add(self.layout.align() - 1))
+            .expect("The capacity of a `Column` overflowed.");
+
+        let mut data: DataBoxed = DataBoxed::uninit(size)?;
+        let offset: usize = layout::padding_needed(data.as_ptr().addr(), self.layout.align());
+
+        // SAFETY: Both regions hold `len` values and are in diferent allocations.
+        unsafe { core::ptr::copy_nonoverlapping(self.base(), data.as_mut_ptr().add(offset), self.len * self.layout.size()); }
+
+        self.data = data;
+        self.offset = offset;
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    /// Moves the value the pointer points to to the end of the column, copying the size of the layout in bytes.
+    /// 
+    /// # ERRORS
+    /// Will return an error (pushing nothing) if the allocation fails (always panics without the `allocator_api` feature).
+    /// 
+    /// # SAFETY
+    /// - Make sure the pointer points to a valid value of the layout of the column
+    /// - Make sure that the value is not used (or dropped) again after being given to this funtion
+    pub unsafe fn push_raw(&mut self, value: *const u8) -> Result<(), TryReserveError> {
+        self.reserve(1)?;
+
+        let at: *mut u8 = self.base_mut().wrapping_add(self.len * self.layout.size());
+        core::ptr::copy_nonoverlapping(value, at, self.layout.size());
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Get's a pointer to the value at the given index, or [None] if there is no such value.
+    /// 
+    /// The pointer is aligned for the layout of the column and is valid until the column is changed.
+    #[inline]
+    pub fn get_ptr(&self, idx: usize) -> Option<*const u8> {
+        if idx >= self.len {
+            return None
+        }
+
+        Some(self.base().wrapping_add(idx * self.layout.size()))
+    }
+
+    /// Get's a mutable pointer to the value at the given index, or [None] if there is no such value.
+    /// 
+    /// The pointer is aligned for the layout of the column and is valid until the column is changed.
+    #[inline]
+    pub fn get_mut_ptr(&mut self, idx: usize) -> Option<*mut u8> {
+        if idx >= self.len {
+            return None
+        }
+
+        Some(self.base_mut().wrapping_add(idx * self.layout.size()))
+    }
+
+    /// Moves the value at the given index out of the column into `out`, without dropping it,
+    /// and moves the last value into it's place.
+    /// 
+    /// Will return false (moving nothing) if there is no such value.
+    /// 
+    /// # SAFETY
+    /// - Make sure `out` is valid for writes of the size of the layout of the column
+    pub unsafe fn swap_remove_raw(&mut self, idx: usize, out: *mut u8) -> bool {
+        if idx >= self.len {
+            return false
+        }
+
+        let (last, size): (usize, usize) = (self.len - 1, self.layout.size());
+        let base: *mut u8 = self.base_mut();
+
+        core::ptr::copy_nonoverlapping(base.wrapping_add(idx * size), out, size);
+        if idx != last {
+            core::ptr::copy_nonoverlapping(base.wrapping_add(last * size), base.wrapping_add(idx * size), size);
+        }
+
+        self.len = last;
+        true
+    }
+
+    /// Drops the value at the given index and moves the last value into it's place.
+    /// 
+    /// Will return false if there is no such value.
+    pub fn swap_remove(&mut self, idx: usize) -> bool {
+        if idx >= self.len {
+            return false
+        }
+
+        let (last, size): (usize, usize) = (self.len - 1, self.layout.size());
+        let base: *mut u8 = self.base_mut();
+
+        // The value is swapped past the end of the column before it is dropped,
+        // so a panic while dropping it does not drop it again.
+        if idx != last {
+            // SAFETY: Both values are in the column and are not the same one.
+            unsafe { core::ptr::swap_nonoverlapping(base.wrapping_add(idx * size), base.wrapping_add(last * size), size); }
+        }
+
+        self.len = last;
+        if let Some(drop) = self.drop {
+            // SAFETY: The drop function can drop any value of the column.
+            unsafe { drop(base.wrapping_add(last * size)); }
+        }
+
+        true
+    }
+
+    /// Drops all the values of the column, keeping it's capacity.
+    pub fn clear(&mut self) {
+        let len: usize = self.len;
+        self.len = 0;
+
+        if let Some(drop) = self.drop {
+            for idx in 0..len {
+                // SAFETY: The drop function can drop any value of the column.
+                unsafe { drop(self.base_mut().wrapping_add(idx * self.layout.size())); }
+            }
+        }
+    }
+}
+
+impl Drop for Column {
+    #[inline] fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl core::fmt::Debug for Column {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Column")
+            .field("layout", &self.layout)
+            .field("len", &self.len)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
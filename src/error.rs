@@ -0,0 +1,257 @@
+
+/*!
+This module provides the [`Error`] type, which can hold any error given by this crate,
+so code that uses many parts of this crate does not need a separate error for each of them.
+
+Every error of this crate converts into it with [`From`], so `?` can be used directly.
+ */
+
+use crate::{
+    idx,
+    varint,
+    guarded,
+};
+
+/// Any error given by this crate.
+/// 
+/// More errors may be added in the future, so matching on it needs a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A data structure could not be accessed in the given region.
+    Idx(idx::IdxError),
+    /// A data structure could not be accessed in the given region as the given type.
+    TypedIdx(idx::TypedIdxError),
+    /// An allocation failed.
+    #[cfg(feature = "alloc")]
+    Alloc(crate::alloc::collections::TryReserveError),
+    /// A conversion was given data of a diferent size then the one it needs.
+    DiferentSizes {
+        /// The size that was needed.
+        expected: usize,
+        /// The size that was gotten.
+        gotten: usize,
+    },
+    /// A variable length integer could not be decoded.
+    Varint(varint::VarintError),
+    /// A value could not be borrowed from a [`DataCells`](crate::cells::DataCells).
+    #[cfg(feature = "alloc")]
+    Borrow(crate::cells::BorrowError),
+    /// A canary of a [`GuardedData`](guarded::GuardedData) was changed.
+    Canary(guarded::CanaryError),
+    /// A record did not fit in a [`DataLog`](crate::sync::DataLog).
+    #[cfg(feature = "std")]
+    LogFull(crate::sync::LogFullError),
+    /// A record could not be laid out or a field of it could not be accessed.
+    #[cfg(feature = "alloc")]
+    Schema(crate::schema::SchemaError),
+    /// An allocation did not fit in a [`DataArena`](crate::arena::DataArena).
+    Arena(crate::arena::ArenaError),
+    /// An allocation did not fit in an in-buffer allocator like the [`DataHeap`](crate::heap::DataHeap).
+    Oom(crate::heap::OomError),
+    /// An offset given to an in-buffer allocator was not an allocation.
+    Free(crate::heap::FreeError),
+    /// Bytes did not fit in a [`DataRing`](crate::ring::DataRing).
+    RingFull(crate::ring::RingFullError),
+    /// An entry did not fit in a [`DataMap`](crate::map::DataMap).
+    MapFull(crate::map::MapFullError),
+    /// A value could not be written to or read from a [`CheckedData`](crate::checked::CheckedData).
+    #[cfg(feature = "alloc")]
+    Any(crate::checked::AnyError),
+    /// Bytes could not be read as a string.
+    Utf8(idx::Utf8OrIdxError),
+    /// Hex text could not be decoded.
+    Hex(crate::hex::HexError),
+    /// The entropy source of the system could not be used.
+    #[cfg(feature = "getrandom")]
+    Random(getrandom::Error),
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Idx(err) => Some(err),
+            Error::TypedIdx(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Error::Alloc(err) => Some(err),
+            Error::DiferentSizes { .. } => None,
+            Error::Varint(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Error::Borrow(err) => Some(err),
+            Error::Canary(err) => Some(err),
+            #[cfg(feature = "std")]
+            Error::LogFull(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Error::Schema(err) => Some(err),
+            Error::Arena(err) => Some(err),
+            Error::Oom(err) => Some(err),
+            Error::Free(err) => Some(err),
+            Error::RingFull(err) => Some(err),
+            Error::MapFull(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Error::Any(err) => Some(err),
+            Error::Utf8(err) => Some(err),
+            Error::Hex(err) => Some(err),
+            #[cfg(all(feature = "getrandom", feature = "std"))]
+            Error::Random(err) => Some(err),
+            #[cfg(all(feature = "getrandom", not(feature = "std")))]
+            Error::Random(_) => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Idx(err) => core::fmt::Display::fmt(err, f),
+            Error::TypedIdx(err) => core::fmt::Display::fmt(err, f),
+            #[cfg(feature = "alloc")]
+            Error::Alloc(err) => core::fmt::Display::fmt(err, f),
+            Error::DiferentSizes { expected, gotten } => write!(
+                f,
+                "Expected data of size `{expected}` but got data of size `{gotten}`.",
+                expected = expected,
+                gotten = gotten,
+            ),
+            Error::Varint(err) => core::fmt::Display::fmt(err, f),
+            #[cfg(feature = "alloc")]
+            Error::Borrow(err) => core::fmt::Display::fmt(err, f),
+            Error::Canary(err) => core::fmt::Display::fmt(err, f),
+            #[cfg(feature = "std")]
+            Error::LogFull(err) => core::fmt::Display::fmt(err, f),
+            #[cfg(feature = "alloc")]
+            Error::Schema(err) => core::fmt::Display::fmt(err, f),
+            Error::Arena(err) => core::fmt::Display::fmt(err, f),
+            Error::Oom(err) => core::fmt::Display::fmt(err, f),
+            Error::Free(err) => core::fmt::Display::fmt(err, f),
+            Error::RingFull(err) => core::fmt::Display::fmt(err, f),
+            Error::MapFull(err) => core::fmt::Display::fmt(err, f),
+            #[cfg(feature = "alloc")]
+            Error::Any(err) => core::fmt::Display::fmt(err, f),
+            Error::Utf8(err) => core::fmt::Display::fmt(err, f),
+            Error::Hex(err) => core::fmt::Display::fmt(err, f),
+            #[cfg(feature = "getrandom")]
+            Error::Random(err) => core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl From<idx::IdxError> for Error {
+    #[inline] fn from(err: idx::IdxError) -> Error {
+        Error::Idx(err)
+    }
+}
+
+impl From<idx::TypedIdxError> for Error {
+    #[inline] fn from(err: idx::TypedIdxError) -> Error {
+        Error::TypedIdx(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<crate::alloc::collections::TryReserveError> for Error {
+    #[inline] fn from(err: crate::alloc::collections::TryReserveError) -> Error {
+        Error::Alloc(err)
+    }
+}
+
+impl<const SIZE: usize> From<crate::array::DiferentSizesError<SIZE>> for Error {
+    #[inline] fn from(err: crate::array::DiferentSizesError<SIZE>) -> Error {
+        Error::DiferentSizes { expected: SIZE, gotten: err.gotten_size() }
+    }
+}
+
+impl From<idx::SizeMismatch> for Error {
+    #[inline] fn from(err: idx::SizeMismatch) -> Error {
+        Error::DiferentSizes { expected: err.expected, gotten: err.gotten }
+    }
+}
+
+impl From<varint::VarintError> for Error {
+    #[inline] fn from(err: varint::VarintError) -> Error {
+        Error::Varint(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<crate::cells::BorrowError> for Error {
+    #[inline] fn from(err: crate::cells::BorrowError) -> Error {
+        Error::Borrow(err)
+    }
+}
+
+impl From<guarded::CanaryError> for Error {
+    #[inline] fn from(err: guarded::CanaryError) -> Error {
+        Error::Canary(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<crate::sync::LogFullError> for Error {
+    #[inline] fn from(err: crate::sync::LogFullError) -> Error {
+        Error::LogFull(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<crate::schema::SchemaError> for Error {
+    #[inline] fn from(err: crate::schema::SchemaError) -> Error {
+        Error::Schema(err)
+    }
+}
+
+impl From<crate::arena::ArenaError> for Error {
+    #[inline] fn from(err: crate::arena::ArenaError) -> Error {
+        Error::Arena(err)
+    }
+}
+
+impl From<crate::heap::OomError> for Error {
+    #[inline] fn from(err: crate::heap::OomError) -> Error {
+        Error::Oom(err)
+    }
+}
+
+impl From<crate::heap::FreeError> for Error {
+    #[inline] fn from(err: crate::heap::FreeError) -> Error {
+        Error::Free(err)
+    }
+}
+
+impl From<crate::ring::RingFullError> for Error {
+    #[inline] fn from(err: crate::ring::RingFullError) -> Error {
+        Error::RingFull(err)
+    }
+}
+
+impl From<crate::map::MapFullError> for Error {
+    #[inline] fn from(err: crate::map::MapFullError) -> Error {
+        Error::MapFull(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<crate::checked::AnyError> for Error {
+    #[inline] fn from(err: crate::checked::AnyError) -> Error {
+        Error::Any(err)
+    }
+}
+
+impl From<idx::Utf8OrIdxError> for Error {
+    #[inline] fn from(err: idx::Utf8OrIdxError) -> Error {
+        Error::Utf8(err)
+    }
+}
+
+impl From<crate::hex::HexError> for Error {
+    #[inline] fn from(err: crate::hex::HexError) -> Error {
+        Error::Hex(err)
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl From<getrandom::Error> for Error {
+    #[inline] fn from(err: getrandom::Error) -> Error {
+        Error::Random(err)
+    }
+}
@@ -0,0 +1,82 @@
+
+/*!
+This module provides the [`FmtWriter`] and all it's associated
+functions, methods and items.
+
+A [`FmtWriter`] lets text be formated straight into a [`DataSlice`] with
+[`write!`](core::write) without needing an allocator.
+ */
+
+use crate::{
+    cursor::DataCursor,
+    slice::DataSlice,
+};
+
+/// A [`core::fmt::Write`] implementor that writes text into a [`DataSlice`].
+/// 
+/// If a piece of text does not fit in the remaining bytes, none of it is written
+/// and [`core::fmt::Error`] is returned.
+#[must_use]
+pub struct FmtWriter<'data> {
+    cursor: DataCursor<'data>,
+}
+
+impl<'data> FmtWriter<'data> {
+    /// Constructs a new [`FmtWriter`] that starts writing at the begining of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data mut DataSlice) -> FmtWriter<'data> {
+        FmtWriter { cursor: DataCursor::new(data) }
+    }
+
+    /// Get's the amount of bytes written so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Get's the amount of bytes left to be written.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.cursor.remaining()
+    }
+
+    /// Get's the text written so far.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            // SAFETY: Only whole `&str`s are ever written and only
+            // through this writer, so the written bytes are valid UTF-8.
+            core::str::from_utf8_unchecked(&self.cursor.written().inner)
+        }
+    }
+
+    /// Finishes writing giving back the amount of bytes written.
+    #[inline]
+    pub const fn finish(self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Gives back the underlying slice.
+    #[inline]
+    pub const fn into_inner(self) -> &'data mut DataSlice {
+        self.cursor.into_inner()
+    }
+}
+
+impl core::fmt::Write for FmtWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let at: usize = self.cursor.claim(s.len()).map_err(|_| core::fmt::Error)?;
+
+        self.cursor.get_mut().inner[at..at + s.len()].copy_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for FmtWriter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FmtWriter")
+            .field("written", &self.as_str())
+            .field("size", &self.cursor.get_ref().size())
+            .finish()
+    }
+}
@@ -0,0 +1,257 @@
+/*!
+This module provides the [`GuardPagedData`] data structure and the [`GuardPages`] allocator,
+made for debugging unsafe code that uses the data structures of this crate.
+
+Every buffer gets it's own pages from the operating system (`mmap` on unix and
+`VirtualAlloc` on windows) with an inaccessible page right before and right after them,
+so any access that gets out of the buffer faults right away instead of silently
+reading or corrupting the data next to it.
+
+The buffer is placed at the end of it's pages, so accesses past the end fault with
+the first byte, accesses before the start fault once they get past the start of the page.
+
+This uses a lot more memory then a normal allocator (at least 3 pages per allocation)
+and is a lot slower, so it should only be used while developing.
+
+The easiest way to use it is [`GuardPagedData::uninit`], which works on stable.
+With the `allocator_api` feature the same pages can back any allocation through the
+[`GuardPages`] allocator, like `DataBoxed::uninit_guarded` does.
+ */
+
+use crate::slice::DataSlice;
+use crate::std::io;
+use core::{
+    alloc::Layout,
+    ptr::NonNull,
+};
+
+/// Maps the pages for a buffer of the given layout, giving back the buffer.
+/// 
+/// Returns [None] if the layout is not possible or the operating system refuses to map the pages.
+fn map(layout: Layout) -> Option<NonNull<[u8]>> {
+    if layout.size() == 0 {
+        return Some(NonNull::slice_from_raw_parts(NonNull::<u8>::dangling().map_addr(
+            // SAFETY: The alignment of a layout is never 0.
+            #[inline] |_| unsafe { core::num::NonZeroUsize::new_unchecked(layout.align()) }
+        ), 0))
+    }
+
+    let page: usize = sys::page_size();
+
+    if layout.align() > page {
+        return None
+    }
+
+    let data_pages: usize = layout.size().checked_next_multiple_of(page)?;
+    let total: usize = data_pages.checked_add(page * 2)?;
+
+    // SAFETY: The size is not 0 and the data pages are inside of the mapping.
+    let base: NonNull<u8> = unsafe { sys::map(total, page, data_pages) }?;
+
+    let end: NonNull<u8> = unsafe {
+        // SAFETY: The end of the data pages is inside of the mapping.
+        base.add(page + data_pages)
+    };
+    let start: NonNull<u8> = end.map_addr(
+        // SAFETY: The start is at least the start of the data pages, so never 0.
+        #[inline] |addr| unsafe { core::num::NonZeroUsize::new_unchecked((addr.get() - layout.size()) & !(layout.align() - 1)) }
+    );
+
+    Some(NonNull::slice_from_raw_parts(start, layout.size()))
+}
+
+/// Unmaps the pages of a buffer given by [`map`].
+/// 
+/// # SAFETY
+/// Make sure the buffer was given by [`map`] with the same layout and is not used anymore.
+unsafe fn unmap(ptr: NonNull<u8>, layout: Layout) {
+    if layout.size() == 0 {
+        return
+    }
+
+    let page: usize = sys::page_size();
+    let data_pages: usize = layout.size().next_multiple_of(page);
+
+    // The buffer always starts in the first data page, right after the guard page.
+    let base: NonNull<u8> = ptr.sub(ptr.addr().get() % page + page);
+
+    sys::unmap(base, data_pages + page * 2);
+}
+
+/// A typeless chunk of data surrounded by inaccessible guard pages.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct GuardPagedData {
+    ptr: NonNull<u8>,
+    size: usize,
+}
+
+// SAFETY: A GuardPagedData owns it's pages and only hands out references to them
+// through the usual borrowing rules, just like a Box<[u8]>.
+unsafe impl Send for GuardPagedData {}
+unsafe impl Sync for GuardPagedData {}
+
+impl GuardPagedData {
+    /// Constructs a new [`GuardPagedData`] without touching the underling data
+    /// (which the operating system gives filled with `0`'s).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the size is too big or if the operating system refuses to map the pages.
+    pub fn uninit(size: usize) -> io::Result<GuardPagedData> {
+        let layout: Layout = Layout::array::<u8>(size).map_err(
+            #[inline] |_| io::Error::new(io::ErrorKind::InvalidInput, "The size is too big to be allocated.")
+        )?;
+
+        match map(layout) {
+            Some(ptr) => Ok(GuardPagedData { ptr: ptr.cast(), size }),
+            None => Err(io::Error::new(io::ErrorKind::OutOfMemory, "Could not map the pages of the data.")),
+        }
+    }
+
+    /// Get's the size of the data.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Drop for GuardPagedData {
+    #[inline] fn drop(&mut self) {
+        // SAFETY: The pointer and layout are the ones the pages were mapped with.
+        unsafe { unmap(self.ptr, Layout::array::<u8>(self.size).unwrap_unchecked()) }
+    }
+}
+
+impl core::ops::Deref for GuardPagedData {
+    type Target = DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        DataSlice::from_slice(unsafe {
+            // SAFETY: The pages are valid for `size` bytes for as long as self lives.
+            core::slice::from_raw_parts(self.ptr.as_ptr(), self.size)
+        })
+    }
+}
+
+impl core::ops::DerefMut for GuardPagedData {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        DataSlice::from_slice_mut(unsafe {
+            // SAFETY: The pages are valid for `size` bytes for as long as self lives.
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.size)
+        })
+    }
+}
+
+impl core::fmt::Debug for GuardPagedData {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <DataSlice as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+/// An allocator that surrounds every allocation with inaccessible guard pages.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GuardPages;
+
+#[cfg(feature = "allocator_api")]
+unsafe impl crate::alloc::alloc::Allocator for GuardPages {
+    #[inline] fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, crate::alloc::alloc::AllocError> {
+        map(layout).ok_or(crate::alloc::alloc::AllocError)
+    }
+
+    #[inline] unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unmap(ptr, layout)
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use core::ptr::NonNull;
+
+    #[inline]
+    pub(super) fn page_size() -> usize {
+        // SAFETY: sysconf has no safety requirments.
+        unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+    }
+
+    pub(super) unsafe fn map(total: usize, page: usize, data_pages: usize) -> Option<NonNull<u8>> {
+        let ptr: *mut libc::c_void = libc::mmap(
+            core::ptr::null_mut(),
+            total,
+            libc::PROT_NONE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+
+        if ptr == libc::MAP_FAILED {
+            return None
+        }
+
+        if libc::mprotect(ptr.cast::<u8>().add(page).cast(), data_pages, libc::PROT_READ | libc::PROT_WRITE) != 0 {
+            libc::munmap(ptr, total);
+            return None
+        }
+
+        NonNull::new(ptr.cast::<u8>())
+    }
+
+    pub(super) unsafe fn unmap(base: NonNull<u8>, total: usize) {
+        libc::munmap(base.as_ptr().cast(), total);
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use core::ptr::NonNull;
+
+    use windows_sys::Win32::System::{
+        Memory::{
+            VirtualAlloc,
+            VirtualFree,
+            VirtualProtect,
+            MEM_COMMIT,
+            MEM_RELEASE,
+            MEM_RESERVE,
+            PAGE_NOACCESS,
+            PAGE_PROTECTION_FLAGS,
+            PAGE_READWRITE,
+        },
+        SystemInformation::{
+            GetSystemInfo,
+            SYSTEM_INFO,
+        },
+    };
+
+    #[inline]
+    pub(super) fn page_size() -> usize {
+        // SAFETY: GetSystemInfo only writes to the given struct.
+        unsafe {
+            let mut info: SYSTEM_INFO = core::mem::zeroed();
+            GetSystemInfo(&mut info);
+            info.dwPageSize as usize
+        }
+    }
+
+    pub(super) unsafe fn map(total: usize, page: usize, data_pages: usize) -> Option<NonNull<u8>> {
+        let ptr: *mut core::ffi::c_void = VirtualAlloc(core::ptr::null(), total, MEM_RESERVE | MEM_COMMIT, PAGE_NOACCESS);
+
+        if ptr.is_null() {
+            return None
+        }
+
+        let mut old: PAGE_PROTECTION_FLAGS = 0;
+        if VirtualProtect(ptr.cast::<u8>().add(page).cast(), data_pages, PAGE_READWRITE, &mut old) == 0 {
+            VirtualFree(ptr, 0, MEM_RELEASE);
+            return None
+        }
+
+        NonNull::new(ptr.cast::<u8>())
+    }
+
+    pub(super) unsafe fn unmap(base: NonNull<u8>, _total: usize) {
+        VirtualFree(base.as_ptr().cast(), 0, MEM_RELEASE);
+    }
+}
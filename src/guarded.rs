@@ -0,0 +1,160 @@
+
+/*!
+This module provides the [`GuardedData`] data structure and all it's associated
+functions, methods and items.
+
+[`GuardedData`] is made for finding out of bounds writes done through the unchecked
+functions, it keeps a known pattern (a canary) right before and right after the usable
+region, so a write that goes one byte too far changes the canary instead of some other data.
+
+The canaries are checked with [`GuardedData::check_canaries`] and (in debug builds)
+when the [`GuardedData`] is dropped, so a broken canary is found close to where it was broken.
+ */
+
+use crate::slice::DataSlice;
+use core::ops::{
+    Deref,
+    DerefMut,
+};
+
+/// The amount of canary bytes on each side of the usable region.
+pub const GUARD_SIZE: usize = 16;
+
+/// The pattern the canaries are filled with, repeated.
+pub const CANARY: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
+
+/// The error given when a canary of a [`GuardedData`] was changed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CanaryError {
+    /// Weather the broken canary is the one after the usable region (otherwise it's the one before).
+    pub after: bool,
+    /// The distance of the changed byte closest to the usable region from it,
+    /// `0` being the byte right next to it.
+    pub distance: usize,
+}
+
+impl core::error::Error for CanaryError {}
+impl core::fmt::Display for CanaryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "The canary {side} the usable region was changed `{distance}` bytes away from it.",
+            side = if self.after { "after" } else { "before" },
+            distance = self.distance,
+        )
+    }
+}
+
+/// A typeless chunk of data with canaries around the usable region.
+/// 
+/// The usable region is the data given at construction without the first and the last
+/// [`GUARD_SIZE`] bytes, which hold the canaries.
+#[must_use]
+pub struct GuardedData<D: DerefMut<Target = DataSlice>> {
+    data: D,
+}
+
+impl<D: DerefMut<Target = DataSlice>> GuardedData<D> {
+    /// Constructs a new [`GuardedData`] from the given data, writing the canaries
+    /// in the first and the last [`GUARD_SIZE`] bytes.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if it is smaller then two guards.
+    pub fn new(mut data: D) -> Result<GuardedData<D>, D> {
+        let size: usize = data.size();
+
+        if size < GUARD_SIZE * 2 {
+            return Err(data)
+        }
+
+        for at in 0..GUARD_SIZE {
+            data.inner[at] = CANARY[at % CANARY.len()];
+            data.inner[size - GUARD_SIZE + at] = CANARY[at % CANARY.len()];
+        }
+
+        Ok(GuardedData { data })
+    }
+
+    /// Get's the size of the usable region.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.data.size() - GUARD_SIZE * 2
+    }
+
+    /// Checks that both canaries are still the same as when they were written.
+    /// 
+    /// # ERRORS
+    /// Will return an error for the canary before the usable region if
+    /// it was changed, otherwise for the one after it if it was changed.
+    pub fn check_canaries(&self) -> Result<(), CanaryError> {
+        let size: usize = self.data.size();
+
+        for distance in 0..GUARD_SIZE {
+            let at: usize = GUARD_SIZE - 1 - distance;
+
+            if self.data.inner[at] != CANARY[at % CANARY.len()] {
+                return Err(CanaryError { after: false, distance })
+            }
+        }
+
+        for distance in 0..GUARD_SIZE {
+            if self.data.inner[size - GUARD_SIZE + distance] != CANARY[distance % CANARY.len()] {
+                return Err(CanaryError { after: true, distance })
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gives back the underlying data, canaries included, without checking them.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        let this: core::mem::ManuallyDrop<GuardedData<D>> = core::mem::ManuallyDrop::new(self);
+
+        // SAFETY: self is never used or dropped again.
+        unsafe { core::ptr::read(&this.data) }
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> Drop for GuardedData<D> {
+    fn drop(&mut self) {
+        if !cfg!(debug_assertions) {
+            return
+        }
+
+        // A second panic while unwinding would abort.
+        #[cfg(feature = "std")]
+        {
+            if crate::std::thread::panicking() {
+                return
+            }
+        }
+
+        if let Err(err) = self.check_canaries() {
+            panic!("{}", err)
+        }
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> Deref for GuardedData<D> {
+    type Target = DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        let size: usize = self.data.size();
+        DataSlice::from_slice(&self.data.inner[GUARD_SIZE..size - GUARD_SIZE])
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> DerefMut for GuardedData<D> {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        let size: usize = self.data.size();
+        DataSlice::from_slice_mut(&mut self.data.inner[GUARD_SIZE..size - GUARD_SIZE])
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for GuardedData<D> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <DataSlice as core::fmt::Debug>::fmt(self, f)
+    }
+}
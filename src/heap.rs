@@ -0,0 +1,598 @@
+
+/*!
+This module provides the [`DataHeap`] allocator and all it's associated
+functions, methods and items.
+
+A [`DataHeap`] manages allocations of any size inside of a single [`DataSlice`],
+and gives out the offset of each allocation instead of a pointer, so the allocations
+stay valid when the data is moved, saved to a file or loaded back from it.
+
+All the bookkeeping is stored in the data it'self (as little endian 64 bit words),
+so a heap can be opened again with [`DataHeap::open`]:
+- The first word is the offset of the first free block.
+- Every block starts with a word holding it's size (header included) and weather it is used.
+- Every free block holds the offset of the next free block right after that,
+  the free blocks being kept in the order of their offsets so neighbours can be merged.
+
+Allocations use the first free block they fit in (first-fit), and the alignment of an
+allocation is relative to the start of the data, not to the address in memory.
+
+The [`SubAllocator`] trait is the interface shared with the other in-buffer allocators
+of this crate, like the [`BuddyAlloc`](crate::buddy::BuddyAlloc).
+
+A [`StaticDataHeap`] puts a [`DataHeap`] over a [`DataArray`] that can be stored in a `static`,
+and implements [`GlobalAlloc`] with it, so it can be the whole heap of a `no_std` target
+with `#[global_allocator]`.
+
+With the `allocator_api` feature a refrence to a [`DataHeapCell`] (a [`DataHeap`] that can be used through a shared refrence)
+or to a [`StaticDataHeap`] is also an [`Allocator`](core::alloc::Allocator), so collections like `Vec` and `Box`
+can allocate inside of the data. Only the refrences are allocators, as the heaps can hold their data inline,
+so moving one together with a collection would move the allocations out from under it.
+ */
+
+use crate::{
+    arena::ArenaStats,
+    array::DataArray,
+    slice::DataSlice,
+};
+use core::alloc::{
+    GlobalAlloc,
+    Layout,
+};
+use core::cell::{
+    RefCell,
+    UnsafeCell,
+};
+use core::convert::TryFrom;
+use core::ops::DerefMut;
+use core::sync::atomic::{
+    AtomicBool,
+    AtomicUsize,
+    Ordering,
+};
+
+/// The error given when an allocation does not fit in an in-buffer allocator.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OomError {
+    /// The size of the allocation.
+    pub size: usize,
+    /// The alignment of the allocation.
+    pub align: usize,
+}
+
+impl core::error::Error for OomError {}
+impl core::fmt::Display for OomError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Can not allocate `{size}` bytes aligned to `{align}`.", size = self.size, align = self.align)
+    }
+}
+
+/// The error given when an offset given to [`free`](SubAllocator::free) is not an allocation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FreeError {
+    /// The offset that was given.
+    pub offset: usize,
+}
+
+impl core::error::Error for FreeError {}
+impl core::fmt::Display for FreeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "The offset `{offset}` is not an allocation.", offset = self.offset)
+    }
+}
+
+/// An allocator that gives out regions of a buffer by their offset.
+pub trait SubAllocator {
+    /// Allocates `size` bytes at an offset that is a multiple of `align` (which must be a power of two)
+    /// and gives back the offset.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no free region the allocation fits in
+    /// or if `align` is not a power of two.
+    fn alloc(&mut self, size: usize, align: usize) -> Result<usize, OomError>;
+
+    /// Gives back the allocation at the given offset, so it's bytes can be allocated again.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the offset is not the offset of an allocation.
+    fn free(&mut self, offset: usize) -> Result<(), FreeError>;
+}
+
+/// The usage and the fragmentation of a [`DataHeap`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeapStats {
+    /// The amount of bytes managed by the heap, bookkeeping included.
+    pub total: usize,
+    /// The amount of bytes in used blocks, headers and padding included.
+    pub used: usize,
+    /// The amount of bytes in free blocks, headers included.
+    pub free: usize,
+    /// The size of the largest free block, header included.
+    pub largest_free: usize,
+    /// The amount of free blocks.
+    pub free_blocks: usize,
+    /// The amount of allocations.
+    pub allocations: usize,
+}
+
+impl HeapStats {
+    /// Get's the fragmentation of the free bytes, from `0.0` when all of them are in a single block
+    /// to almost `1.0` when they are split into many small blocks.
+    #[inline]
+    pub fn fragmentation(&self) -> f64 {
+        match self.free {
+            0 => 0.0,
+            free => 1.0 - self.largest_free as f64 / free as f64,
+        }
+    }
+}
+
+pub(crate) const WORD: usize = 8;
+pub(crate) const NONE: usize = usize::MAX;
+const MIN_BLOCK: usize = WORD * 2;
+const USED: usize = 1;
+const START: usize = WORD;
+
+/// A first-fit allocator that manages allocations inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DataHeap<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    used: usize,
+    peak: usize,
+}
+
+impl<D: DerefMut<Target = DataSlice>> DataHeap<D> {
+    /// Constructs a new empty [`DataHeap`] in the given data, overwriting it's bookkeeping words.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if it is too small to hold a single allocation.
+    pub fn new(mut data: D) -> Result<DataHeap<D>, D> {
+        let size: usize = (data.size().saturating_sub(START)) & !(WORD - 1);
+
+        if size < MIN_BLOCK {
+            return Err(data)
+        }
+
+        write_word(&mut data, 0, START);
+        write_word(&mut data, START, size);
+        write_word(&mut data, START + WORD, NONE);
+
+        Ok(DataHeap { data, used: 0, peak: 0 })
+    }
+
+    /// Opens a heap that was constructed with [`new`](DataHeap::new) in the given data before.
+    /// 
+    /// The bookkeeping words are only checked while they are used,
+    /// a heap in corrupted data never touches bytes outside of the data but may give wrong results.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if it does not start with the offset of a free block (or the end of the free list).
+    pub fn open(data: D) -> Result<DataHeap<D>, D> {
+        let mut heap: DataHeap<D> = match read_word(&data, 0) {
+            Some(head) if head == NONE || (head >= START && head < data.size()) => DataHeap { data, used: 0, peak: 0 },
+            _ => return Err(data),
+        };
+
+        heap.used = heap.stats().used;
+        heap.peak = heap.used;
+        Ok(heap)
+    }
+
+    /// Get's a refrence to the underlying data.
+    #[inline]
+    pub fn get_ref(&self) -> &DataSlice {
+        &self.data
+    }
+
+    /// Get's a mutable refrence to the underlying data, so the allocations can be written.
+    /// 
+    /// Writing outside of the allocations corrupts the heap.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut DataSlice {
+        &mut self.data
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    #[inline]
+    fn end(&self) -> usize {
+        START + ((self.data.size().saturating_sub(START)) & !(WORD - 1))
+    }
+
+    #[inline]
+    fn limit(&self) -> usize {
+        self.end() / MIN_BLOCK + 1
+    }
+
+    /// Get's the usage and the fragmentation of the heap.
+    pub fn stats(&self) -> HeapStats {
+        let mut stats: HeapStats = HeapStats { total: self.data.size(), ..HeapStats::default() };
+        let mut block: usize = START;
+
+        while block < self.end() {
+            let header: usize = match read_word(&self.data, block) {
+                Some(header) => header,
+                None => break,
+            };
+            let size: usize = header & !USED;
+
+            if size < MIN_BLOCK {
+                break
+            }
+
+            if header & USED == USED {
+                stats.used += size;
+                stats.allocations += 1;
+            } else {
+                stats.free += size;
+                stats.free_blocks += 1;
+                stats.largest_free = stats.largest_free.max(size);
+            }
+
+            block = block.saturating_add(size);
+        }
+
+        stats
+    }
+
+    /// Get's the usage of the heap since it was constructed or opened.
+    /// 
+    /// The allocated bytes include the headers of the blocks, and the largest free allocation
+    /// is the size of the largest free block without it's header, ignoring any padding it might need.
+    pub fn report(&self) -> ArenaStats {
+        let stats: HeapStats = self.stats();
+
+        ArenaStats {
+            allocated: stats.used,
+            peak: self.peak,
+            allocations: stats.allocations,
+            largest_free: stats.largest_free.saturating_sub(WORD),
+        }
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> SubAllocator for DataHeap<D> {
+    fn alloc(&mut self, size: usize, align: usize) -> Result<usize, OomError> {
+        let err: OomError = OomError { size, align };
+
+        if !align.is_power_of_two() {
+            return Err(err)
+        }
+
+        let body: usize = size.max(WORD).checked_next_multiple_of(WORD).ok_or(err)?;
+        let mut prev: usize = 0;
+        let mut block: usize = read_word(&self.data, 0).ok_or(err)?;
+
+        for _ in 0..self.limit() {
+            if block == NONE || block >= self.end() {
+                break
+            }
+
+            let block_size: usize = read_word(&self.data, block).ok_or(err)?.min(self.end() - block);
+            let next: usize = read_word(&self.data, block + WORD).ok_or(err)?;
+
+            // The padding before the allocation is eather nothing or big enough to stay a free block.
+            let mut offset: usize = crate::layout::next_aligned(block + WORD, align).ok_or(err)?;
+            while offset - WORD - block != 0 && offset - WORD - block < MIN_BLOCK {
+                offset = offset.checked_add(align).ok_or(err)?;
+            }
+            let pad: usize = offset - WORD - block;
+
+            match pad.checked_add(WORD + body) {
+                Some(needed) if needed <= block_size => {
+                    let tail: usize = block_size - needed;
+                    let used: usize = block + pad;
+                    let used_size: usize = if tail >= MIN_BLOCK { WORD + body } else { WORD + body + tail };
+
+                    let mut after: usize = next;
+                    if tail >= MIN_BLOCK {
+                        let rest: usize = used + used_size;
+                        write_word(&mut self.data, rest, tail);
+                        write_word(&mut self.data, rest + WORD, next);
+                        after = rest;
+                    }
+
+                    if pad != 0 {
+                        write_word(&mut self.data, block, pad);
+                        write_word(&mut self.data, block + WORD, after);
+                    } else {
+                        write_word(&mut self.data, if prev == 0 { 0 } else { prev + WORD }, after);
+                    }
+
+                    write_word(&mut self.data, used, used_size | USED);
+                    self.used = self.used.saturating_add(used_size);
+                    self.peak = self.peak.max(self.used);
+                    return Ok(offset)
+                },
+                _ => {
+                    prev = block;
+                    block = next;
+                },
+            }
+        }
+
+        Err(err)
+    }
+
+    fn free(&mut self, offset: usize) -> Result<(), FreeError> {
+        let err: FreeError = FreeError { offset };
+
+        let block: usize = offset.checked_sub(WORD).filter(#[inline] |block| *block >= START).ok_or(err)?;
+        let header: usize = read_word(&self.data, block).ok_or(err)?;
+        let mut size: usize = header & !USED;
+
+        if header & USED != USED || size < MIN_BLOCK || block >= self.end() || size > self.end() - block {
+            return Err(err)
+        }
+
+        // Find the free blocks right before and right after the block.
+        let mut prev: usize = 0;
+        let mut next: usize = read_word(&self.data, 0).ok_or(err)?;
+        for _ in 0..self.limit() {
+            if next == NONE || next > block || next < START {
+                break
+            }
+
+            prev = next;
+            next = read_word(&self.data, next + WORD).ok_or(err)?;
+        }
+
+        let prev_size: usize = if prev == 0 { 0 } else { read_word(&self.data, prev).ok_or(err)? };
+
+        // A block that overlaps a free block was already freed (or never allocated).
+        if prev.saturating_add(prev_size) > block || (next != NONE && block + size > next) {
+            return Err(err)
+        }
+
+        self.used = self.used.saturating_sub(size);
+
+        if next != NONE && block + size == next {
+            size = size.saturating_add(read_word(&self.data, next).ok_or(err)?);
+            next = read_word(&self.data, next + WORD).ok_or(err)?;
+        }
+
+        if prev != 0 && prev + prev_size == block {
+            // The header of the block is cleared, so freeing it again fails.
+            write_word(&mut self.data, block, 0);
+            write_word(&mut self.data, prev, prev_size.saturating_add(size));
+            write_word(&mut self.data, prev + WORD, next);
+            return Ok(())
+        }
+
+        write_word(&mut self.data, block, size);
+        write_word(&mut self.data, block + WORD, next);
+        write_word(&mut self.data, if prev == 0 { 0 } else { prev + WORD }, block);
+        Ok(())
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for DataHeap<D> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataHeap").field("stats", &self.stats()).finish()
+    }
+}
+
+/// A [`DataHeap`] that can allocate through a shared refrence.
+/// 
+/// With the `allocator_api` feature a `&DataHeapCell` is an [`Allocator`](core::alloc::Allocator).
+/// The alignment of the allocations of a [`DataHeap`] is relative to the start of the data,
+/// so allocations that need a bigger alignment then the address of the data has always fail.
+pub struct DataHeapCell<D: DerefMut<Target = DataSlice>> {
+    heap: RefCell<DataHeap<D>>,
+}
+
+impl<D: DerefMut<Target = DataSlice>> DataHeapCell<D> {
+    /// Constructs a new [`DataHeapCell`] with the given heap.
+    #[inline]
+    pub const fn new(heap: DataHeap<D>) -> DataHeapCell<D> {
+        DataHeapCell { heap: RefCell::new(heap) }
+    }
+
+    /// Get's the usage and the fragmentation of the heap.
+    #[inline]
+    pub fn stats(&self) -> HeapStats {
+        self.heap.borrow().stats()
+    }
+
+    /// Get's the usage of the heap since it was constructed or opened.
+    #[inline]
+    pub fn report(&self) -> ArenaStats {
+        self.heap.borrow().report()
+    }
+
+    /// Gives back the underlying heap.
+    #[inline]
+    pub fn into_inner(self) -> DataHeap<D> {
+        self.heap.into_inner()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+// SAFETY: Every allocation is a used block of the heap, which is never given out twice,
+// and it's address is checked to be aligned. The heap can't be moved while it is borrowed.
+unsafe impl<D: DerefMut<Target = DataSlice>> core::alloc::Allocator for &DataHeapCell<D> {
+    fn allocate(&self, layout: Layout) -> Result<core::ptr::NonNull<[u8]>, core::alloc::AllocError> {
+        let mut heap: core::cell::RefMut<'_, DataHeap<D>> = self.heap.try_borrow_mut().map_err(#[inline] |_| core::alloc::AllocError)?;
+        let base: *mut u8 = heap.data.as_mut_ptr();
+
+        if !base.addr().is_multiple_of(layout.align()) {
+            return Err(core::alloc::AllocError)
+        }
+
+        let offset: usize = heap.alloc(layout.size(), layout.align()).map_err(#[inline] |_| core::alloc::AllocError)?;
+
+        // SAFETY: The allocation is inside of the data.
+        let ptr: core::ptr::NonNull<u8> = unsafe { core::ptr::NonNull::new_unchecked(base.add(offset)) };
+        Ok(core::ptr::NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, _layout: Layout) {
+        if let Ok(mut heap) = self.heap.try_borrow_mut() {
+            let offset: usize = ptr.addr().get().wrapping_sub(heap.data.as_ptr().addr());
+            heap.free(offset).ok();
+        }
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for DataHeapCell<D> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataHeapCell").field("stats", &self.stats()).finish()
+    }
+}
+
+/// A [`DataHeap`] over a [`DataArray`] of `SIZE` bytes that can be used as the global allocator.
+/// 
+/// The heap is constructed the first time it is used, and every use locks it with a spin lock,
+/// so it can be used from many threads (or interrupts that never interrupt an allocation).
+/// 
+/// The data is aligned to [`MAX_ALIGN`](StaticDataHeap::MAX_ALIGN) bytes,
+/// allocations that need a bigger alignment always fail.
+/// 
+/// With the `allocator_api` feature a `&StaticDataHeap` is an [`Allocator`](core::alloc::Allocator).
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[repr(C, align(16))]
+pub struct StaticDataHeap<const SIZE: usize> {
+    data: UnsafeCell<DataArray<SIZE>>,
+    locked: AtomicBool,
+    ready: AtomicBool,
+    used: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+// SAFETY: The data is only ever accessed while the lock is held.
+unsafe impl<const SIZE: usize> Sync for StaticDataHeap<SIZE> {}
+
+impl<const SIZE: usize> StaticDataHeap<SIZE> {
+    /// The largest alignment an allocation can have.
+    pub const MAX_ALIGN: usize = 16;
+
+    /// Constructs a new [`StaticDataHeap`] with nothing allocated.
+    #[inline]
+    pub const fn new() -> StaticDataHeap<SIZE> {
+        StaticDataHeap {
+            data: UnsafeCell::new(DataArray::zeroed()),
+            locked: AtomicBool::new(false),
+            ready: AtomicBool::new(false),
+            used: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    /// Locks the heap and calls the closure with it, constructing the heap if it is the first use.
+    fn with<R>(&self, f: impl FnOnce(&mut DataHeap<&mut DataSlice>) -> R) -> Option<R> {
+        while self.locked.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            core::hint::spin_loop();
+        }
+
+        // SAFETY: The lock is held, so no other refrence to the data exists.
+        let data: &mut DataSlice = unsafe { &mut *self.data.get() };
+        let heap: Result<DataHeap<&mut DataSlice>, &mut DataSlice> = if self.ready.load(Ordering::Relaxed) {
+            Ok(DataHeap { data, used: self.used.load(Ordering::Relaxed), peak: self.peak.load(Ordering::Relaxed) })
+        } else {
+            DataHeap::new(data)
+        };
+
+        let result: Option<R> = heap.ok().map(#[inline] |mut heap| {
+            let result: R = f(&mut heap);
+
+            self.ready.store(true, Ordering::Relaxed);
+            self.used.store(heap.used, Ordering::Relaxed);
+            self.peak.store(heap.peak, Ordering::Relaxed);
+            result
+        });
+
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+
+    /// Get's the usage and the fragmentation of the heap.
+    #[inline]
+    pub fn stats(&self) -> HeapStats {
+        self.with(#[inline] |heap| heap.stats()).unwrap_or_default()
+    }
+
+    /// Get's the usage of the heap since it was constructed.
+    #[inline]
+    pub fn report(&self) -> ArenaStats {
+        self.with(#[inline] |heap| heap.report()).unwrap_or_default()
+    }
+}
+
+impl<const SIZE: usize> Default for StaticDataHeap<SIZE> {
+    #[inline] fn default() -> StaticDataHeap<SIZE> {
+        StaticDataHeap::new()
+    }
+}
+
+// SAFETY: Every allocation is a used block of the heap, which is never given out twice
+// and is aligned in memory since the data is aligned to `MAX_ALIGN`.
+unsafe impl<const SIZE: usize> GlobalAlloc for StaticDataHeap<SIZE> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.align() > Self::MAX_ALIGN {
+            return core::ptr::null_mut()
+        }
+
+        match self.with(#[inline] |heap| heap.alloc(layout.size(), layout.align())) {
+            Some(Ok(offset)) => self.data.get().cast::<u8>().add(offset),
+            _ => core::ptr::null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        let offset: usize = ptr.addr().wrapping_sub(self.data.get().addr());
+        self.with(#[inline] |heap| heap.free(offset).ok());
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+// SAFETY: Same as for the `GlobalAlloc` implementation, and the heap can't be moved while it is borrowed.
+unsafe impl<const SIZE: usize> core::alloc::Allocator for &StaticDataHeap<SIZE> {
+    #[inline] fn allocate(&self, layout: Layout) -> Result<core::ptr::NonNull<[u8]>, core::alloc::AllocError> {
+        // SAFETY: A null pointer is turned into an error.
+        let ptr: *mut u8 = unsafe { GlobalAlloc::alloc(*self, layout) };
+
+        core::ptr::NonNull::new(ptr)
+            .map(#[inline] |ptr| core::ptr::NonNull::slice_from_raw_parts(ptr, layout.size()))
+            .ok_or(core::alloc::AllocError)
+    }
+
+    #[inline] unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, layout: Layout) {
+        GlobalAlloc::dealloc(*self, ptr.as_ptr(), layout)
+    }
+}
+
+impl<const SIZE: usize> core::fmt::Debug for StaticDataHeap<SIZE> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticDataHeap").field("stats", &self.stats()).finish()
+    }
+}
+
+#[inline]
+pub(crate) fn read_word(data: &DataSlice, at: usize) -> Option<usize> {
+    let bytes: &[u8] = data.inner.get(at..at.checked_add(WORD)?)?;
+    let mut word: [u8; WORD] = [0; WORD];
+    word.copy_from_slice(bytes);
+
+    match u64::from_le_bytes(word) {
+        u64::MAX => Some(NONE),
+        word => usize::try_from(word).ok(),
+    }
+}
+
+#[inline]
+pub(crate) fn write_word(data: &mut DataSlice, at: usize, word: usize) {
+    let word: u64 = if word == NONE { u64::MAX } else { word as u64 };
+
+    if let Some(bytes) = at.checked_add(WORD).and_then(#[inline] |end| data.inner.get_mut(at..end)) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+}
@@ -0,0 +1,108 @@
+
+/*!
+This module provides helper items for the hex text encoding used by
+[`DataSlice::encode_hex`](crate::slice::DataSlice::encode_hex),
+[`DataArray::from_hex`](crate::array::DataArray::from_hex) and
+[`DataBoxed::from_hex`](crate::boxed::DataBoxed::from_hex).
+
+Each byte is written as two hex digits, most significant first. Lowercase digits are
+written, but both lowercase and uppercase digits are accepted when decoding.
+ */
+
+/// The lowercase hex digits, indexed by their value.
+pub const DIGITS: [u8; 16] = *b"0123456789abcdef";
+
+/// The error given when decoding hex text fails.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexError {
+    /// The text has an odd amount of digits, so the last byte is not whole.
+    OddLength {
+        /// The length of the text.
+        len: usize,
+    },
+    /// The text has a character that is not a hex digit.
+    InvalidDigit {
+        /// The index of the character in the text.
+        idx: usize,
+        /// The (first byte of the) character.
+        byte: u8,
+    },
+    /// The text does not decode into as many bytes as are needed.
+    DiferentSizes {
+        /// The amount of bytes that were needed.
+        expected: usize,
+        /// The amount of bytes the text decodes into.
+        gotten: usize,
+    },
+}
+
+impl core::error::Error for HexError {}
+impl core::fmt::Display for HexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            HexError::OddLength { len } => write!(
+                f,
+                "Can not decode `{len}` hex digits because their amount is odd.",
+                len = len,
+            ),
+            HexError::InvalidDigit { idx, byte } => write!(
+                f,
+                "Can not decode the byte `{byte:#04X}` at idx `{idx}` because it is not a hex digit.",
+                byte = byte,
+                idx = idx,
+            ),
+            HexError::DiferentSizes { expected, gotten } => write!(
+                f,
+                "Expected hex text of `{expected}` bytes but got hex text of `{gotten}` bytes.",
+                expected = expected,
+                gotten = gotten,
+            ),
+        }
+    }
+}
+
+/// Get's the value of the given hex digit, or [None] if it is not one.
+#[inline]
+pub const fn decode_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes the given hex text into the given bytes, which must be exactly as many as the text has.
+/// 
+/// # ERRORS
+/// Will return an error if the text has an odd length, does not decode into
+/// exactly `out.len()` bytes or has a character that is not a hex digit.
+/// If an invalid digit is found the bytes before it are already written.
+pub const fn decode_into(hex: &str, out: &mut [u8]) -> Result<(), HexError> {
+    let hex: &[u8] = hex.as_bytes();
+
+    if !hex.len().is_multiple_of(2) {
+        return Err(HexError::OddLength { len: hex.len() })
+    }
+    if hex.len() / 2 != out.len() {
+        return Err(HexError::DiferentSizes { expected: out.len(), gotten: hex.len() / 2 })
+    }
+
+    let mut at: usize = 0;
+    while at < out.len() {
+        let high: u8 = match decode_digit(hex[at * 2]) {
+            Some(high) => high,
+            None => return Err(HexError::InvalidDigit { idx: at * 2, byte: hex[at * 2] }),
+        };
+        let low: u8 = match decode_digit(hex[at * 2 + 1]) {
+            Some(low) => low,
+            None => return Err(HexError::InvalidDigit { idx: at * 2 + 1, byte: hex[at * 2 + 1] }),
+        };
+
+        out[at] = high << 4 | low;
+        at += 1;
+    }
+
+    Ok(())
+}
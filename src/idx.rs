@@ -10,32 +10,348 @@ use core::ops::{
     Bound
 };
 
+/// The error given when a data structure can not be accessed in the given region.
 /// 
+/// More reasons may be added in the future, so matching on it needs a wildcard arm.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct IdxError {
-    #[allow(missing_docs)] pub idx: usize,
-    #[allow(missing_docs)] pub data_size: usize,
-    #[allow(missing_docs)] pub type_size: usize,
+#[non_exhaustive]
+pub enum IdxError {
+    /// The region of `size` bytes starting from `idx` gets out of the `len` bytes of the data.
+    OutOfBounds {
+        /// The index the region starts at.
+        idx: usize,
+        /// The size of the data that was accessed.
+        len: usize,
+        /// The size of the region.
+        size: usize,
+    },
+    /// The end of the region of `size` bytes starting from `idx` does not fit in an [usize].
+    Overflow {
+        /// The index the region starts at.
+        idx: usize,
+        /// The size of the region.
+        size: usize,
+    },
+    /// The region is not aligned for the type it is accessed as.
+    Misaligned {
+        /// The alignment the type needs.
+        required: usize,
+        /// The alignment the region actually has.
+        actual: usize,
+    },
+    /// The access needs a region that is not empty, but the region is empty.
+    ZeroSize,
+    /// The range starts after it ends.
+    Reversed {
+        /// The index the range starts at.
+        start: usize,
+        /// The index the range ends at (excluded).
+        end: usize,
+    },
+}
+
+impl IdxError {
+    /// Constructs the error for a region of `size` bytes starting from `idx` that
+    /// does not fit in data of `len` bytes, [`Overflow`](IdxError::Overflow) if the end of the region does not
+    /// fit in an [usize], otherwise [`OutOfBounds`](IdxError::OutOfBounds).
+    #[inline]
+    pub const fn new(idx: usize, len: usize, size: usize) -> IdxError {
+        match idx.checked_add(size) {
+            Some(_) => IdxError::OutOfBounds { idx, len, size },
+            None => IdxError::Overflow { idx, size },
+        }
+    }
+
+    /// Constructs the [`Misaligned`](IdxError::Misaligned) error for the given pointer
+    /// which should have been aligned to `required`.
+    #[inline]
+    pub fn misaligned<T: ?Sized>(ptr: *const T, required: usize) -> IdxError {
+        IdxError::Misaligned {
+            required,
+            actual: match ptr.cast::<u8>().addr() {
+                0 => 1 << (usize::BITS - 1),
+                addr => 1 << addr.trailing_zeros(),
+            },
+        }
+    }
+
+    /// Get's the index the region starts at, if the error has one.
+    #[inline]
+    pub const fn idx(&self) -> Option<usize> {
+        match *self {
+            IdxError::OutOfBounds { idx, .. } | IdxError::Overflow { idx, .. } | IdxError::Reversed { start: idx, .. } => Some(idx),
+            _ => None,
+        }
+    }
+
+    /// Get's the size of the data that was accessed, if the error has one.
+    #[inline]
+    pub const fn data_len(&self) -> Option<usize> {
+        match *self {
+            IdxError::OutOfBounds { len, .. } => Some(len),
+            _ => None,
+        }
+    }
+
+    /// Get's the size of the region, if the error has one.
+    #[inline]
+    pub const fn size(&self) -> Option<usize> {
+        match *self {
+            IdxError::OutOfBounds { size, .. } | IdxError::Overflow { size, .. } => Some(size),
+            IdxError::ZeroSize => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Get's the alignment that was needed, if the error is about alignment.
+    #[inline]
+    pub const fn required_align(&self) -> Option<usize> {
+        match *self {
+            IdxError::Misaligned { required, .. } => Some(required),
+            _ => None,
+        }
+    }
 }
 
 impl core::error::Error for IdxError {}
 impl core::fmt::Display for IdxError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.idx > self.data_size {
-            write!(f, "Can not acces data at idx `{idx}` because it is greater then `{size}`.", idx = self.idx, size = self.data_size)
-        } else if self.idx.checked_add(self.type_size).map(|idx| idx > self.data_size).unwrap_or(true) {
-            write!(
+        match *self {
+            IdxError::OutOfBounds { idx, len, .. } if idx > len => write!(
+                f,
+                "Can not acces data at idx `{idx}` because it is greater then `{len}`.",
+                idx = idx,
+                len = len,
+            ),
+            IdxError::OutOfBounds { idx, len, size } => write!(
                 f,
-                "Can not acces data at idx `{idx}` because the size of the data is too large and gets out of the memory given to data.",
-                idx = self.idx,
-            )
-        } else {
-            unimplemented!("This error should have never been cosntructed and given.")
+                "Can not acces `{size}` bytes at idx `{idx}` because they get out of the `{len}` bytes of the data.",
+                size = size,
+                idx = idx,
+                len = len,
+            ),
+            IdxError::Overflow { idx, size } => write!(
+                f,
+                "Can not acces `{size}` bytes at idx `{idx}` because the end of them overflows.",
+                size = size,
+                idx = idx,
+            ),
+            IdxError::Misaligned { required, actual } => write!(
+                f,
+                "Can not acces data aligned to `{actual}` as data that needs to be aligned to `{required}`.",
+                actual = actual,
+                required = required,
+            ),
+            IdxError::ZeroSize => write!(f, "Can not acces an empty region."),
+            IdxError::Reversed { start, end } => write!(
+                f,
+                "Can not acces the range `{start}..{end}` because it starts after it ends.",
+                start = start,
+                end = end,
+            ),
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl IdxError {
+    #[inline]
+    fn io_kind(&self) -> crate::std::io::ErrorKind {
+        match self {
+            IdxError::OutOfBounds { .. } => crate::std::io::ErrorKind::UnexpectedEof,
+            _ => crate::std::io::ErrorKind::InvalidInput,
+        }
+    }
+}
+
+/// Accesses that get out of the data become [`UnexpectedEof`](crate::std::io::ErrorKind::UnexpectedEof),
+/// all the other errors become [`InvalidInput`](crate::std::io::ErrorKind::InvalidInput).
+#[cfg(feature = "std")]
+impl From<IdxError> for crate::std::io::Error {
+    #[inline] fn from(value: IdxError) -> crate::std::io::Error {
+        crate::std::io::Error::new(value.io_kind(), value)
+    }
+}
+
+/// Uses the same [`ErrorKind`](crate::std::io::ErrorKind) as the underlying [`IdxError`].
+#[cfg(feature = "std")]
+impl From<TypedIdxError> for crate::std::io::Error {
+    #[inline] fn from(value: TypedIdxError) -> crate::std::io::Error {
+        crate::std::io::Error::new(value.error.io_kind(), value)
+    }
+}
+
+/// The kind of access that failed, given in [`TypedIdxError`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    #[allow(missing_docs)] Read,
+    #[allow(missing_docs)] Write,
+    #[allow(missing_docs)] Take,
+    #[allow(missing_docs)] Replace,
+}
+
+impl core::fmt::Display for Access {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Access::Read => "read",
+            Access::Write => "write",
+            Access::Take => "take",
+            Access::Replace => "replace",
+        })
+    }
+}
+
+/// An [`IdxError`] together with the type that was accessed,
+/// given by the generic sized methods of [`RawDataStructure`](crate::RawDataStructure)
+/// and [`DataSlice`](crate::slice::DataSlice).
+/// 
+/// It converts into an [`IdxError`] so `?` keeps working in functions that return one.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct TypedIdxError {
+    // `type_name` can't be called in a `const fn` on stable, so it is called when the name is asked for.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_type_name"))]
+    type_name: fn() -> &'static str,
+    /// The kind of access that failed.
+    pub access: Access,
+    /// The underlying error.
+    pub error: IdxError,
+}
+
+#[cfg(feature = "serde")]
+fn serialize_type_name<S: serde::Serializer>(type_name: &fn() -> &'static str, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(type_name())
+}
+
+impl TypedIdxError {
+    /// The name of the type that was accessed, as given by [`type_name`](core::any::type_name).
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
+}
+
+impl PartialEq for TypedIdxError {
+    fn eq(&self, other: &TypedIdxError) -> bool {
+        self.type_name() == other.type_name() && self.access == other.access && self.error == other.error
+    }
+}
+impl Eq for TypedIdxError {}
+
+impl core::hash::Hash for TypedIdxError {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.type_name().hash(state);
+        self.access.hash(state);
+        self.error.hash(state);
+    }
+}
+
+impl IdxError {
+    /// Adds the type that was accessed to the error.
+    #[inline]
+    pub const fn typed<T: ?Sized>(self, access: Access) -> TypedIdxError {
+        TypedIdxError { type_name: core::any::type_name::<T>, access, error: self }
+    }
+}
+
+impl From<TypedIdxError> for IdxError {
+    #[inline] fn from(value: TypedIdxError) -> IdxError {
+        value.error
+    }
+}
+
+impl core::error::Error for TypedIdxError {
+    #[inline] fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+impl core::fmt::Display for TypedIdxError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.error {
+            IdxError::OutOfBounds { idx, len, size } => write!(
+                f,
+                "failed to {access} `{name}` ({size} bytes) at offset {idx} of a {len}-byte buffer",
+                access = self.access,
+                name = self.type_name(),
+                size = size,
+                idx = idx,
+                len = len,
+            ),
+            err => write!(
+                f,
+                "failed to {access} `{name}`: {err}",
+                access = self.access,
+                name = self.type_name(),
+                err = err,
+            ),
+        }
+    }
+}
+
+/// The error given by [`read_str`](crate::RawDataStructure::read_str), when the bytes
+/// could not be accessed or are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Utf8OrIdxError {
+    /// The bytes could not be accessed.
+    Idx(IdxError),
+    /// The bytes are not valid UTF-8.
+    Utf8(core::str::Utf8Error),
+}
+
+impl core::error::Error for Utf8OrIdxError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Utf8OrIdxError::Idx(err) => Some(err),
+            Utf8OrIdxError::Utf8(err) => Some(err),
+        }
+    }
+}
+impl core::fmt::Display for Utf8OrIdxError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Utf8OrIdxError::Idx(err) => core::fmt::Display::fmt(err, f),
+            Utf8OrIdxError::Utf8(err) => core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl From<IdxError> for Utf8OrIdxError {
+    #[inline] fn from(err: IdxError) -> Utf8OrIdxError {
+        Utf8OrIdxError::Idx(err)
+    }
+}
+
+impl From<core::str::Utf8Error> for Utf8OrIdxError {
+    #[inline] fn from(err: core::str::Utf8Error) -> Utf8OrIdxError {
+        Utf8OrIdxError::Utf8(err)
+    }
+}
+
+/// The error given when data structures that need to be of the same size
+/// (like the ones given to [`eq_masked`](crate::slice::DataSlice::eq_masked)) are not.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeMismatch {
+    /// The size that was needed.
+    pub expected: usize,
+    /// The size that was gotten.
+    pub gotten: usize,
+}
+
+impl core::error::Error for SizeMismatch {}
+impl core::fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Expected data of size `{expected}` but got data of size `{gotten}`.",
+            expected = self.expected,
+            gotten = self.gotten,
+        )
+    }
+}
+
 trait Sealed {}
 /// A custom index trait.
 /// 
@@ -58,6 +374,77 @@ pub trait Idx: Sealed {
     fn range(&self) -> (Bound<usize>, Bound<usize>) {
         (self.start(), self.end())
     }
+
+    /// Resolves the bounds into the range of indices they cover in data of `len` bytes,
+    /// with the start included and the end excluded.
+    /// 
+    /// Go to [`resolve`] for the details.
+    /// 
+    /// # ERRORS
+    /// Will return an error if a bound overflows, if the range starts after it ends
+    /// or if the range gets out of the data.
+    #[inline]
+    fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> {
+        resolve(self.range(), len)
+    }
+}
+
+/// Resolves the given bounds into the range of indices they cover in data of `len` bytes.
+/// 
+/// The start of the range is included and the end is excluded, so `a..=b`
+/// resolves to `a..b + 1` and reliably includes the byte at `b`.
+/// 
+/// This is what all the getters of this crate use, so it is the
+/// one place where the meaning of the bounds is decided.
+/// 
+/// # ERRORS
+/// Will return an error if a bound overflows, if the range starts after it ends
+/// or if the range gets out of the data.
+pub const fn resolve(bounds: (Bound<usize>, Bound<usize>), len: usize) -> Result<ops::Range<usize>, IdxError> {
+    let start: usize = match bounds.0 {
+        Bound::Unbounded => 0,
+        Bound::Included(idx) => idx,
+        Bound::Excluded(idx) => match idx.checked_add(1) {
+            Some(idx) => idx,
+            None => return Err(IdxError::Overflow { idx, size: 1 }),
+        },
+    };
+
+    let end: usize = match bounds.1 {
+        Bound::Unbounded => len,
+        Bound::Included(idx) => match idx.checked_add(1) {
+            Some(idx) => idx,
+            None => return Err(IdxError::Overflow { idx, size: 1 }),
+        },
+        Bound::Excluded(idx) => idx,
+    };
+
+    if start > end {
+        return Err(IdxError::Reversed { start, end })
+    }
+
+    if end > len {
+        return Err(IdxError::OutOfBounds { idx: start, len, size: end - start })
+    }
+
+    Ok(start..end)
+}
+
+/// [`Idx::resolve`] for the unchecked getters, which checks the bounds only in debug builds
+/// (or always with the `checked-unchecked` feature).
+/// 
+/// # SAFETY
+/// The bounds must resolve to a range inside of the data.
+#[inline]
+pub(crate) unsafe fn resolve_unchecked(idx: &impl Idx, len: usize) -> ops::Range<usize> {
+    match idx.resolve(len) {
+        Ok(range) => range,
+        Err(_) if cfg!(any(debug_assertions, feature = "checked-unchecked")) => {
+            panic!("An unchecked function was given a region outside of the data structure.")
+        },
+        // SAFETY: The caller must give bounds that resolve.
+        Err(_) => core::hint::unreachable_unchecked(),
+    }
 }
 
 impl<T: Idx> Sealed for &T {}
@@ -65,6 +452,7 @@ impl<T: Idx> Idx for &T {
     #[inline] fn start(&self) -> Bound<usize> { (**self).start() }
     #[inline] fn end(&self) -> Bound<usize> { (**self).end() }
     #[inline] fn range(&self) -> (Bound<usize>, Bound<usize>) { (**self).range() }
+    #[inline] fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> { (**self).resolve(len) }
 }
 
 impl<T: Idx> Sealed for &mut T {}
@@ -72,6 +460,7 @@ impl<T: Idx> Idx for &mut T {
     #[inline] fn start(&self) -> Bound<usize> { (**self).start() }
     #[inline] fn end(&self) -> Bound<usize> { (**self).end() }
     #[inline] fn range(&self) -> (Bound<usize>, Bound<usize>) { (**self).range() }
+    #[inline] fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> { (**self).resolve(len) }
 }
 
 impl Sealed for usize {}
@@ -221,6 +610,263 @@ impl Idx for ops::RangeFull {
     }
 }
 
+/// An index made out of the index it starts at and the amount of bytes it covers,
+/// for formats that store offset and length pairs.
+/// 
+/// The tuple `(usize, usize)` is interpreted the same way.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StartLen {
+    /// The index the region starts at.
+    pub start: usize,
+    /// The amount of bytes in the region.
+    pub len: usize,
+}
+
+impl StartLen {
+    /// Constructs a new [`StartLen`].
+    #[inline]
+    pub const fn new(start: usize, len: usize) -> StartLen {
+        StartLen { start, len }
+    }
+}
+
+impl Sealed for StartLen {}
+/// The end bound saturates if it overflows, but [`resolve`](Idx::resolve) gives
+/// an [`Overflow`](IdxError::Overflow) error for it.
+impl Idx for StartLen {
+    #[inline] fn start(&self) -> Bound<usize> {
+        Bound::Included(self.start)
+    }
+
+    #[inline] fn end(&self) -> Bound<usize> {
+        Bound::Excluded(self.start.saturating_add(self.len))
+    }
+
+    #[inline] fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> {
+        match self.start.checked_add(self.len) {
+            Some(_) => resolve(self.range(), len),
+            None => Err(IdxError::Overflow { idx: self.start, size: self.len }),
+        }
+    }
+}
+
+impl Sealed for (usize, usize) {}
+/// Interpreted as a [`StartLen`], the first being the start and the second the length.
+impl Idx for (usize, usize) {
+    #[inline] fn start(&self) -> Bound<usize> { StartLen::new(self.0, self.1).start() }
+    #[inline] fn end(&self) -> Bound<usize> { StartLen::new(self.0, self.1).end() }
+    #[inline] fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> { StartLen::new(self.0, self.1).resolve(len) }
+}
+
+/// An adapter that makes any [`RangeBounds<usize>`](ops::RangeBounds) an index,
+/// for generic code and custom range types.
+/// 
+/// It is made with [`bounds`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<R>(pub R);
+
+/// Wraps any [`RangeBounds<usize>`](ops::RangeBounds) in a [`Bounds`] so it can be used as an index.
+#[inline]
+pub const fn bounds<R: ops::RangeBounds<usize>>(range: R) -> Bounds<R> {
+    Bounds(range)
+}
+
+impl<R: ops::RangeBounds<usize>> Sealed for Bounds<R> {}
+impl<R: ops::RangeBounds<usize>> Idx for Bounds<R> {
+    #[inline] fn start(&self) -> Bound<usize> { self.0.start_bound().cloned() }
+    #[inline] fn end(&self) -> Bound<usize> { self.0.end_bound().cloned() }
+}
+
+/// An index counted from the end of the data, `FromEnd(n)` being the index `len - n`,
+/// so trailers, footers and checksums can be addressed without knowing the size of the data.
+/// 
+/// It can be used by it'self (`FromEnd(1)` being the last byte) or in ranges,
+/// for example `from_end(4)..` covers the last 4 bytes and `..from_end(4)` all the other ones.
+/// 
+/// Since the bounds depend on the size of the data, only [`resolve`](Idx::resolve) gives the real range,
+/// [`start`](Idx::start) and [`end`](Idx::end) give the bounds as if the data was [`usize::MAX`] bytes long.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FromEnd(pub usize);
+
+/// Constructs a [`FromEnd`] for the index `n` bytes before the end of the data.
+#[inline]
+pub const fn from_end(n: usize) -> FromEnd {
+    FromEnd(n)
+}
+
+impl FromEnd {
+    /// Get's the index this points to in data of `len` bytes.
+    /// 
+    /// # ERRORS
+    /// Will return an error if it points to before the start of the data.
+    #[inline]
+    pub const fn idx(self, len: usize) -> Result<usize, IdxError> {
+        match len.checked_sub(self.0) {
+            Some(idx) => Ok(idx),
+            None => Err(IdxError::OutOfBounds { idx: 0, len, size: self.0 }),
+        }
+    }
+}
+
+#[inline]
+fn from_end_bound(bound: Bound<FromEnd>, len: usize) -> Result<Bound<usize>, IdxError> {
+    Ok(match bound {
+        Bound::Unbounded => Bound::Unbounded,
+        Bound::Included(from_end) => Bound::Included(from_end.idx(len)?),
+        Bound::Excluded(from_end) => Bound::Excluded(from_end.idx(len)?),
+    })
+}
+
+macro_rules! from_end_idx {
+    ($( $ty:ty => |$this:ident| $bounds:expr; )*) => {$(
+        impl Sealed for $ty {}
+        impl Idx for $ty {
+            #[inline] fn start(&self) -> Bound<usize> {
+                let $this = self;
+                let bounds: (Bound<FromEnd>, Bound<FromEnd>) = $bounds;
+                from_end_bound(bounds.0, usize::MAX).unwrap_or(Bound::Unbounded)
+            }
+
+            #[inline] fn end(&self) -> Bound<usize> {
+                let $this = self;
+                let bounds: (Bound<FromEnd>, Bound<FromEnd>) = $bounds;
+                from_end_bound(bounds.1, usize::MAX).unwrap_or(Bound::Unbounded)
+            }
+
+            #[inline] fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> {
+                let $this = self;
+                let bounds: (Bound<FromEnd>, Bound<FromEnd>) = $bounds;
+                resolve((from_end_bound(bounds.0, len)?, from_end_bound(bounds.1, len)?), len)
+            }
+        }
+    )*};
+}
+
+from_end_idx! {
+    FromEnd => |this| (Bound::Included(*this), Bound::Included(*this));
+    ops::Range<FromEnd> => |this| (Bound::Included(this.start), Bound::Excluded(this.end));
+    ops::RangeInclusive<FromEnd> => |this| (Bound::Included(*this.start()), Bound::Included(*this.end()));
+    ops::RangeFrom<FromEnd> => |this| (Bound::Included(this.start), Bound::Unbounded);
+    ops::RangeTo<FromEnd> => |this| (Bound::Unbounded, Bound::Excluded(this.end));
+    ops::RangeToInclusive<FromEnd> => |this| (Bound::Unbounded, Bound::Included(this.end));
+}
+
+/// A strided index, `count` regions that start at `start` and are `step` bytes apart,
+/// for interleaved data such as audio channels or the fields of an array of structs.
+/// 
+/// It does not cover a single contiguous range, so it is not an [`Idx`],
+/// use [`get_strided`](crate::DataStructureSlice::get_strided) to get a view of the regions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Strided {
+    /// The index the first region starts at.
+    pub start: usize,
+    /// The distance between the starts of two following regions.
+    pub step: usize,
+    /// The amount of regions.
+    pub count: usize,
+}
+
+impl Strided {
+    /// Constructs a new [`Strided`].
+    #[inline]
+    pub const fn new(start: usize, step: usize, count: usize) -> Strided {
+        Strided { start, step, count }
+    }
+
+    /// Get's the index the region `n` starts at, or [None] if `n` is not smaller then the count.
+    #[inline]
+    pub const fn idx(&self, n: usize) -> Option<usize> {
+        if n >= self.count {
+            return None
+        }
+
+        match self.step.checked_mul(n) {
+            Some(offset) => self.start.checked_add(offset),
+            None => None,
+        }
+    }
+
+    /// Resolves the range covered by all the regions, each being `size` bytes, in data of `len` bytes.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the end of the last region overflows
+    /// or if any of the regions gets out of the data.
+    pub const fn resolve(&self, size: usize, len: usize) -> Result<ops::Range<usize>, IdxError> {
+        if self.count == 0 {
+            return resolve((Bound::Included(self.start), Bound::Excluded(self.start)), len)
+        }
+
+        let last: usize = match self.step.checked_mul(self.count - 1) {
+            Some(offset) => match self.start.checked_add(offset) {
+                Some(last) => last,
+                None => return Err(IdxError::Overflow { idx: self.start, size: offset }),
+            },
+            None => return Err(IdxError::Overflow { idx: self.start, size: usize::MAX }),
+        };
+
+        match last.checked_add(size) {
+            Some(end) => resolve((Bound::Included(self.start), Bound::Excluded(end)), len),
+            None => Err(IdxError::Overflow { idx: last, size }),
+        }
+    }
+}
+
+/// A handle to a value of type `T` written at an index, given by
+/// [`write_typed`](crate::RawDataStructure::write_typed).
+/// 
+/// [`read_at`](crate::RawDataStructure::read_at) and [`take_at`](crate::RawDataStructure::take_at)
+/// only accept a handle of the same type, so the value is read as the same type it was written as.
+/// Taking the value consumes the handle, so it can not be taken twice through it.
+/// 
+/// As an index it covers the `size_of::<T>()` bytes of the value.
+#[must_use]
+pub struct TypedIdx<T> {
+    idx: usize,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> TypedIdx<T> {
+    /// Constructs a handle to a value of type `T` at the given index.
+    /// 
+    /// # SAFETY
+    /// Make sure a valid `T` is at the index of every data structure the handle is used with.
+    #[inline]
+    pub const unsafe fn new(idx: usize) -> TypedIdx<T> {
+        TypedIdx { idx, _marker: core::marker::PhantomData }
+    }
+
+    /// Get's the index the value starts at.
+    #[inline]
+    pub const fn idx(&self) -> usize {
+        self.idx
+    }
+}
+
+impl<T> core::fmt::Debug for TypedIdx<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "TypedIdx<{}>({})", core::any::type_name::<T>(), self.idx)
+    }
+}
+
+impl<T> PartialEq for TypedIdx<T> {
+    #[inline] fn eq(&self, other: &Self) -> bool {
+        self.idx == other.idx
+    }
+}
+
+impl<T> Eq for TypedIdx<T> {}
+
+impl<T> Sealed for TypedIdx<T> {}
+impl<T> Idx for TypedIdx<T> {
+    #[inline] fn start(&self) -> Bound<usize> { StartLen::new(self.idx, core::mem::size_of::<T>()).start() }
+    #[inline] fn end(&self) -> Bound<usize> { StartLen::new(self.idx, core::mem::size_of::<T>()).end() }
+    #[inline] fn resolve(&self, len: usize) -> Result<ops::Range<usize>, IdxError> { StartLen::new(self.idx, core::mem::size_of::<T>()).resolve(len) }
+}
+
 #[cfg(feature = "new_range_api")]
 mod range_impl {
     use super::*;
@@ -0,0 +1,141 @@
+
+/*!
+This module provides the tracking of which bytes of a data structure were ever written,
+to catch reads of uninitialized data in debug builds.
+
+When the `init-tracking` feature is on, every [`DataBoxed::uninit`](crate::boxed::DataBoxed::uninit)
+registers a shadow bitmap of it's bytes. The writes done through the
+[`RawDataStructure`](crate::RawDataStructure) methods mark the bytes they write and
+[`take`](crate::RawDataStructure::take) and [`read_ref`](crate::RawDataStructure::read_ref)
+panic (in debug builds) if they touch a byte that was never written.
+
+## Limitations
+- The regions are found by their address, so [`DataArray::uninit`](crate::array::DataArray::uninit)
+  is not tracked: it is const and the array moves with it's value, so there is no address to
+  track it at. [`track`] can be called once the array is in it's final place.
+- Only the [`RawDataStructure`](crate::RawDataStructure) methods are seen, writes through the
+  const inherent methods, raw pointers or the underlying bytes are not. Mutable pointers
+  and refrences given out by the read methods count as written, so they never give false positives.
+- A [`DataBoxed`](crate::boxed::DataBoxed) stops being tracked when it is dropped, but memory
+  handed over to another owner (like when it is turned into a `Box<DataSlice>`) and regions
+  tracked with [`track`] keep being tracked until [`forget`] is called or a new region is
+  tracked over the same memory.
+ */
+
+use crate::slice::DataSlice;
+use crate::std::{
+    sync::Mutex,
+    vec::Vec,
+};
+
+struct Region {
+    start: usize,
+    size: usize,
+    written: Vec<u8>,
+}
+
+impl Region {
+    #[inline]
+    fn overlaps(&self, start: usize, size: usize) -> bool {
+        start < self.start + self.size && self.start < start + size
+    }
+}
+
+static REGIONS: Mutex<Vec<Region>> = Mutex::new(Vec::new());
+
+fn with_regions<R>(f: impl FnOnce(&mut Vec<Region>) -> R) -> R {
+    f(&mut REGIONS.lock().unwrap_or_else(#[inline] |poison| poison.into_inner()))
+}
+
+fn remove_overlapping(regions: &mut Vec<Region>, start: usize, size: usize) {
+    regions.retain(#[inline] |region| !region.overlaps(start, size));
+}
+
+/// Starts tracking the given data, with all of it's bytes marked as never written.
+/// 
+/// Any region that was tracked in the same memory before is replaced.
+pub fn track(data: &DataSlice) {
+    if data.size() == 0 {
+        return
+    }
+
+    with_regions(|regions| {
+        remove_overlapping(regions, data.addr_of_idx(0), data.size());
+        regions.push(Region {
+            start: data.addr_of_idx(0),
+            size: data.size(),
+            written: crate::std::vec![0; data.size().div_ceil(8)],
+        });
+    });
+}
+
+/// Stops tracking all the regions that overlap the given data.
+pub fn forget(data: &DataSlice) {
+    if data.size() == 0 {
+        return
+    }
+
+    with_regions(|regions| remove_overlapping(regions, data.addr_of_idx(0), data.size()));
+}
+
+/// Checks weather all the bytes of the given data were written,
+/// the bytes that are not tracked always count as written.
+pub fn is_written(data: &DataSlice) -> bool {
+    first_unwritten(data.as_ptr(), data.size()).is_none()
+}
+
+/// Marks the `size` bytes starting from `ptr` as written.
+pub(crate) fn mark_written(ptr: *const u8, size: usize) {
+    if size == 0 {
+        return
+    }
+
+    let start: usize = ptr.addr();
+
+    with_regions(|regions| {
+        for region in regions.iter_mut().filter(#[inline] |region| region.overlaps(start, size)) {
+            let from: usize = start.max(region.start) - region.start;
+            let to: usize = (start + size).min(region.start + region.size) - region.start;
+
+            for at in from..to {
+                region.written[at / 8] |= 1 << (at % 8);
+            }
+        }
+    })
+}
+
+/// Get's the address of the first byte in the `size` bytes from `ptr` that was never written.
+fn first_unwritten(ptr: *const u8, size: usize) -> Option<usize> {
+    if size == 0 {
+        return None
+    }
+
+    let start: usize = ptr.addr();
+
+    with_regions(|regions| {
+        regions.iter()
+            .filter(#[inline] |region| region.overlaps(start, size))
+            .find_map(|region| {
+                let from: usize = start.max(region.start) - region.start;
+                let to: usize = (start + size).min(region.start + region.size) - region.start;
+
+                (from..to)
+                    .find(#[inline] |at| region.written[at / 8] & (1 << (at % 8)) == 0)
+                    .map(#[inline] |at| region.start + at)
+            })
+    })
+}
+
+/// Panics (in debug builds) if any of the `size` bytes starting from `ptr` was never written.
+pub(crate) fn check_read(ptr: *const u8, size: usize) {
+    if !cfg!(debug_assertions) {
+        return
+    }
+
+    if let Some(addr) = first_unwritten(ptr, size) {
+        panic!(
+            "Read `{size}` bytes of which the one at offset `{offset}` was never written.",
+            offset = addr - ptr.addr(),
+        )
+    }
+}
@@ -0,0 +1,293 @@
+
+/*!
+This module provides helpers for laying out data of mixed types in a data structure,
+so offsets that need to be aligned can be computed through this crate instead of by hand.
+
+The alignment here is always relative to an index, not to an address in memory,
+use [`DataSlice::align_offset`](crate::slice::DataSlice::align_offset) to
+find where the aligned addresses of a slice start.
+
+To plan the layout of a whole data structure there is the [`OffsetBuilder`],
+which (like all the helpers here) can also be used in const contexts.
+
+The [`ByteOffset`] type is an offset whose arithmetic is always checked,
+so an offset that overflows gives [None] instead of silently wrapping.
+
+For layouts that are fixed at compile time there is the [`layout!`](crate::layout!) macro,
+which computes the offsets of all the fields as constants.
+ */
+
+/// Get's the amount of bytes that need to be skipped from `idx` to get to the next multiple of `align`.
+/// 
+/// `align` does not need to be a power of two.
+/// 
+/// # PANICS
+/// Will panic if `align` is `0`.
+#[inline]
+pub const fn padding_needed(idx: usize, align: usize) -> usize {
+    match idx % align {
+        0 => 0,
+        rem => align - rem,
+    }
+}
+
+/// Get's the smallest multiple of `align` that is greater then or equal to `idx`,
+/// or [None] if it does not fit in a [usize].
+/// 
+/// `align` does not need to be a power of two.
+/// 
+/// # PANICS
+/// Will panic if `align` is `0`.
+#[inline]
+pub const fn next_aligned(idx: usize, align: usize) -> Option<usize> {
+    idx.checked_add(padding_needed(idx, align))
+}
+
+/// An offset in bytes from the start of a data structure, with checked arithmetic.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteOffset(pub usize);
+
+impl ByteOffset {
+    /// The offset of the start of a data structure.
+    pub const ZERO: ByteOffset = ByteOffset(0);
+
+    /// Constructs a new [`ByteOffset`].
+    #[inline]
+    pub const fn new(offset: usize) -> ByteOffset {
+        ByteOffset(offset)
+    }
+
+    /// Get's the offset as a [usize].
+    #[inline]
+    pub const fn get(self) -> usize {
+        self.0
+    }
+
+    /// Moves the offset forward by `bytes` bytes, or gives [None] if it overflows.
+    #[inline]
+    pub const fn add(self, bytes: usize) -> Option<ByteOffset> {
+        match self.0.checked_add(bytes) {
+            Some(offset) => Some(ByteOffset(offset)),
+            None => None,
+        }
+    }
+
+    /// Moves the offset forward past a value of type `T`, or gives [None] if it overflows.
+    #[inline]
+    pub const fn add_sized<T>(self) -> Option<ByteOffset> {
+        self.add(core::mem::size_of::<T>())
+    }
+
+    /// Moves the offset forward to the next multiple of `align`, or gives [None] if it overflows.
+    /// 
+    /// # PANICS
+    /// Will panic if `align` is `0`.
+    #[inline]
+    pub const fn align_up(self, align: usize) -> Option<ByteOffset> {
+        match next_aligned(self.0, align) {
+            Some(offset) => Some(ByteOffset(offset)),
+            None => None,
+        }
+    }
+
+    /// Moves the offset forward to the next offset aligned for `T`, or gives [None] if it overflows.
+    #[inline]
+    pub const fn align_up_for<T>(self) -> Option<ByteOffset> {
+        self.align_up(core::mem::align_of::<T>())
+    }
+
+    /// Get's the amount of bytes from this offset to the next multiple of `align`.
+    /// 
+    /// # PANICS
+    /// Will panic if `align` is `0`.
+    #[inline]
+    pub const fn padding_to(self, align: usize) -> usize {
+        padding_needed(self.0, align)
+    }
+}
+
+impl From<usize> for ByteOffset {
+    #[inline] fn from(offset: usize) -> ByteOffset {
+        ByteOffset(offset)
+    }
+}
+
+impl From<ByteOffset> for usize {
+    #[inline] fn from(offset: ByteOffset) -> usize {
+        offset.0
+    }
+}
+
+impl core::fmt::Display for ByteOffset {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// A calculator for laying out values one after another, each at an offset aligned for it.
+/// 
+/// Every reservation returns the offset it was placed at, so the values can then be
+/// written at those offsets in a data structure of (at least) [`size`](OffsetBuilder::size) bytes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffsetBuilder {
+    end: ByteOffset,
+    align: usize,
+}
+
+impl OffsetBuilder {
+    /// Constructs a new empty [`OffsetBuilder`].
+    #[inline]
+    pub const fn new() -> OffsetBuilder {
+        OffsetBuilder { end: ByteOffset::ZERO, align: 1 }
+    }
+
+    /// Reserves space for a value of type `T`, aligned for it,
+    /// and returns the offset it was placed at.
+    /// 
+    /// # PANICS
+    /// Will panic if the size overflows.
+    #[inline]
+    pub const fn reserve<T>(&mut self) -> usize {
+        self.reserve_bytes(core::mem::size_of::<T>(), core::mem::align_of::<T>())
+    }
+
+    /// Reserves `size` bytes aligned to `align` and returns the offset they were placed at.
+    /// 
+    /// # PANICS
+    /// Will panic if `align` is `0` or if the size overflows.
+    pub const fn reserve_bytes(&mut self, size: usize, align: usize) -> usize {
+        let offset: ByteOffset = match self.end.align_up(align) {
+            Some(offset) => offset,
+            None => panic!("The layout of an `OffsetBuilder` overflowed."),
+        };
+
+        self.end = match offset.add(size) {
+            Some(end) => end,
+            None => panic!("The layout of an `OffsetBuilder` overflowed."),
+        };
+
+        if align > self.align {
+            self.align = align;
+        }
+
+        offset.get()
+    }
+
+    /// Get's the amount of bytes needed for everything reserved so far.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.end.get()
+    }
+
+    /// Get's the offset right after everything reserved so far,
+    /// where the next reservation would start if it needs no padding.
+    #[inline]
+    pub const fn end(&self) -> ByteOffset {
+        self.end
+    }
+
+    /// Get's the largest alignment of everything reserved so far.
+    #[inline]
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+}
+
+impl Default for OffsetBuilder {
+    #[inline] fn default() -> OffsetBuilder {
+        OffsetBuilder::new()
+    }
+}
+
+/// A compile time check that `NEEDED` bytes fit in `SIZE` bytes,
+/// used by the [`layout!`](crate::layout!) macro.
+/// 
+/// Using [`OK`](AssertFits::OK) fails to compile if they do not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AssertFits<const NEEDED: usize, const SIZE: usize>;
+
+impl<const NEEDED: usize, const SIZE: usize> AssertFits<NEEDED, SIZE> {
+    /// Fails to compile if `NEEDED` is greater then `SIZE`.
+    pub const OK: () = assert!(NEEDED <= SIZE, "The data array is too small for the layout.");
+}
+
+/// Declares a unit struct whose associated constants are the offsets of the given fields,
+/// laid out one after another each at an offset aligned for it (the same way [`OffsetBuilder`] does).
+/// 
+/// ```text
+/// layout! {
+///     pub struct PacketLayout {
+///         header: Header,
+///         count: u32,
+///         payload: [u8; 256],
+///     }
+/// }
+/// ```
+/// 
+/// Gives the constants `PacketLayout::header`, `PacketLayout::count` and `PacketLayout::payload`
+/// with the offset of each field, `PacketLayout::SIZE` with the total size and
+/// `PacketLayout::ALIGN` with the largest alignment of the fields.
+/// 
+/// It also gives `PacketLayout::array()`, which constructs a zeroed [`DataArray`](crate::array::DataArray)
+/// of exactly `SIZE` bytes, and `PacketLayout::check(&data)`, which fails to compile if the
+/// given [`DataArray`](crate::array::DataArray) is too small for the layout.
+/// 
+/// If the layout overflows, or a field is named like one of the generated items
+/// (`SIZE`, `ALIGN`, `array` or `check`), it fails to compile.
+#[macro_export]
+macro_rules! layout {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident { $($field:ident : $ty:ty),* $(,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $name;
+
+        #[allow(non_upper_case_globals, dead_code)]
+        impl $name {
+            $crate::layout!(@fields 0; $($field: $ty,)*);
+
+            /// The largest alignment of the fields.
+            pub const ALIGN: usize = {
+                let mut builder: $crate::layout::OffsetBuilder = $crate::layout::OffsetBuilder::new();
+                $( builder.reserve::<$ty>(); )*
+                builder.align()
+            };
+
+            /// Constructs a zeroed data array of exactly the size of the layout.
+            #[inline]
+            pub const fn array() -> $crate::array::DataArray<{ $name::SIZE }> {
+                $crate::array::DataArray::zeroed()
+            }
+
+            /// Fails to compile if the given data array is too small for the layout.
+            #[inline]
+            pub const fn check<const SIZE: usize>(_data: &$crate::array::DataArray<SIZE>) {
+                $crate::layout::AssertFits::<{ $name::SIZE }, SIZE>::OK
+            }
+        }
+    };
+    (@fields $end:expr; $field:ident : $ty:ty, $($rest:tt)*) => {
+        $crate::layout!(@reserved $field);
+
+        /// The offset of the field.
+        pub const $field: usize = match $crate::layout::ByteOffset($end).align_up_for::<$ty>() {
+            Some(offset) => offset.get(),
+            None => panic!("The layout overflowed."),
+        };
+
+        $crate::layout!(@fields match $crate::layout::ByteOffset(Self::$field).add_sized::<$ty>() {
+            Some(end) => end.get(),
+            None => panic!("The layout overflowed."),
+        }; $($rest)*);
+    };
+    (@fields $end:expr;) => {
+        /// The total size of the layout.
+        pub const SIZE: usize = $end;
+    };
+    (@reserved SIZE) => { compile_error!("A `layout!` field can't be named `SIZE`, it is the total size of the layout."); };
+    (@reserved ALIGN) => { compile_error!("A `layout!` field can't be named `ALIGN`, it is the largest alignment of the fields."); };
+    (@reserved array) => { compile_error!("A `layout!` field can't be named `array`, it constructs the data array of the layout."); };
+    (@reserved check) => { compile_error!("A `layout!` field can't be named `check`, it checks the size of a data array."); };
+    (@reserved $field:ident) => {};
+}
@@ -8,6 +8,8 @@ TODO
 #![cfg_attr(feature = "allocator_api", feature(try_reserve_kind))]
 #![cfg_attr(feature = "new_range_api", feature(new_range_api))]
 #![cfg_attr(feature = "generic_const_exprs", feature(generic_const_exprs))]
+#![cfg_attr(feature = "provenance", feature(strict_provenance_lints))]
+#![cfg_attr(feature = "provenance", deny(fuzzy_provenance_casts, lossy_provenance_casts))]
 #![no_std]
 
 #![warn(missing_docs)]
@@ -19,34 +21,239 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "embedded-io")]
+extern crate embedded_io;
+
+#[cfg(all(any(feature = "shm", feature = "guard-pages"), unix))]
+extern crate libc;
+
+#[cfg(all(any(feature = "shm", feature = "guard-pages"), windows))]
+extern crate windows_sys;
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+extern crate js_sys;
+
+#[cfg(feature = "parking_lot")]
+extern crate parking_lot;
+
+#[cfg(feature = "trace")]
+#[macro_use]
+extern crate log;
+
+#[cfg(feature = "ufmt")]
+extern crate ufmt;
+
+#[cfg(feature = "xxhash")]
+extern crate xxhash_rust;
+
+#[cfg(feature = "getrandom")]
+extern crate getrandom;
+
+#[cfg(feature = "rand")]
+extern crate rand;
+
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+
+/// Checks in debug builds (or always with the `checked-unchecked` feature) that the
+/// `size` bytes starting from `idx` are inside of the data structure, so the misuse of an
+/// unchecked function is caught instead of silently corrupting the data next to it.
+macro_rules! debug_check_bounds {
+    ($data:expr, $idx:expr, $size:expr) => {
+        if cfg!(any(debug_assertions, feature = "checked-unchecked")) {
+            match ($idx).checked_add($size) {
+                Some(end) if end <= ($data).size() => {},
+                _ => panic!("An unchecked function was given a region outside of the data structure."),
+            }
+        }
+    };
+}
+
+/// Checks in debug builds (or always with the `checked-unchecked` feature) that the
+/// pointer is aligned, for the unchecked functions that ask for aligned data.
+macro_rules! debug_check_align {
+    ($ptr:expr) => {
+        if cfg!(any(debug_assertions, feature = "checked-unchecked")) && !($ptr).is_aligned() {
+            panic!("An unchecked function was given a region that is not aligned for it's type.")
+        }
+    };
+}
+
+/// Emits a `trace` level [`log`](https://docs.rs/log) event with the `trace` feature,
+/// for the checked accesses of a [`RawDataStructure`], so the access history of a buffer can be
+/// reconstructed. The data structure is identified by the address of it's first byte.
+/// 
+/// This is also used by the non `const` accessors of [`DataSlice`](slice::DataSlice), the `const` ones can't log.
+macro_rules! trace_access {
+    ($data:expr, $op:literal, $idx:expr, $size:expr, $ty:ty) => {
+        #[cfg(feature = "trace")]
+        trace!(
+            target: "typeless_data",
+            "{} `{}` bytes of `{}` at offset `{}` of `{:p}`",
+            $op,
+            $size,
+            core::any::type_name::<$ty>(),
+            $idx,
+            // SAFETY: A zero sized read from the start is always in bounds.
+            unsafe { ($data).read_unchecked::<()>(0) },
+        );
+    };
+}
+
 pub mod array;
 pub mod slice;
 #[cfg(feature = "alloc")]
 pub mod boxed;
+#[cfg(feature = "alloc")]
+pub mod vec;
+#[cfg(feature = "alloc")]
+pub mod cells;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(feature = "init-tracking")]
+pub mod init;
+#[cfg(all(feature = "shm", any(unix, windows)))]
+pub mod shm;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub mod wasm;
+#[cfg(all(feature = "guard-pages", any(unix, windows)))]
+pub mod guard_pages;
 
 mod const_ops;
 // pub use const_ops::*;
 
 pub mod idx;
-
-#[cfg(feature = "ptr_metadata")]
-trait GetSizeOf<T: ?Sized> {
+pub mod cursor;
+pub mod bits;
+pub mod varint;
+pub mod hex;
+pub mod checksum;
+pub mod tlv;
+pub mod packet;
+pub mod fmt;
+pub mod volatile;
+pub mod guarded;
+pub mod secure;
+pub mod error;
+pub mod strided;
+pub mod layout;
+#[cfg(feature = "alloc")]
+pub mod schema;
+#[cfg(feature = "alloc")]
+pub mod chain;
+#[cfg(feature = "alloc")]
+pub mod rope;
+#[cfg(feature = "alloc")]
+pub mod overlay;
+#[cfg(feature = "alloc")]
+pub mod patch;
+#[cfg(feature = "alloc")]
+pub mod watch;
+#[cfg(feature = "alloc")]
+pub mod erased;
+#[cfg(feature = "alloc")]
+pub mod checked;
+pub mod slotmap;
+pub mod arena;
+pub mod heap;
+pub mod buddy;
+pub mod pool;
+pub mod stack;
+pub mod queue;
+pub mod ring;
+pub mod command;
+pub mod task;
+pub mod map;
+
+pub use error::Error;
+
+/// Get's the size (in bytes) of a value of type `T` from the metadata of a pointer to it,
+/// so the unsized reads of [`RawDataStructure`] (like [`read_unsized`](RawDataStructure::read_unsized))
+/// can check that the value fits in the data structure.
+/// 
+/// It is implemented for the metadata of sized types (`()`), slices and strings (`usize`)
+/// and trait objects (`DynMetadata`, with the `ptr_metadata` feature). For a custom dynamically sized type
+/// implement it for it's metadata, with the size of the whole value (header included):
+/// 
+/// ```ignore
+/// struct Packet {
+///     len: u16,
+///     payload: [u8],
+/// }
+/// 
+/// // SAFETY: The size is the one of the header and the payload, padded to the alignment of the header.
+/// unsafe impl GetSizeOf<Packet> for usize {
+///     fn size(&self) -> usize {
+///         core::mem::size_of::<u16>().saturating_add(*self)
+///             .checked_next_multiple_of(core::mem::align_of::<u16>())
+///             .unwrap_or(usize::MAX)
+///     }
+/// }
+/// ```
+/// 
+/// # SAFETY
+/// The size given must never be smaller then the size of a `T` with this metadata
+/// (as given by [`size_of_val`](core::mem::size_of_val)), since the unsized reads only check
+/// that this many bytes are in the data structure before making a pointer to the value.
+pub unsafe trait GetSizeOf<T: ?Sized> {
+    /// Get's the size of a `T` that has this metadata.
+    /// 
+    /// If the size does not fit in an [usize] this should give [`usize::MAX`],
+    /// so the value is never thought to fit in a data structure.
     fn size(&self) -> usize;
 }
 
-#[cfg(feature = "ptr_metadata")]
-impl<T: Sized> GetSizeOf<T> for () {
+// SAFETY: These are the sizes `size_of_val` gives for the values with this metadata.
+unsafe impl<T: Sized> GetSizeOf<T> for () {
     #[inline] fn size(&self) -> usize { core::mem::size_of::<T>() }
 }
+unsafe impl<T: Sized> GetSizeOf<[T]> for usize {
+    #[inline] fn size(&self) -> usize { self.saturating_mul(core::mem::size_of::<T>()) }
+}
+unsafe impl GetSizeOf<str> for usize {
+    #[inline] fn size(&self) -> usize { *self }
+}
 #[cfg(feature = "ptr_metadata")]
-impl<T: ?Sized> GetSizeOf<T> for usize {
+unsafe impl GetSizeOf<core::ffi::CStr> for usize {
+    #[inline] fn size(&self) -> usize { *self }
+}
+unsafe impl GetSizeOf<slice::DataSlice> for usize {
     #[inline] fn size(&self) -> usize { *self }
 }
 #[cfg(feature = "ptr_metadata")]
-impl<T: ?Sized> GetSizeOf<T> for core::ptr::DynMetadata<T> {
+unsafe impl<T: ?Sized> GetSizeOf<T> for core::ptr::DynMetadata<T> {
     #[inline] fn size(&self) -> usize { self.size_of() }
 }
 
+/// The dynamically sized types whose metadata is their length, which
+/// [`read_slice_like`](RawDataStructure::read_slice_like) can read on stable.
+/// 
+/// With the `ptr_metadata` feature `read_unsized` can read any type through `Pointee` instead.
+/// 
+/// # SAFETY
+/// The pointers made must point to a value of `len` elements starting from `ptr`,
+/// that has the size given by [`GetSizeOf`].
+pub unsafe trait SliceLike where usize: GetSizeOf<Self> {
+    /// Makes a pointer to the value of `len` elements starting from `ptr`.
+    fn from_raw_parts(ptr: *const u8, len: usize) -> *const Self;
+
+    /// Makes a mutable pointer to the value of `len` elements starting from `ptr`.
+    fn from_raw_parts_mut(ptr: *mut u8, len: usize) -> *mut Self;
+}
+
+unsafe impl<T: Sized> SliceLike for [T] {
+    #[inline] fn from_raw_parts(ptr: *const u8, len: usize) -> *const [T] { core::ptr::slice_from_raw_parts(ptr.cast::<T>(), len) }
+    #[inline] fn from_raw_parts_mut(ptr: *mut u8, len: usize) -> *mut [T] { core::ptr::slice_from_raw_parts_mut(ptr.cast::<T>(), len) }
+}
+unsafe impl SliceLike for str {
+    #[inline] fn from_raw_parts(ptr: *const u8, len: usize) -> *const str { core::ptr::slice_from_raw_parts(ptr, len) as *const str }
+    #[inline] fn from_raw_parts_mut(ptr: *mut u8, len: usize) -> *mut str { core::ptr::slice_from_raw_parts_mut(ptr, len) as *mut str }
+}
+unsafe impl SliceLike for slice::DataSlice {
+    #[inline] fn from_raw_parts(ptr: *const u8, len: usize) -> *const slice::DataSlice { slice::DataSlice::from_slice_ptr(core::ptr::slice_from_raw_parts(ptr, len)) }
+    #[inline] fn from_raw_parts_mut(ptr: *mut u8, len: usize) -> *mut slice::DataSlice { slice::DataSlice::from_slice_ptr_mut(core::ptr::slice_from_raw_parts_mut(ptr, len)) }
+}
+
 #[doc()]
 pub struct DocTest;
 
@@ -80,6 +287,13 @@ current moment (eg: Vec's len) or the capacity of how much data can be stored
 - All indecies strictly smaller then the output given by [`size`](RawDataStructure::size)
 are valid indecies.
 
+# Zero Sized Accesses
+An access of `0` bytes (a zero sized type or an empty range) touches no memory,
+so it succeeds at any index smaller then or equal to [`size`](RawDataStructure::size)
+(the end of the data structure included), zero sized fills and copies do nothing,
+and an empty range gives an empty [`DataSlice`](slice::DataSlice).
+An index greater then the size is still an error, even for zero sized accesses.
+
 # Implementation Details
 Finnally: if 'idx' is index, identitifaction, id extras, io do externals or anythign else is up to the implementor
 (the termenology does not matter as long as you know what the implementor is refering to)
@@ -97,7 +311,7 @@ pub unsafe trait RawDataStructure {
     /// this function checks for is that the slice of size `size` starting from
     /// the index `idx` fits fully within the allocated/stored memory region of
     /// the data structure.
-    /// (aka: `idx + size < self.size()`)
+    /// (aka: `idx + size <= self.size()`)
     /// 
     /// Meaning of each input:
     /// - `idx`: The starting index of the check.
@@ -138,11 +352,47 @@ pub unsafe trait RawDataStructure {
     /// 
     /// The default implementation just calls both functions and returns an error if eather one errors, otherwise [`Ok(())`](Ok).
     /// But for omtimization purpaces you may change this function's implementation, but it works in all cases by default.
+    /// 
+    /// This is also what the methods that give mutable access (like [`read_mut`](RawDataStructure::read_mut)) check.
     fn full_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
         self.read_validity(idx, size)?;
         self.write_validity(idx, size)
     }
 
+    /// Checks weather the data at the given index is aligned to `align` bytes,
+    /// where `align` should be a power of two.
+    /// 
+    /// This lets the "make sure the data is aligned" safety requirment of the
+    /// unchecked functions be checked instead of assumed.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is outside of the data structure (the end of it is allowed),
+    /// or a [`Misaligned`](idx::IdxError::Misaligned) error if `align` is not a power of two or the data at the index is not aligned to it.
+    fn align_validity(&self, idx: usize, align: usize) -> Result<(), idx::IdxError> {
+        self.read_validity(idx, 0)?;
+
+        // SAFETY: A zero sized read at an index that is not outside the data structure is valid.
+        let ptr: *const () = unsafe { self.read_unchecked::<()>(idx) };
+
+        if !align.is_power_of_two() || ptr.addr() & (align - 1) != 0 {
+            return Err(idx::IdxError::misaligned(ptr, align))
+        }
+
+        Ok(())
+    }
+
+    /// Checks weather a value of type `T` can be read at the given index,
+    /// that is weather it fits in the data structure and the data at the index is aligned for `T`.
+    /// 
+    /// # ERRORS
+    /// Will return the error of [`read_validity`](RawDataStructure::read_validity) for the size of `T`,
+    /// otherwise the error of [`align_validity`](RawDataStructure::align_validity) for the alignment of `T`.
+    #[inline]
+    fn typed_validity<T>(&self, idx: usize) -> Result<(), idx::IdxError> {
+        self.read_validity(idx, core::mem::size_of::<T>())?;
+        self.align_validity(idx, core::mem::align_of::<T>())
+    }
+
     /// Writes the given value at the given index.
     /// 
     /// If you want to store a [?Sized](Sized) value use [write_unsized](RawDataStructure::write_unsized)
@@ -155,15 +405,56 @@ pub unsafe trait RawDataStructure {
     /// # SAFETY
     /// Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
-    unsafe fn write<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) -> Result<(), (core::mem::ManuallyDrop<T>, idx::IdxError)> {
+    unsafe fn write<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) -> Result<(), (core::mem::ManuallyDrop<T>, idx::TypedIdxError)> {
         if let Err(err) = self.write_validity(idx, core::mem::size_of::<T>()) {
-            return Err((value, err));
+            return Err((value, err.typed::<T>(idx::Access::Write)));
         }
 
+        trace_access!(self, "write", idx, core::mem::size_of::<T>(), T);
         self.write_unchecked(idx, value);
         Ok(())
     }
 
+    /// Writes the given value at the given index and gives back a handle
+    /// that can only be used to read it as the same type.
+    /// 
+    /// # ERRORS
+    /// Will return the same errors as [`write`](RawDataStructure::write).
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    unsafe fn write_typed<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) -> Result<idx::TypedIdx<T>, (core::mem::ManuallyDrop<T>, idx::TypedIdxError)> {
+        self.write::<T>(idx, value)?;
+        Ok(idx::TypedIdx::new(idx))
+    }
+
+    /// Returns a refrence to the value the handle points to.
+    /// 
+    /// # ERRORS
+    /// Will return the same errors as [`read_aligned`](RawDataStructure::read_aligned).
+    /// 
+    /// # SAFETY
+    /// - Make sure the handle was made for this data structure
+    /// - Make sure the value was not changed (other then through the handle) since it was written
+    unsafe fn read_at<T: Sized>(&self, handle: &idx::TypedIdx<T>) -> Result<&T, idx::TypedIdxError> {
+        self.read_ref_aligned::<T>(handle.idx())
+    }
+
+    /// Takes the value the handle points to, consuming the handle.
+    /// 
+    /// Note: This does NOT zero out the specified region
+    /// 
+    /// # ERRORS
+    /// Will return the same errors as [`take`](RawDataStructure::take).
+    /// 
+    /// # SAFETY
+    /// - Make sure the handle was made for this data structure
+    /// - Make sure the value was not changed (other then through the handle) since it was written
+    unsafe fn take_at<T: Sized>(&self, handle: idx::TypedIdx<T>) -> Result<T, idx::TypedIdxError> {
+        self.take::<T>(handle.idx())
+    }
+
     /// Writes the given value at the given index.
     /// 
     /// If you want to store a [?Sized](Sized) value use [write_unsized](RawDataStructure::write_unsized)
@@ -176,6 +467,42 @@ pub unsafe trait RawDataStructure {
         self.write_unsized_unchecked(idx, &value)
     }
 
+    /// Writes the given value at the given index with a volatile write,
+    /// so the write is never removed or merged by the compiler (for example for
+    /// buffers that are read by DMA hardware).
+    /// 
+    /// # SAFETY
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data is aligned
+    unsafe fn write_volatile<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) -> Result<(), (core::mem::ManuallyDrop<T>, idx::TypedIdxError)> {
+        if let Err(err) = self.write_validity(idx, core::mem::size_of::<T>()) {
+            return Err((value, err.typed::<T>(idx::Access::Write)));
+        }
+
+        self.write_volatile_unchecked(idx, value);
+        Ok(())
+    }
+
+    /// Writes the given value at the given index with a volatile write,
+    /// so the write is never removed or merged by the compiler.
+    /// 
+    /// # SAFETY
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure data isn't written outside the data structure
+    /// - Make sure the data is aligned
+    unsafe fn write_volatile_unchecked<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+        debug_check_align!(self.read_mut_unchecked::<T>(idx));
+
+        unsafe {
+            self.read_mut_unchecked::<T>(idx) // SAFETY: The caller must uphold the safety contract.
+                .cast::<core::mem::ManuallyDrop<T>>()
+                .write_volatile(value) // SAFETY: The caller must uphold the safety contract.
+        }
+    }
+
     /// Fills with `0`'s the specified bytes
     /// 
     /// # SAFETY
@@ -243,6 +570,7 @@ pub unsafe trait RawDataStructure {
             )
         )?;
 
+        trace_access!(self, "write", idx, core::mem::size_of_val(&*value), T);
         self.write_unsized_unchecked(idx, value);
 
         Ok(())
@@ -269,12 +597,128 @@ pub unsafe trait RawDataStructure {
     /// - Make sure no data is written to a region outside of the specified data structure
     unsafe fn write_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>);
 
+    /// Writes the metadata of the given value at the given index, followed by the value it'self,
+    /// so it can be read back with [read_unsized_self_described](RawDataStructure::read_unsized_self_described)
+    /// without keeping the length (or vtable) of it anywhere else.
+    /// 
+    /// The value starts at `idx + size_of::<T::Metadata>()` and the metadata is not aligned in memory.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # SAFETY
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [`ManuallyDrop`](core::mem::ManuallyDrop))
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn write_unsized_with_meta<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) -> Result<(), idx::IdxError> {
+        let (meta, size): (usize, usize) = (
+            core::mem::size_of::<T::Metadata>(),
+            core::mem::size_of_val::<core::mem::ManuallyDrop<T>>(
+                value.as_ref().expect("A null pointer was given to write_unsized_with_meta.")
+            ),
+        );
+
+        let start: usize = idx.checked_add(meta).ok_or(idx::IdxError::Overflow { idx, size: meta })?;
+        self.write_validity(idx, size.checked_add(meta).ok_or(idx::IdxError::Overflow { idx, size: usize::MAX })?)?;
+
+        trace_access!(self, "write", idx, meta + size, T);
+        self.read_mut_unchecked::<T::Metadata>(idx).write_unaligned(core::ptr::metadata(value));
+        self.write_unsized_unchecked(start, value);
+
+        Ok(())
+    }
+
+    /// Writes the vtable of the given trait object at the given index, followed by the value it'self,
+    /// so it can be read back as the trait object with [read_dyn](RawDataStructure::read_dyn).
+    /// 
+    /// This is [write_unsized_with_meta](RawDataStructure::write_unsized_with_meta) for trait objects only.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # SAFETY
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [`ManuallyDrop`](core::mem::ManuallyDrop))
+    #[cfg(feature = "ptr_metadata")]
+    #[inline]
+    unsafe fn write_dyn<T: ?Sized + core::ptr::Pointee<Metadata = core::ptr::DynMetadata<T>>>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) -> Result<(), idx::IdxError> {
+        self.write_unsized_with_meta::<T>(idx, value)
+    }
+
+    /// Moves the boxed trait object into the data structure like [write_dyn](RawDataStructure::write_dyn),
+    /// freeing the box without dropping the value.
+    /// 
+    /// # ERRORS
+    /// Will give the box back together with the error if the value does not fit in the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    #[cfg(all(feature = "ptr_metadata", feature = "alloc"))]
+    unsafe fn write_dyn_boxed<T: ?Sized + core::ptr::Pointee<Metadata = core::ptr::DynMetadata<T>>>(&mut self, idx: usize, value: alloc::boxed::Box<T>) -> Result<(), (alloc::boxed::Box<T>, idx::IdxError)> {
+        let ptr: *mut T = alloc::boxed::Box::into_raw(value);
+
+        match self.write_dyn::<T>(idx, ptr as *const core::mem::ManuallyDrop<T>) {
+            Ok(()) => {
+                // SAFETY: The pointer came from a box, and the value was moved out of it so only the allocation is freed.
+                drop(alloc::boxed::Box::from_raw(ptr as *mut core::mem::ManuallyDrop<T>));
+                Ok(())
+            },
+            // SAFETY: The pointer came from a box and nothing was written.
+            Err(err) => Err((alloc::boxed::Box::from_raw(ptr), err)),
+        }
+    }
+
+    /// Writes all the values of the given slice one after another starting from the given index.
+    /// 
+    /// Unlike [write_unsized](RawDataStructure::write_unsized) this does not need the
+    /// `ptr_metadata` feature to read the values back, see [read_slice_of](RawDataStructure::read_slice_of).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the values do not fit in the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    unsafe fn write_slice_of<T: Copy>(&mut self, idx: usize, values: &[T]) -> Result<(), idx::IdxError> {
+        self.write_validity(idx, core::mem::size_of_val(values))?;
+
+        trace_access!(self, "write", idx, core::mem::size_of_val(values), [T]);
+        self.write_unsized_unchecked(idx, values as *const [T] as *const core::mem::ManuallyDrop<[T]>);
+
+        Ok(())
+    }
+
     /// Returns a pointer to the specified data region.
     /// 
     /// The pointer is guaranteed to be non-null.
     // Not using NonNull is intentional
-    fn read<T: Sized>(&self, idx: usize) -> Result<*const T, crate::idx::IdxError> {
-        self.read_validity(idx, core::mem::size_of::<T>())?;
+    fn read<T: Sized>(&self, idx: usize) -> Result<*const T, idx::TypedIdxError> {
+        self.read_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Read))?;
+        trace_access!(self, "read", idx, core::mem::size_of::<T>(), T);
+
+        Ok(
+            unsafe {
+                self.read_unchecked::<T>(idx)
+            }
+        )
+    }
+
+    /// Returns a pointer to the specified data region, checking that it is aligned for `T`.
+    /// 
+    /// The pointer is guaranteed to be non-null and aligned.
+    /// 
+    /// # ERRORS
+    /// Will return the same errors as [`read`](RawDataStructure::read), or a
+    /// [`Misaligned`](idx::IdxError::Misaligned) error if the data at the index is not aligned for `T`.
+    // Not using NonNull is intentional
+    fn read_aligned<T: Sized>(&self, idx: usize) -> Result<*const T, idx::TypedIdxError> {
+        self.typed_validity::<T>(idx).map_err(#[inline] |err| err.typed::<T>(idx::Access::Read))?;
+        trace_access!(self, "read", idx, core::mem::size_of::<T>(), T);
 
         Ok(
             unsafe {
@@ -289,15 +733,37 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is aligned
     /// - Make sure the data is valid
     // Not using NonNull is intentional
-    unsafe fn read_ref<T: Sized>(&self, idx: usize) -> Result<&T, crate::idx::IdxError> {
+    unsafe fn read_ref<T: Sized>(&self, idx: usize) -> Result<&T, idx::TypedIdxError> {
         self.read::<T>(idx).map(
             #[inline] |ptr| unsafe {
+                #[cfg(feature = "init-tracking")]
+                crate::init::check_read(ptr.cast::<u8>(), core::mem::size_of::<T>());
+
                 ptr.as_ref() // SAFETY: The caller msut uphold the safety contract.
                    .unwrap_unchecked() // SAFETY: read can never return a null ptr.
             }
         )
     }
 
+    /// Returns a refrence to the specified data region, checking that it is aligned for `T`.
+    /// 
+    /// # ERRORS
+    /// Will return the same errors as [`read_aligned`](RawDataStructure::read_aligned).
+    /// 
+    /// # SAFETY
+    /// - Make sure the data is valid
+    unsafe fn read_ref_aligned<T: Sized>(&self, idx: usize) -> Result<&T, idx::TypedIdxError> {
+        self.read_aligned::<T>(idx).map(
+            #[inline] |ptr| unsafe {
+                #[cfg(feature = "init-tracking")]
+                crate::init::check_read(ptr.cast::<u8>(), core::mem::size_of::<T>());
+
+                ptr.as_ref() // SAFETY: The pointer is aligned and the caller msut uphold the rest of the safety contract.
+                   .unwrap_unchecked() // SAFETY: read can never return a null ptr.
+            }
+        )
+    }
+
     /// Returns a pointer to the specified data region.
     /// 
     /// The pointer is guaranteed to ne non-null.
@@ -314,6 +780,10 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is aligned
     /// - Make sure the data is valid
     unsafe fn read_ref_unchecked<T: Sized>(&self, idx: usize) -> &T {
+        debug_check_align!(self.read_unchecked::<T>(idx));
+        #[cfg(feature = "init-tracking")]
+        crate::init::check_read(self.read_unchecked::<T>(idx).cast::<u8>(), core::mem::size_of::<T>());
+
         unsafe {
             self.read_unchecked::<T>(idx) // SAFETY: The caller must uphold the safety contract.
                 .as_ref() // SAFETY: The caller must uphold the safety contract.
@@ -325,8 +795,9 @@ pub unsafe trait RawDataStructure {
     /// 
     /// The pointer is guaranteed to ne non-null.
     // Not using NonNull is intentional
-    fn read_mut<T: Sized>(&mut self, idx: usize) -> Result<*mut T, crate::idx::IdxError> {
-        self.read_validity(idx, core::mem::size_of::<T>())?;
+    fn read_mut<T: Sized>(&mut self, idx: usize) -> Result<*mut T, idx::TypedIdxError> {
+        self.full_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Read))?;
+        trace_access!(self, "read_mut", idx, core::mem::size_of::<T>(), T);
 
         Ok(
             // SAFETY: The data will always be from within the data structure
@@ -343,7 +814,7 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is valid
     /// - Make sure there is only one refrence to
     ///   the specified data while whis refrence exists
-    unsafe fn read_ref_mut<T: Sized>(&mut self, idx: usize) -> Result<&mut T, crate::idx::IdxError> {
+    unsafe fn read_ref_mut<T: Sized>(&mut self, idx: usize) -> Result<&mut T, idx::TypedIdxError> {
         self.read_mut::<T>(idx).map(
             #[inline] |ptr| unsafe {
                 ptr.as_mut() // SAFETY: The caller msut uphold the safety contract.
@@ -371,6 +842,8 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is valid
     /// - Make sure there is only one refrence to the targeted value
     unsafe fn read_ref_mut_unchecked<T: Sized>(&mut self, idx: usize) -> &mut T {
+        debug_check_align!(self.read_mut_unchecked::<T>(idx));
+
         unsafe {
             self.read_mut_unchecked::<T>(idx) // SAFETY: The caller must uphold the safety contract.
                 .as_mut() // SAFETY: The caller msut uphold the safety contract.
@@ -378,11 +851,201 @@ pub unsafe trait RawDataStructure {
         }
     }
 
+    /// Returns a [`NonNull`](core::ptr::NonNull) pointer to the specified data region.
+    /// 
+    /// Since the pointer comes from a shared refrence it must NOT be written through,
+    /// use [read_mut_nonnull](RawDataStructure::read_mut_nonnull) for that.
+    fn read_nonnull<T: Sized>(&self, idx: usize) -> Result<core::ptr::NonNull<T>, idx::TypedIdxError> {
+        self.read::<T>(idx).map(
+            // SAFETY: read can never return a null ptr.
+            #[inline] |ptr| unsafe { core::ptr::NonNull::new_unchecked(ptr.cast_mut()) }
+        )
+    }
+
+    /// Returns a mutable [`NonNull`](core::ptr::NonNull) pointer to the specified data region.
+    fn read_mut_nonnull<T: Sized>(&mut self, idx: usize) -> Result<core::ptr::NonNull<T>, idx::TypedIdxError> {
+        self.read_mut::<T>(idx).map(
+            // SAFETY: read_mut can never return a null ptr.
+            #[inline] |ptr| unsafe { core::ptr::NonNull::new_unchecked(ptr) }
+        )
+    }
+
+    /// Returns a pinned mutable refrence to the specified data region.
+    /// 
+    /// This is made for self-referential and intrusive values (like the nodes of
+    /// an intrusive list) that are stored inside of a data structure used as an arena.
+    /// 
+    /// # Pinning
+    /// A [`Pin`](core::pin::Pin) promises that the value is never moved or reused before it is dropped,
+    /// but the data structure has no way of knowing there is a pinned value inside of it,
+    /// so that promise is made by the caller.
+    /// 
+    /// The buffer it'self must not move, this holds for data structures that keep their
+    /// bytes on the heap (like [`DataBoxed`](crate::boxed::DataBoxed)) even if the data structure
+    /// is moved, but NOT for ones that keep their bytes inline (like [`DataArray`](crate::array::DataArray)),
+    /// unless the data structure it'self is pinned.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data is aligned
+    /// - Make sure the data is valid
+    /// - Make sure there is only one refrence to
+    ///   the specified data while whis refrence exists
+    /// - Make sure the bytes of the value are not moved, overwritten or freed
+    ///   (the buffer growing, shrinking or being dropped included) until the value
+    ///   is dropped in place, unless `T` is [`Unpin`]
+    unsafe fn read_pin<T: Sized>(&mut self, idx: usize) -> Result<core::pin::Pin<&mut T>, idx::TypedIdxError> {
+        self.full_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Read))?;
+
+        Ok(
+            core::pin::Pin::new_unchecked( // SAFETY: The caller must uphold the pinning contract.
+                &mut *self.read_mut_unchecked::<T>(idx) // SAFETY: The caller must uphold the safety contract.
+            )
+        )
+    }
+
+    /// Reads a copy of the value at the specified data region with a volatile read,
+    /// so the read is never removed or merged by the compiler (for example for
+    /// buffers that are written by DMA hardware).
+    /// 
+    /// # SAFETY
+    /// - Make sure the data is aligned
+    /// - Make sure the data is valid
+    unsafe fn read_volatile<T: Sized>(&self, idx: usize) -> Result<T, idx::TypedIdxError> {
+        self.read_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Read))?;
+        Ok(self.read_volatile_unchecked(idx))
+    }
+
+    /// Reads a copy of the value at the specified data region with a volatile read,
+    /// so the read is never removed or merged by the compiler.
+    /// 
+    /// # SAFETY
+    /// - Make sure data isn't read from outside the data structure
+    /// - Make sure the data is aligned
+    /// - Make sure the data is valid
+    unsafe fn read_volatile_unchecked<T: Sized>(&self, idx: usize) -> T {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+        debug_check_align!(self.read_unchecked::<T>(idx));
+
+        unsafe {
+            self.read_unchecked::<T>(idx) // SAFETY: The caller must uphold the safety contract.
+                .read_volatile() // SAFETY: The caller must uphold the safety contract.
+        }
+    }
+
+    /// Returns a pointer to the `len` values of type `T` starting from the given index.
+    /// 
+    /// The pointer is guaranteed to be non-null, but it is NOT guaranteed to be aligned.
+    /// 
+    /// Unlike [read_unsized](RawDataStructure::read_unsized) this does not need the `ptr_metadata` feature.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the values do not fit in the data structure,
+    /// or an [`Overflow`](idx::IdxError::Overflow) error if their size does not fit in an [usize].
+    // Not using NonNull is intentional
+    fn read_slice_of<T: Sized>(&self, idx: usize, len: usize) -> Result<*const [T], idx::IdxError> {
+        let size: usize = len.checked_mul(core::mem::size_of::<T>()).ok_or(idx::IdxError::Overflow { idx, size: usize::MAX })?;
+        self.read_validity(idx, size)?;
+        trace_access!(self, "read", idx, size, [T]);
+
+        Ok(
+            core::ptr::slice_from_raw_parts(
+                // SAFETY: The data will always be from within the data structure
+                unsafe { self.read_unchecked::<T>(idx) },
+                len,
+            )
+        )
+    }
+
+    /// Writes the bytes of the given string starting from the given index.
+    /// 
+    /// The length of the string is not written, so it must be kept to read it back with
+    /// [read_str](RawDataStructure::read_str).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the string does not fit in the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    #[inline]
+    unsafe fn write_str(&mut self, idx: usize, value: &str) -> Result<(), idx::IdxError> {
+        self.write_slice_of::<u8>(idx, value.as_bytes())
+    }
+
+    /// Returns the string made of the `len` bytes starting from the given index.
+    /// 
+    /// # ERRORS
+    /// Will return an [`Idx`](idx::Utf8OrIdxError::Idx) error if the bytes do not fit in the data structure,
+    /// or an [`Utf8`](idx::Utf8OrIdxError::Utf8) error if they are not valid UTF-8.
+    fn read_str(&self, idx: usize, len: usize) -> Result<&str, idx::Utf8OrIdxError> {
+        let ptr: *const [u8] = self.read_slice_of::<u8>(idx, len)?;
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::check_read(ptr.cast::<u8>(), len);
+
+        // SAFETY: read_slice_of can never return a null ptr and bytes are always aligned.
+        Ok(core::str::from_utf8(unsafe { &*ptr })?)
+    }
+
+    /// Returns the string made of the `len` bytes starting from the given index,
+    /// without checking that they are valid UTF-8.
+    /// 
+    /// # SAFETY
+    /// - Make sure data isn't read from outside the data structure
+    /// - Make sure the bytes are valid UTF-8
+    unsafe fn read_str_unchecked(&self, idx: usize, len: usize) -> &str {
+        debug_check_bounds!(self, idx, len);
+
+        core::str::from_utf8_unchecked(
+            core::slice::from_raw_parts(
+                self.read_unchecked::<u8>(idx), // SAFETY: The caller must uphold the safety contract.
+                len,
+            )
+        )
+    }
+
+    /// Writes the bytes of the given C string starting from the given index, the terminating NUL included.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the string (with it's NUL) does not fit in the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    #[inline]
+    unsafe fn write_cstr(&mut self, idx: usize, value: &core::ffi::CStr) -> Result<(), idx::IdxError> {
+        self.write_slice_of::<u8>(idx, value.to_bytes_with_nul())
+    }
+
+    /// Returns the C string starting from the given index, which ends at the first NUL byte after it.
+    /// 
+    /// Only the bytes inside of the data structure are searched for the NUL,
+    /// so a fixed size char array that is not terminated is never read past.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is outside of the data structure
+    /// or if there is no NUL byte between the index and the end of the data structure.
+    fn read_cstr(&self, idx: usize) -> Result<&core::ffi::CStr, idx::IdxError> {
+        self.read_validity(idx, 0)?;
+
+        let len: usize = self.size() - idx;
+        // SAFETY: read_slice_of can never return a null ptr and bytes are always aligned.
+        let bytes: &[u8] = unsafe { &*self.read_slice_of::<u8>(idx, len)? };
+
+        // The NUL would have been right after the end of the data structure.
+        let cstr: &core::ffi::CStr = core::ffi::CStr::from_bytes_until_nul(bytes)
+            .map_err(#[inline] |_| idx::IdxError::new(idx, self.size(), len + 1))?;
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::check_read(bytes.as_ptr(), cstr.count_bytes() + 1);
+
+        Ok(cstr)
+    }
+
     /// Returns a pointer to the specified data region with the provided metadata.
     /// 
     /// If you know T is sized use [read](RawDataStructure::read) instead.
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     fn read_unsized<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> Result<*const T, idx::IdxError>
     where T::Metadata: crate::GetSizeOf<T> {
         self.read_validity(idx, meta.size())?;
@@ -393,6 +1056,44 @@ pub unsafe trait RawDataStructure {
         )
     }
 
+    /// Returns a pointer to the value written at the given index with
+    /// [write_unsized_with_meta](RawDataStructure::write_unsized_with_meta), using the metadata stored before it.
+    /// 
+    /// # SAFETY
+    /// - Make sure a `T` was written at the index with [write_unsized_with_meta](RawDataStructure::write_unsized_with_meta),
+    ///   so the metadata stored there is valid
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_self_described<T: ?Sized + core::ptr::Pointee>(&self, idx: usize) -> Result<*const T, idx::IdxError>
+    where T::Metadata: crate::GetSizeOf<T> {
+        let size: usize = core::mem::size_of::<T::Metadata>();
+        self.read_validity(idx, size)?;
+
+        let meta: T::Metadata = self.read_unchecked::<T::Metadata>(idx).read_unaligned();
+        self.read_unsized::<T>(idx + size, meta)
+    }
+
+    /// Returns a refrence to the trait object written at the given index with
+    /// [write_dyn](RawDataStructure::write_dyn), using the vtable stored before it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit in the data structure,
+    /// or a [`Misaligned`](idx::IdxError::Misaligned) error if it is not aligned for the type behind the trait object.
+    /// 
+    /// # SAFETY
+    /// - Make sure a `T` was written at the index with [write_dyn](RawDataStructure::write_dyn),
+    ///   so the vtable stored there is valid
+    /// - Make sure the data is valid
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_dyn<T: ?Sized + core::ptr::Pointee<Metadata = core::ptr::DynMetadata<T>>>(&self, idx: usize) -> Result<&T, idx::IdxError> {
+        let size: usize = core::mem::size_of::<core::ptr::DynMetadata<T>>();
+        self.read_validity(idx, size)?;
+
+        let meta: core::ptr::DynMetadata<T> = self.read_unchecked::<core::ptr::DynMetadata<T>>(idx).read_unaligned();
+        self.align_validity(idx + size, meta.align_of())?;
+
+        self.read_unsized_ref::<T>(idx + size, meta)
+    }
+
     /// Returns a pointer to the specified data region with the provided metadata.
     /// 
     /// If you know T is sized use [read_red](RawDataStructure::read_ref) instead.
@@ -401,7 +1102,6 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is aligned
     /// - Make sure the data is valid
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     unsafe fn read_unsized_ref<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> Result<&T, idx::IdxError>
     where T::Metadata: crate::GetSizeOf<T> {
         self.read_unsized::<T>(idx, meta).map(
@@ -412,6 +1112,71 @@ pub unsafe trait RawDataStructure {
         )
     }
 
+    /// Returns a pointer to the `len` long value of type `T` starting from the given index.
+    /// 
+    /// This is the stable version of `read_unsized` (from the `ptr_metadata` feature), for the [`SliceLike`] types ([\[T\]](prim@slice) and [str]).
+    /// 
+    /// If you know T is sized use [read](RawDataStructure::read) instead.
+    fn read_slice_like<T: ?Sized + crate::SliceLike>(&self, idx: usize, len: usize) -> Result<*const T, idx::IdxError>
+    where usize: crate::GetSizeOf<T> {
+        self.read_validity(idx, crate::GetSizeOf::<T>::size(&len))?;
+
+        Ok(
+            // SAFETY: The data will always be from within the data structure
+            T::from_raw_parts(unsafe { self.read_unchecked::<u8>(idx) }, len)
+        )
+    }
+
+    /// Returns a refrence to the `len` long value of type `T` starting from the given index.
+    /// 
+    /// This is the stable version of `read_unsized_ref` (from the `ptr_metadata` feature), for the [`SliceLike`] types ([\[T\]](prim@slice) and [str]).
+    /// 
+    /// # SAFETY
+    /// - Make sure the data is aligned
+    /// - Make sure the data is valid
+    unsafe fn read_slice_like_ref<T: ?Sized + crate::SliceLike>(&self, idx: usize, len: usize) -> Result<&T, idx::IdxError>
+    where usize: crate::GetSizeOf<T> {
+        self.read_slice_like::<T>(idx, len).map(
+            #[inline] |ptr| unsafe {
+                ptr.as_ref() // SAFETY: The caller msut uphold the safety contract.
+                   .unwrap_unchecked() // SAFETY: read can never return a null ptr.
+            }
+        )
+    }
+
+    /// Returns a mutable pointer to the `len` long value of type `T` starting from the given index.
+    /// 
+    /// This is the stable version of `read_unsized_mut` (from the `ptr_metadata` feature), for the [`SliceLike`] types ([\[T\]](prim@slice) and [str]).
+    /// 
+    /// If you know T is sized use [read_mut](RawDataStructure::read_mut) instead.
+    fn read_slice_like_mut<T: ?Sized + crate::SliceLike>(&mut self, idx: usize, len: usize) -> Result<*mut T, idx::IdxError>
+    where usize: crate::GetSizeOf<T> {
+        self.full_validity(idx, crate::GetSizeOf::<T>::size(&len))?;
+
+        Ok(
+            // SAFETY: The data will always be from within the data structure
+            T::from_raw_parts_mut(unsafe { self.read_mut_unchecked::<u8>(idx) }, len)
+        )
+    }
+
+    /// Returns a mutable refrence to the `len` long value of type `T` starting from the given index.
+    /// 
+    /// This is the stable version of `read_unsized_ref_mut` (from the `ptr_metadata` feature), for the [`SliceLike`] types ([\[T\]](prim@slice) and [str]).
+    /// 
+    /// # SAFETY
+    /// - Make sure the data is aligned
+    /// - Make sure the data is valid
+    /// - Make sure there is only one refrence to the targeted value
+    unsafe fn read_slice_like_ref_mut<T: ?Sized + crate::SliceLike>(&mut self, idx: usize, len: usize) -> Result<&mut T, idx::IdxError>
+    where usize: crate::GetSizeOf<T> {
+        self.read_slice_like_mut::<T>(idx, len).map(
+            |ptr| unsafe {
+                ptr.as_mut() // SAFETY: The caller must uphold this safety contract
+                   .unwrap_unchecked() // SAFETY: the ptr can not be null
+            }
+        )
+    }
+
     /// Returns a pointer to the specified data region with the provided metadata.
     /// 
     /// If you know T is sized use [read_unchecked](RawDataStructure::read_unchecked) instead.
@@ -419,7 +1184,6 @@ pub unsafe trait RawDataStructure {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T;
 
     /// Returns a pointer to the specified data region with the provided metadata.
@@ -429,7 +1193,6 @@ pub unsafe trait RawDataStructure {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     unsafe fn read_unsized_ref_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> &T
     where T::Metadata: crate::GetSizeOf<T> {
         self.read_unsized_unchecked::<T>(idx, meta)
@@ -441,10 +1204,9 @@ pub unsafe trait RawDataStructure {
     /// 
     /// If you know T is sized use [read_mut](RawDataStructure::read_mut) instead.
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     fn read_unsized_mut<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> Result<*mut T, idx::IdxError>
     where T::Metadata: crate::GetSizeOf<T> {
-        self.read_validity(idx, meta.size())?;
+        self.full_validity(idx, meta.size())?;
         
         Ok(
             // SAFETY: The data will always be from within the data structure
@@ -460,7 +1222,6 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is aligned
     /// - Make sure the data is valid
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     unsafe fn read_unsized_ref_mut<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> Result<&mut T, idx::IdxError>
     where T::Metadata: crate::GetSizeOf<T> {
         self.read_unsized_mut::<T>(idx, meta).map(
@@ -478,7 +1239,6 @@ pub unsafe trait RawDataStructure {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T;
 
     /// Returns a pointer to the specified data region with the provided metadata.
@@ -490,7 +1250,6 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the data is aligned
     /// - Make sure the data is valid
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     unsafe fn read_unsized_ref_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> &mut T {
         self.read_unsized_mut_unchecked::<T>(idx, meta) // SAFETY: Up to the caller to uphold this safety contract
             .as_mut() // SAFETY: Up to the caller to uphold this safety contract
@@ -505,8 +1264,9 @@ pub unsafe trait RawDataStructure {
     /// - Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
     /// - Make sure the data gotten from inside is a valid T
-    unsafe fn take<T: Sized>(&self, idx: usize) -> Result<T, idx::IdxError> {
-        self.write_validity(idx, core::mem::size_of::<T>())?;
+    unsafe fn take<T: Sized>(&self, idx: usize) -> Result<T, idx::TypedIdxError> {
+        self.write_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Take))?;
+        trace_access!(self, "take", idx, core::mem::size_of::<T>(), T);
         Ok(self.take_unchecked(idx))
     }
 
@@ -527,7 +1287,7 @@ pub unsafe trait RawDataStructure {
     /// 
     /// # Safety
     /// - Make sure the data gotten from inside is a valid T
-    unsafe fn take_zeroed<T: Sized>(&mut self, idx: usize) -> Result<T, idx::IdxError> {
+    unsafe fn take_zeroed<T: Sized>(&mut self, idx: usize) -> Result<T, idx::TypedIdxError> {
         let take: T = self.take(idx)?;
         // SAFETY: If this would have been an invalid operation, self.take() would ahve returned an error.
         self.write_zeroes_unchecked(idx, core::mem::size_of::<T>());
@@ -547,17 +1307,59 @@ pub unsafe trait RawDataStructure {
         take
     }
 
+    /// Reads a copy of the value at the given index without requiring the data to be aligned,
+    /// so packed structs (like the ones of wire formats) can be read from any buffer.
+    /// 
+    /// This uses [`ptr::read_unaligned`](core::ptr::read_unaligned), so the alignment of `T` does not matter.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit in the data structure.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data gotten from inside is a valid T
+    unsafe fn read_unaligned_value<T: Copy>(&self, idx: usize) -> Result<T, idx::TypedIdxError> {
+        self.read_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Read))?;
+        trace_access!(self, "read", idx, core::mem::size_of::<T>(), T);
+
+        let ptr: *const T = self.read_unchecked::<T>(idx);
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::check_read(ptr.cast::<u8>(), core::mem::size_of::<T>());
+
+        Ok(ptr.read_unaligned())
+    }
+
+    /// Writes the given value at the given index without requiring the data to be aligned,
+    /// so packed structs (like the ones of wire formats) can be written to any buffer.
+    /// 
+    /// This uses [`ptr::write_unaligned`](core::ptr::write_unaligned), so the alignment of `T` does not matter.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit in the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    unsafe fn write_unaligned_value<T: Copy>(&mut self, idx: usize, value: T) -> Result<(), idx::TypedIdxError> {
+        self.write_validity(idx, core::mem::size_of::<T>()).map_err(#[inline] |err| err.typed::<T>(idx::Access::Write))?;
+        trace_access!(self, "write", idx, core::mem::size_of::<T>(), T);
+
+        self.read_mut_unchecked::<T>(idx).write_unaligned(value);
+        Ok(())
+    }
+
     /// Takes the value from the specified region and writes a new value in it's palce.
     /// 
     /// # Safety
     /// - Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
     /// - Make sure the data gotten from inside is a valid T
-    unsafe fn replace<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) -> Result<T, (core::mem::ManuallyDrop<T>, idx::IdxError)> {
+    unsafe fn replace<T: Sized>(&mut self, idx: usize, value: core::mem::ManuallyDrop<T>) -> Result<T, (core::mem::ManuallyDrop<T>, idx::TypedIdxError)> {
         if let Err(err) = self.full_validity(idx, core::mem::size_of::<T>()) {
-            return Err((value, err));
+            return Err((value, err.typed::<T>(idx::Access::Replace)));
         }
 
+        trace_access!(self, "replace", idx, core::mem::size_of::<T>(), T);
         Ok(self.replace_unchecked(idx, value))
     }
 
@@ -574,6 +1376,73 @@ pub unsafe trait RawDataStructure {
         take
     }
 
+    /// Takes the value from the specified region and writes a new value in it's palce.
+    /// 
+    /// The old bytes are copied into `out` and the new value is written
+    /// in their place, the size of the region beeing the size of the given value.
+    /// 
+    /// If you want to replace a sized value it
+    /// is recomended to use [replace](RawDataStructure::replace) instead.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [`ManuallyDrop`](core::mem::ManuallyDrop))
+    /// - Make sure `out` is valid for writes of as many bytes as the given value has.
+    unsafe fn replace_unsized<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) -> Result<(), idx::IdxError> {
+        self.full_validity(
+            idx,
+            core::mem::size_of_val::<core::mem::ManuallyDrop<T>>(
+                value.as_ref().expect("A null pointer was given to replace_unsized.")
+            )
+        )?;
+
+        trace_access!(self, "replace", idx, core::mem::size_of_val(&*value), T);
+        self.replace_unsized_unchecked(idx, value, out);
+
+        Ok(())
+    }
+
+    /// Takes the value from the specified region and writes a new value in it's palce.
+    /// 
+    /// The old bytes are copied into `out` and the new value is written
+    /// in their place, the size of the region beeing the size of the given value.
+    /// 
+    /// By default this copies the old bytes starting from [read_unchecked](RawDataStructure::read_unchecked)
+    /// and then calls [write_unsized_unchecked](RawDataStructure::write_unsized_unchecked),
+    /// so data structures that do not store their data contiguously should change this function's implementation.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [`ManuallyDrop`](core::mem::ManuallyDrop))
+    /// - Make sure `out` is valid for writes of as many bytes as the given value has.
+    /// - Make sure data isn't taken from outside the data structure.
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        let type_size: usize = core::mem::size_of_val::<core::mem::ManuallyDrop<T>>(
+            value.as_ref().expect("A null pointer was given to replace_unsized_unchecked.")
+        );
+        debug_check_bounds!(self, idx, type_size);
+
+        core::ptr::copy_nonoverlapping(
+            // A zero sized read, so it is also valid for zero sized values at the end of the data structure.
+            self.read_unchecked::<()>(idx).cast::<u8>(), // SAFETY: The caller must uphold the safety contract.
+            out.cast::<u8>(),
+            type_size,
+        );
+        self.write_unsized_unchecked(idx, value);
+    }
+
     /// Clones the entire chunk of data.
     /// 
     /// # ERRORS
@@ -607,6 +1476,95 @@ pub unsafe trait RawDataStructure {
     /// - Make sure the sizes of the two data structures match.
     unsafe fn clone_from_unchecked(&mut self, data: &Self);
 
+    /// Copies the entire chunk of data from any other data structure.
+    /// 
+    /// Unlike [clone_from](RawDataStructure::clone_from) the given data structure
+    /// does not need to be of the same type, only to use the same [DataByte](RawDataStructure::DataByte).
+    /// 
+    /// # ERRORS
+    /// If the sizes of the two data structures do not match, then an error is returned,
+    /// where the first usize is the size of `self` and the second is the size of the given data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    unsafe fn copy_from<D: RawDataStructure<DataByte = Self::DataByte> + ?Sized>(&mut self, data: &D) -> Result<(), (usize, usize)> {
+        if self.size() != data.size() {
+            return Err((self.size(), data.size()));
+        }
+
+        self.copy_range_from_unchecked(0, data, 0, self.size());
+
+        Ok(())
+    }
+
+    /// Copies `size` bytes starting from `data_idx` in the given data structure
+    /// into this data structure starting from `idx`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if eather the read from the given data structure
+    /// or the write into this one gets out of the memory region of the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    unsafe fn copy_range_from<D: RawDataStructure<DataByte = Self::DataByte> + ?Sized>(&mut self, idx: usize, data: &D, data_idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        data.read_validity(data_idx, size)?;
+        self.write_validity(idx, size)?;
+
+        self.copy_range_from_unchecked(idx, data, data_idx, size);
+
+        Ok(())
+    }
+
+    /// Copies `size` bytes starting from `data_idx` in the given data structure
+    /// into this data structure starting from `idx`.
+    /// 
+    /// By default this copies the data byte by byte using [get_at_idx](RawDataStructure::get_at_idx)
+    /// and [set_at_idx](RawDataStructure::set_at_idx).
+    /// 
+    /// # SAFETY
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure no data is read from outside the given data structure.
+    /// - Make sure no data is written to a region outside of this data structure.
+    unsafe fn copy_range_from_unchecked<D: RawDataStructure<DataByte = Self::DataByte> + ?Sized>(&mut self, idx: usize, data: &D, data_idx: usize, size: usize) {
+        debug_check_bounds!(self, idx, size);
+        debug_check_bounds!(data, data_idx, size);
+
+        for at in 0..size {
+            self.set_at_idx(idx + at, data.get_at_idx(data_idx + at));
+        }
+    }
+
+    /// Copies all the given slices one after another into this data structure starting from `idx`,
+    /// like `io::Write::write_vectored`, and gives back the amount of bytes written.
+    /// 
+    /// The whole region is validated once, instead of once for every slice.
+    /// 
+    /// # ERRORS
+    /// Will return an error (writing nothing) if the slices together get out of the data structure.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    unsafe fn write_vectored(&mut self, idx: usize, bufs: &[&slice::DataSlice]) -> Result<usize, idx::IdxError> {
+        let size: usize = bufs.iter().try_fold(0usize, #[inline] |size, buf| size.checked_add(buf.size()))
+            .ok_or(idx::IdxError::Overflow { idx, size: usize::MAX })?;
+
+        self.write_validity(idx, size)?;
+        trace_access!(self, "write", idx, size, [u8]);
+
+        let ptr: *mut u8 = self.read_mut_unchecked::<()>(idx).cast::<u8>();
+        let mut done: usize = 0;
+        for buf in bufs {
+            core::ptr::copy_nonoverlapping(buf.as_ptr(), ptr.add(done), buf.size());
+            done += buf.size();
+        }
+
+        Ok(size)
+    }
+
     /// The smallest chunk of data that is used by this particular data structure.
     type DataByte;
 
@@ -637,56 +1595,81 @@ pub unsafe trait RawDataStructure {
 pub trait DataStructureSlice: RawDataStructure {
     /// Gets a subslice of the whole data structure.
     /// 
-    /// Giving in a full range will always give a slice to the entire data slice
+    /// Giving in a full range will always give a slice to the entire data slice,
+    /// even if it is empty, [None] is only given if the range gets out of bounds.
     fn get(&self, idx: impl idx::Idx) -> Option<&slice::DataSlice> {
-        if self.size() == 0 { return None }
-        
-        use core::ops::Bound::*;
-
-        match idx.start() {
-            Unbounded => (),
-            Included(idx) => if idx >= self.size() { return None },
-            Excluded(idx) => if idx.saturating_add(1) >= self.size() { return None },
-        };
-
-        match idx.end() {
-            Unbounded => (),
-            Included(idx) => if idx >= self.size() { return None },
-            Excluded(idx) => if idx > self.size() { return None },
-        };
+        let range: core::ops::Range<usize> = idx.resolve(self.size()).ok()?;
 
         unsafe {
-            self.get_unchecked(idx)
+            self.get_unchecked(range)
                 .as_ref()
         }
     }
 
     /// Gets a mutable subslice of the whole data structure.
     /// 
-    /// Giving in a full range will always give a slice to the entire data slice
+    /// Giving in a full range will always give a slice to the entire data slice,
+    /// even if it is empty, [None] is only given if the range gets out of bounds.
     fn get_mut(&mut self, idx: impl idx::Idx) -> Option<&mut slice::DataSlice> {
-        if self.size() == 0 { return None }
-        
-        use core::ops::Bound::*;
-
-        match idx.start() {
-            Unbounded => (),
-            Included(idx) => if idx >= self.size() { return None },
-            Excluded(idx) => if idx.saturating_add(1) >= self.size() { return None },
-        };
-
-        match idx.end() {
-            Unbounded => (),
-            Included(idx) => if idx >= self.size() { return None },
-            Excluded(idx) => if idx > self.size() { return None },
-        };
+        let range: core::ops::Range<usize> = idx.resolve(self.size()).ok()?;
 
         unsafe {
-            self.get_mut_unchecked(idx)
+            self.get_mut_unchecked(range)
                 .as_mut()
         }
     }
 
+    /// Gets many subslices of the whole data structure at once, all the ranges
+    /// being checked before any of them is gotten.
+    /// 
+    /// The ranges can overlap, since all the subslices are shared.
+    /// 
+    /// Will return [None] if any of the ranges gets out of bounds.
+    fn get_disjoint<const N: usize>(&self, ranges: [impl idx::Idx; N]) -> Option<[&slice::DataSlice; N]> {
+        let mut resolved: [core::ops::Range<usize>; N] = core::array::from_fn(#[inline] |_| 0..0);
+
+        for (range, idx) in resolved.iter_mut().zip(ranges.iter()) {
+            *range = idx.resolve(self.size()).ok()?;
+        }
+
+        Some(resolved.map(
+            // SAFETY: All the ranges were checked.
+            #[inline] |range| unsafe { &*self.get_unchecked(range) }
+        ))
+    }
+
+    /// Gets many mutable subslices of the whole data structure at once, all the ranges
+    /// being checked before any of them is gotten.
+    /// 
+    /// Empty ranges never overlap with any other range.
+    /// 
+    /// Will return [None] if any of the ranges gets out of bounds or if any two of them overlap.
+    fn get_disjoint_mut<const N: usize>(&mut self, ranges: [impl idx::Idx; N]) -> Option<[&mut slice::DataSlice; N]> {
+        let mut resolved: [core::ops::Range<usize>; N] = core::array::from_fn(#[inline] |_| 0..0);
+
+        for (range, idx) in resolved.iter_mut().zip(ranges.iter()) {
+            *range = idx.resolve(self.size()).ok()?;
+        }
+
+        for (at, range) in resolved.iter().enumerate() {
+            if !range.is_empty() && resolved[..at].iter().any(#[inline] |other| !other.is_empty() && range.start < other.end && other.start < range.end) {
+                return None
+            }
+        }
+
+        // One pointer for all of the subslices, so getting one does not invalidate the others.
+        let data: *mut u8 = self.as_data_slice_mut().as_mut_ptr();
+
+        Some(resolved.map(
+            // SAFETY: All the ranges were checked and none of them overlap.
+            #[inline] |range| unsafe {
+                &mut *slice::DataSlice::from_slice_ptr_mut(
+                    core::ptr::slice_from_raw_parts_mut(data.add(range.start), range.len())
+                )
+            }
+        ))
+    }
+
     /// Gets a subslice of the whole data structure without checking bounds.
     /// 
     /// Giving in a full range will always give a slice to the entire data slice
@@ -705,6 +1688,14 @@ pub trait DataStructureSlice: RawDataStructure {
     /// reserved memory for the data structure.
     unsafe fn get_mut_unchecked(&mut self, idx: impl idx::Idx) -> *mut slice::DataSlice;
 
+    /// Gets a view of the `count` regions of `size` bytes that start
+    /// at `start` and are `step` bytes apart, as described by the [`Strided`](idx::Strided) index.
+    /// 
+    /// Will return [None] if any of the regions gets out of bounds.
+    fn get_strided(&self, strided: idx::Strided, size: usize) -> Option<strided::StridedView<'_>> {
+        strided::StridedView::new(self.as_data_slice(), strided, size).ok()
+    }
+
     /// Gets a [`DataSlice`] reprezenting the entire data structure
     fn as_data_slice(&self) -> &slice::DataSlice {
         unsafe {
@@ -0,0 +1,367 @@
+
+/*!
+This module provides the [`DataMap`] data structure and all it's associated
+functions, methods and items.
+
+A [`DataMap`] is a hash map of byte keys to byte values kept entirely inside of a [`DataSlice`],
+with a fixed amount of slots (open addressing with linear probing) and no allocation,
+so it can be written to a flash page as it is and [opened](DataMap::open) again later,
+like a small dictionary of settings.
+
+The data starts with a header (the amount of slots, the end of the used bytes and the amount of entries),
+followed by the slots (the offset and the hash of an entry each) and the entries (the lengths of the key and the value,
+followed by their bytes) one after another. Every number is a little endian [u32] and the hashes are FNV-1a,
+so the layout does not depend on the target.
+
+Replacing a value with one of the same length writes it in place, any other insertion adds a new entry, and the bytes of
+replaced or removed entries are only reused after a [`compact`](DataMap::compact).
+ */
+
+use crate::slice::DataSlice;
+use core::convert::TryFrom;
+use core::ops::DerefMut;
+
+const HEADER: usize = 12;
+const SLOT: usize = 8;
+const ENTRY: usize = 8;
+
+/// The offset of a slot that never held an entry.
+const EMPTY: u32 = 0;
+/// The offset of a slot whose entry was removed.
+const REMOVED: u32 = u32::MAX;
+
+/// The error given when an entry does not fit in a [`DataMap`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MapFullError {
+    /// All the slots hold entries.
+    Slots,
+    /// The entry does not fit in the bytes that are not used yet.
+    Bytes {
+        /// The amount of bytes the entry needs (with it's lengths).
+        needed: usize,
+        /// The amount of bytes that were not used yet.
+        remaining: usize,
+    },
+}
+
+impl core::error::Error for MapFullError {}
+impl core::fmt::Display for MapFullError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MapFullError::Slots => f.write_str("All the slots of the map hold entries."),
+            MapFullError::Bytes { needed, remaining } => write!(
+                f,
+                "Can not add an entry of `{needed}` bytes to a map with only `{remaining}` bytes left.",
+            ),
+        }
+    }
+}
+
+/// Get's the FNV-1a hash of the key.
+#[inline]
+fn hash(key: &[u8]) -> u32 {
+    key.iter().fold(0x811C_9DC5, #[inline] |hash: u32, byte| (hash ^ *byte as u32).wrapping_mul(0x0100_0193))
+}
+
+/// A hash map of byte keys and values inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct DataMap<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    slots: usize,
+}
+
+impl<D: DerefMut<Target = DataSlice>> DataMap<D> {
+    #[inline]
+    fn read_u32(&self, at: usize) -> u32 {
+        let mut word: [u8; 4] = [0; 4];
+        word.copy_from_slice(&self.data.inner[at..at + 4]);
+        u32::from_le_bytes(word)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, at: usize, value: u32) {
+        self.data.inner[at..at + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// Constructs a new empty [`DataMap`] with the given amount of slots in the given data,
+    /// overwriting it's header and slots.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if there are no slots, if the header and the slots do not fit in it
+    /// or if it is bigger then [u32::MAX] bytes.
+    pub fn new(data: D, slots: usize) -> Result<DataMap<D>, D> {
+        match slots.checked_mul(SLOT).and_then(#[inline] |size| size.checked_add(HEADER)) {
+            Some(table) if slots != 0 && table <= data.size() && u32::try_from(data.size()).is_ok() => {},
+            _ => return Err(data),
+        }
+
+        let mut map: DataMap<D> = DataMap { data, slots };
+        map.write_u32(0, slots as u32);
+        map.clear();
+
+        Ok(map)
+    }
+
+    /// Opens a map that was constructed with [`new`](DataMap::new) in the given data before, keeping it's entries.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if it's header does not describe a map that fits in it.
+    pub fn open(data: D) -> Result<DataMap<D>, D> {
+        if data.size() < HEADER || u32::try_from(data.size()).is_err() {
+            return Err(data)
+        }
+
+        let mut map: DataMap<D> = DataMap { data, slots: 0 };
+        let slots: usize = map.read_u32(0) as usize;
+        let end: usize = map.read_u32(4) as usize;
+        let len: usize = map.read_u32(8) as usize;
+
+        match slots.checked_mul(SLOT).map(#[inline] |size| size + HEADER) {
+            Some(table) if slots != 0 && table <= end && end <= map.data.size() && len <= slots => {
+                map.slots = slots;
+                Ok(map)
+            },
+            _ => Err(map.data),
+        }
+    }
+
+    /// Get's the amount of slots, which is the most entries the map can hold.
+    #[inline]
+    pub fn slots(&self) -> usize {
+        self.slots
+    }
+
+    /// Get's the amount of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.read_u32(8) as usize
+    }
+
+    /// Checks weather the map has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn end(&self) -> usize {
+        self.read_u32(4) as usize
+    }
+
+    /// Get's the amount of bytes that are not used by entries yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.size() - self.end()
+    }
+
+    #[inline]
+    fn slot(&self, slot: usize) -> (u32, u32) {
+        (self.read_u32(HEADER + slot * SLOT), self.read_u32(HEADER + slot * SLOT + 4))
+    }
+
+    #[inline]
+    fn set_slot(&mut self, slot: usize, offset: u32, hash: u32) {
+        self.write_u32(HEADER + slot * SLOT, offset);
+        self.write_u32(HEADER + slot * SLOT + 4, hash);
+    }
+
+    /// Get's the ranges of the key and the value of the entry at the given offset,
+    /// or [None] if they do not fit in the used bytes.
+    fn entry(&self, offset: u32) -> Option<(core::ops::Range<usize>, core::ops::Range<usize>)> {
+        let offset: usize = offset as usize;
+
+        if offset.checked_add(ENTRY)? > self.end() {
+            return None
+        }
+
+        let key: usize = offset + ENTRY;
+        let value: usize = key.checked_add(self.read_u32(offset) as usize)?;
+        let end: usize = value.checked_add(self.read_u32(offset + 4) as usize).filter(#[inline] |end| *end <= self.end())?;
+
+        Some((key..value, value..end))
+    }
+
+    /// Get's the slot of the entry with the given key, or the slot it would be inserted in
+    /// (the first removed or empty one on it's way), and weather the key was found.
+    fn find(&self, key: &[u8]) -> (Option<usize>, bool) {
+        let hash: u32 = hash(key);
+        let mut free: Option<usize> = None;
+
+        for step in 0..self.slots {
+            let slot: usize = (hash as usize % self.slots + step) % self.slots;
+
+            match self.slot(slot) {
+                (EMPTY, _) => return (free.or(Some(slot)), false),
+                (REMOVED, _) => free = free.or(Some(slot)),
+                (offset, stored) if stored == hash && self.entry(offset).is_some_and(#[inline] |(range, _)| self.data.inner[range] == *key) => {
+                    return (Some(slot), true)
+                },
+                _ => {},
+            }
+        }
+
+        (free, false)
+    }
+
+    /// Get's the value of the given key, or [None] if the map has no such key.
+    pub fn get(&self, key: &[u8]) -> Option<&DataSlice> {
+        match self.find(key) {
+            (Some(slot), true) => {
+                let (_, value) = self.entry(self.slot(slot).0)?;
+                Some(DataSlice::from_slice(&self.data.inner[value]))
+            },
+            _ => None,
+        }
+    }
+
+    /// Get's the value of the given key mutably, or [None] if the map has no such key.
+    pub fn get_mut(&mut self, key: &[u8]) -> Option<&mut DataSlice> {
+        match self.find(key) {
+            (Some(slot), true) => {
+                let (_, value) = self.entry(self.slot(slot).0)?;
+                Some(DataSlice::from_slice_mut(&mut self.data.inner[value]))
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks weather the map has the given key.
+    #[inline]
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.find(key).1
+    }
+
+    /// Inserts the value for the given key, and gives back weather the key was already in the map
+    /// (in which case the value is replaced).
+    /// 
+    /// # ERRORS
+    /// Will return an error (changing nothing) if all the slots hold entries of other keys,
+    /// or if the entry does not fit in the remaining bytes.
+    pub fn insert(&mut self, key: &[u8], value: &[u8]) -> Result<bool, MapFullError> {
+        let (slot, found): (Option<usize>, bool) = self.find(key);
+        let slot: usize = slot.ok_or(MapFullError::Slots)?;
+
+        if found {
+            if let Some((_, old)) = self.entry(self.slot(slot).0).filter(#[inline] |(_, old)| old.len() == value.len()) {
+                self.data.inner[old].copy_from_slice(value);
+                return Ok(true)
+            }
+        }
+
+        let needed: usize = ENTRY.saturating_add(key.len()).saturating_add(value.len());
+        if needed > self.remaining() {
+            return Err(MapFullError::Bytes { needed, remaining: self.remaining() })
+        }
+
+        let offset: usize = self.end();
+        self.write_u32(offset, key.len() as u32);
+        self.write_u32(offset + 4, value.len() as u32);
+        self.data.inner[offset + ENTRY..offset + ENTRY + key.len()].copy_from_slice(key);
+        self.data.inner[offset + ENTRY + key.len()..offset + needed].copy_from_slice(value);
+
+        self.write_u32(4, (offset + needed) as u32);
+        self.set_slot(slot, offset as u32, hash(key));
+        if !found {
+            self.write_u32(8, self.len() as u32 + 1);
+        }
+
+        Ok(found)
+    }
+
+    /// Removes the given key (and it's value) from the map.
+    /// 
+    /// Will return false if the map has no such key.
+    pub fn remove(&mut self, key: &[u8]) -> bool {
+        match self.find(key) {
+            (Some(slot), true) => {
+                self.set_slot(slot, REMOVED, 0);
+                self.write_u32(8, self.len() as u32 - 1);
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Get's an iterator over the keys and the values of the map, in the order of their slots.
+    pub fn iter(&self) -> impl Iterator<Item = (&DataSlice, &DataSlice)> + '_ {
+        (0..self.slots).filter_map(
+            #[inline] move |slot| match self.slot(slot) {
+                (EMPTY, _) | (REMOVED, _) => None,
+                (offset, _) => self.entry(offset).map(
+                    #[inline] |(key, value)| (DataSlice::from_slice(&self.data.inner[key]), DataSlice::from_slice(&self.data.inner[value]))
+                ),
+            }
+        )
+    }
+
+    /// Moves the entries next to each other (in the order they were added), so the bytes of the replaced and removed
+    /// entries can be used again, and rebuilds the slots without the removed ones.
+    pub fn compact(&mut self) {
+        let table: usize = HEADER + self.slots * SLOT;
+        let (mut read, mut write): (usize, usize) = (table, table);
+
+        while let Some((key, value)) = self.entry(read as u32).filter(#[inline] |_| read < self.end()) {
+            let size: usize = value.end - read;
+            let bytes: &[u8] = &self.data.inner[key];
+
+            // An entry is kept only if it's key leads to it.
+            if let (Some(slot), true) = self.find(bytes) {
+                if self.slot(slot).0 as usize == read {
+                    self.data.inner.copy_within(read..read + size, write);
+                    self.set_slot(slot, write as u32, self.slot(slot).1);
+                    write += size;
+                }
+            }
+
+            read += size;
+        }
+
+        // The slots are rebuilt from the entries that were kept, so no removed slots are left.
+        self.data.inner[HEADER..table].fill(0);
+        self.write_u32(4, write as u32);
+        self.write_u32(8, 0);
+
+        let mut at: usize = table;
+        while let Some((key, value)) = self.entry(at as u32).filter(#[inline] |_| at < write) {
+            let hash: u32 = hash(&self.data.inner[key]);
+
+            let mut slot: usize = hash as usize % self.slots;
+            while self.slot(slot).0 != EMPTY {
+                slot = (slot + 1) % self.slots;
+            }
+
+            self.set_slot(slot, at as u32, hash);
+            self.write_u32(8, self.len() as u32 + 1);
+            at = value.end;
+        }
+    }
+
+    /// Removes all the entries.
+    pub fn clear(&mut self) {
+        let table: usize = HEADER + self.slots * SLOT;
+
+        self.data.inner[HEADER..table].fill(0);
+        self.write_u32(4, table as u32);
+        self.write_u32(8, 0);
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for DataMap<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataMap")
+            .field("len", &self.len())
+            .field("slots", &self.slots)
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
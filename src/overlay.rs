@@ -0,0 +1,211 @@
+
+/*!
+This module provides the [`OverlayData`] view and all it's associated
+functions, methods and items.
+
+An [`OverlayData`] lays a sparse set of changed ranges over a base [`DataSlice`] it never writes to:
+reads get the changed bytes where there are any and the bytes of the base everywhere else,
+and writes only change the overlay, like a speculative change to a large mapped file
+that may or may not be kept.
+
+The changes can be looked at with [`diff`](OverlayData::diff) and written to a data slice
+(like a writable mapping of the same file) with [`commit`](OverlayData::commit).
+Ranges that touch or overlap are joined into one when they are written, so the overlay never
+holds the same byte twice.
+ */
+
+use crate::{
+    idx,
+    slice::DataSlice,
+};
+use crate::alloc::vec::Vec;
+
+/// A range of bytes that was changed, starting at `start`.
+struct Change {
+    start: usize,
+    bytes: Vec<u8>,
+}
+
+impl Change {
+    #[inline]
+    fn end(&self) -> usize {
+        self.start + self.bytes.len()
+    }
+}
+
+/// A copy on write view over a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct OverlayData<'a> {
+    base: &'a DataSlice,
+    /// The changed ranges, sorted by their start and never touching.
+    changes: Vec<Change>,
+}
+
+impl<'a> OverlayData<'a> {
+    /// Constructs a new [`OverlayData`] with no changes over the given base.
+    #[inline]
+    pub fn new(base: &'a DataSlice) -> OverlayData<'a> {
+        OverlayData { base, changes: Vec::new() }
+    }
+
+    /// Get's the base the overlay is over.
+    #[inline]
+    pub fn base(&self) -> &'a DataSlice {
+        self.base
+    }
+
+    /// Get's the amount of bytes in the view, which is always the size of the base.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.base.size()
+    }
+
+    /// Checks weather any byte of the base was changed.
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// Get's the amount of bytes held by the overlay.
+    #[inline]
+    pub fn modified_bytes(&self) -> usize {
+        self.changes.iter().map(#[inline] |change| change.bytes.len()).sum()
+    }
+
+    #[inline]
+    fn bounds(&self, idx: usize, size: usize) -> Result<core::ops::Range<usize>, idx::IdxError> {
+        match idx.checked_add(size) {
+            Some(end) if end <= self.size() => Ok(idx..end),
+            _ => Err(idx::IdxError::new(idx, self.size(), size)),
+        }
+    }
+
+    /// Copies the bytes starting at `idx` into `out`, from the overlay where they were changed
+    /// and from the base everywhere else.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes get out of the view.
+    pub fn read_bytes(&self, idx: usize, out: &mut [u8]) -> Result<(), idx::IdxError> {
+        let range: core::ops::Range<usize> = self.bounds(idx, out.len())?;
+        out.copy_from_slice(&self.base.inner[range.clone()]);
+
+        let first: usize = self.changes.partition_point(#[inline] |change| change.end() <= range.start);
+        for change in self.changes[first..].iter().take_while(#[inline] |change| change.start < range.end) {
+            let start: usize = change.start.max(range.start);
+            let end: usize = change.end().min(range.end);
+
+            out[start - range.start..end - range.start].copy_from_slice(&change.bytes[start - change.start..end - change.start]);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the bytes to the overlay starting at `idx`, leaving the base as it is.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes get out of the view.
+    pub fn write_bytes(&mut self, idx: usize, bytes: &[u8]) -> Result<(), idx::IdxError> {
+        let range: core::ops::Range<usize> = self.bounds(idx, bytes.len())?;
+
+        if bytes.is_empty() {
+            return Ok(())
+        }
+
+        // All the changes that touch the range are joined with it.
+        let first: usize = self.changes.partition_point(#[inline] |change| change.end() < range.start);
+        let last: usize = first + self.changes[first..].iter().take_while(#[inline] |change| change.start <= range.end).count();
+
+        if first == last {
+            self.changes.insert(first, Change { start: range.start, bytes: bytes.to_vec() });
+            return Ok(())
+        }
+
+        // The first change is extended in place, so writing right after a change
+        // does not copy it again, and only the gaps between the changes are taken from the base.
+        let mut start: usize = self.changes[first].start;
+        let mut joined: Vec<u8> = core::mem::take(&mut self.changes[first].bytes);
+        if range.start < start {
+            joined.splice(0..0, bytes[..start - range.start].iter().copied());
+            start = range.start;
+        }
+
+        for change in self.changes.drain(first + 1..last) {
+            joined.extend_from_slice(&self.base.inner[start + joined.len()..change.start]);
+            joined.extend_from_slice(&change.bytes);
+        }
+
+        if range.end > start + joined.len() {
+            joined.extend_from_slice(&bytes[start + joined.len() - range.start..]);
+        }
+        joined[range.start - start..range.end - start].copy_from_slice(bytes);
+
+        self.changes[first] = Change { start, bytes: joined };
+        Ok(())
+    }
+
+    /// Reads a copy of the value starting at `idx`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the view.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data gotten from inside is a valid T
+    pub unsafe fn read_value<T: Copy>(&self, idx: usize) -> Result<T, idx::IdxError> {
+        let mut value: core::mem::MaybeUninit<T> = core::mem::MaybeUninit::zeroed();
+
+        self.read_bytes(idx, core::slice::from_raw_parts_mut(value.as_mut_ptr().cast::<u8>(), core::mem::size_of::<T>()))?;
+        Ok(value.assume_init())
+    }
+
+    /// Writes the value to the overlay starting at `idx`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the view.
+    /// 
+    /// # SAFETY
+    /// - Make sure a T has no uninitialized bytes (padding), since all of it's bytes are copied
+    pub unsafe fn write_value<T: Copy>(&mut self, idx: usize, value: T) -> Result<(), idx::IdxError> {
+        self.write_bytes(idx, core::slice::from_raw_parts((&value as *const T).cast::<u8>(), core::mem::size_of::<T>()))
+    }
+
+    /// Get's an iterator over the changed ranges, as their start and their bytes, in order.
+    #[inline]
+    pub fn diff(&self) -> impl Iterator<Item = (usize, &DataSlice)> + '_ {
+        self.changes.iter().map(#[inline] |change| (change.start, DataSlice::from_slice(&change.bytes)))
+    }
+
+    /// Writes all the changes to the same offsets of the target, keeping them in the overlay.
+    /// 
+    /// # ERRORS
+    /// Will return an error (writing nothing) if the target is smaller then the last change needs.
+    pub fn commit(&self, target: &mut DataSlice) -> Result<(), idx::IdxError> {
+        if let Some(change) = self.changes.last() {
+            if change.end() > target.size() {
+                return Err(idx::IdxError::new(change.start, target.size(), change.bytes.len()))
+            }
+        }
+
+        for change in self.changes.iter() {
+            target.inner[change.start..change.end()].copy_from_slice(&change.bytes);
+        }
+
+        Ok(())
+    }
+
+    /// Throws all the changes away, so the view reads as the base again.
+    #[inline]
+    pub fn discard(&mut self) {
+        self.changes.clear();
+    }
+}
+
+impl core::fmt::Debug for OverlayData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OverlayData")
+            .field("size", &self.size())
+            .field("changes", &self.changes.len())
+            .field("modified_bytes", &self.modified_bytes())
+            .finish()
+    }
+}
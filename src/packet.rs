@@ -0,0 +1,150 @@
+
+/*!
+This module provides the [`PacketBuilder`] and all it's associated
+functions, methods and items.
+
+A [`PacketBuilder`] writes the fields of a frame one after another in network (big endian)
+byte order, keeping track of how much was written so the frame buffer (usually a
+[`DataArray`](crate::array::DataArray)) does not have to be sized exactly.
+
+Fields that can only be known once the whole frame is written (lengths, checksums)
+can be left empty with [`PacketBuilder::reserve`] and filled in by the callback of
+[`PacketBuilder::finish_with`].
+ */
+
+use crate::{
+    idx,
+    cursor::DataCursor,
+    slice::DataSlice,
+};
+
+/// A builder that writes the fields of a packet into a [`DataSlice`].
+/// 
+/// All the `put_*` methods return the builder back so they can be chained with `?`,
+/// if a field does not fit nothing of it is written and the position is left untouched.
+#[must_use]
+pub struct PacketBuilder<'data> {
+    cursor: DataCursor<'data>,
+}
+
+impl<'data> PacketBuilder<'data> {
+    /// Constructs a new [`PacketBuilder`] that starts writing at the begining of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data mut DataSlice) -> PacketBuilder<'data> {
+        PacketBuilder { cursor: DataCursor::new(data) }
+    }
+
+    /// Get's the amount of bytes written so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Get's the amount of bytes left to be written.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.cursor.remaining()
+    }
+
+    /// Writes a single byte.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the byte does not fit.
+    #[inline]
+    pub const fn put_u8(&mut self, value: u8) -> Result<&mut PacketBuilder<'data>, idx::IdxError> {
+        let at: usize = match self.cursor.claim(1) {
+            Ok(at) => at,
+            Err(err) => return Err(err),
+        };
+
+        self.cursor.get_mut().inner[at] = value;
+        Ok(self)
+    }
+
+    /// Writes a `u16` in big endian byte order.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit.
+    #[inline]
+    pub const fn put_u16_be(&mut self, value: u16) -> Result<&mut PacketBuilder<'data>, idx::IdxError> {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes a `u32` in big endian byte order.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value does not fit.
+    #[inline]
+    pub const fn put_u32_be(&mut self, value: u32) -> Result<&mut PacketBuilder<'data>, idx::IdxError> {
+        self.put_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes the given bytes as they are.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes do not fit.
+    pub const fn put_bytes(&mut self, bytes: &[u8]) -> Result<&mut PacketBuilder<'data>, idx::IdxError> {
+        let at: usize = match self.cursor.claim(bytes.len()) {
+            Ok(at) => at,
+            Err(err) => return Err(err),
+        };
+
+        let mut offset: usize = 0;
+        while offset < bytes.len() {
+            self.cursor.get_mut().inner[at + offset] = bytes[offset];
+            offset += 1;
+        }
+
+        Ok(self)
+    }
+
+    /// Skips over `size` bytes, setting them to `0`, and gives back the position they start at.
+    /// 
+    /// This is meant for fields that get filled in later, like in the callback of [`finish_with`](PacketBuilder::finish_with).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the bytes do not fit.
+    pub const fn reserve(&mut self, size: usize) -> Result<usize, idx::IdxError> {
+        let at: usize = match self.cursor.claim(size) {
+            Ok(at) => at,
+            Err(err) => return Err(err),
+        };
+
+        let mut offset: usize = 0;
+        while offset < size {
+            self.cursor.get_mut().inner[at + offset] = 0;
+            offset += 1;
+        }
+
+        Ok(at)
+    }
+
+    /// Get's the bytes written so far.
+    #[inline]
+    pub fn written(&self) -> &DataSlice {
+        self.cursor.written()
+    }
+
+    /// Finishes the packet giving back the amount of bytes written.
+    #[inline]
+    pub const fn finish(self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Finishes the packet giving back the amount of bytes written,
+    /// but first calls `patch` with the written bytes so fields that depend on the
+    /// whole packet (like a length or a checksum) can be filled in.
+    pub fn finish_with(mut self, patch: impl FnOnce(&mut DataSlice)) -> usize {
+        patch(self.cursor.written_mut());
+        self.cursor.position()
+    }
+}
+
+impl core::fmt::Debug for PacketBuilder<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PacketBuilder")
+            .field("position", &self.cursor.position())
+            .field("size", &self.cursor.get_ref().size())
+            .finish()
+    }
+}
@@ -0,0 +1,129 @@
+
+/*!
+This module provides the [`DataPatch`] delta and all it's associated
+functions, methods and items.
+
+A [`DataPatch`] is the list of the ranges of bytes that changed between two snapshots of the same data,
+made by [`diff`], so only the changed bytes have to be sent (like the state of a device synchronized
+over a slow link) and [applied](DataPatch::apply) to the old snapshot on the other side.
+
+Changed ranges that are less then [`GAP`] bytes apart are joined into one, since every range
+costs it's start and it's length on top of it's bytes. With the `serde` feature a patch
+can be serialized.
+ */
+
+use crate::{
+    idx,
+    slice::DataSlice,
+};
+use crate::alloc::vec::Vec;
+
+/// The amount of unchanged bytes between two changed ranges under which [`diff`] joins them into one.
+pub const GAP: usize = 2 * core::mem::size_of::<usize>();
+
+/// A range of bytes that changed, starting at `start`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Change {
+    start: usize,
+    bytes: Vec<u8>,
+}
+
+/// The changes between two snapshots of the same data.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DataPatch {
+    /// The changed ranges, sorted by their start and never overlapping.
+    changes: Vec<Change>,
+}
+
+/// Get's the changes that turn `old` into `new`.
+/// 
+/// The bytes of `new` past the end of `old` count as changed,
+/// the bytes of `old` past the end of `new` are left as they are.
+pub fn diff(old: &DataSlice, new: &DataSlice) -> DataPatch {
+    let mut changes: Vec<Change> = Vec::new();
+    let mut idx: usize = 0;
+
+    while idx < new.size() {
+        if old.inner.get(idx) == Some(&new.inner[idx]) {
+            idx += 1;
+            continue;
+        }
+
+        // The range ends at the first run of `GAP` unchanged bytes (or the end of `new`).
+        let start: usize = idx;
+        let mut end: usize = idx + 1;
+        idx += 1;
+
+        while idx < new.size() && idx - end < GAP {
+            if old.inner.get(idx) != Some(&new.inner[idx]) {
+                end = idx + 1;
+            }
+            idx += 1;
+        }
+
+        changes.push(Change { start, bytes: new.inner[start..end].to_vec() });
+    }
+
+    DataPatch { changes }
+}
+
+impl DataPatch {
+    /// Constructs a new [`DataPatch`] with no changes.
+    #[inline]
+    pub fn new() -> DataPatch {
+        DataPatch { changes: Vec::new() }
+    }
+
+    /// Get's the amount of changed ranges.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Checks weather the patch has no changes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get's the amount of changed bytes held by the patch.
+    #[inline]
+    pub fn patched_bytes(&self) -> usize {
+        self.changes.iter().map(#[inline] |change| change.bytes.len()).sum()
+    }
+
+    /// Get's the amount of bytes a data slice needs for the patch to be applied to it.
+    #[inline]
+    pub fn required_size(&self) -> usize {
+        self.changes.iter().map(#[inline] |change| change.start.saturating_add(change.bytes.len())).max().unwrap_or(0)
+    }
+
+    /// Get's an iterator over the changed ranges, as their start and their bytes, in order.
+    #[inline]
+    pub fn changes(&self) -> impl Iterator<Item = (usize, &DataSlice)> + '_ {
+        self.changes.iter().map(#[inline] |change| (change.start, DataSlice::from_slice(&change.bytes)))
+    }
+
+    /// Writes all the changes to the same offsets of the target.
+    /// 
+    /// # ERRORS
+    /// Will return an error (writing nothing) if the target is smaller then [`required_size`](DataPatch::required_size).
+    pub fn apply(&self, target: &mut DataSlice) -> Result<(), idx::IdxError> {
+        // A deserialized patch may not be sorted, so every change is checked.
+        for change in self.changes.iter() {
+            if change.start.checked_add(change.bytes.len()).is_none_or(#[inline] |end| end > target.size()) {
+                return Err(idx::IdxError::new(change.start, target.size(), change.bytes.len()))
+            }
+        }
+
+        for change in self.changes.iter() {
+            target.inner[change.start..change.start + change.bytes.len()].copy_from_slice(&change.bytes);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+
+/*!
+This module provides the [`DataPool`] allocator and all it's associated
+functions, methods and items.
+
+A [`DataPool`] splits a [`DataSlice`] into blocks of `BLOCK` bytes that are all the same size
+(like the packet buffers of a network driver), and keeps track of the used ones with a bitmap.
+Acquiring and releasing a block never splits or merges anything, so it can never fragment.
+
+The blocks are at the start of the data and the bitmap (a bit for each block) right after them,
+so a pool can be opened again with [`DataPool::open`].
+ */
+
+use crate::{
+    arena::ArenaStats,
+    slice::DataSlice,
+};
+use core::ops::{
+    Bound,
+    DerefMut,
+};
+
+/// A pool of blocks of `BLOCK` bytes inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DataPool<const BLOCK: usize, D: DerefMut<Target = DataSlice>> {
+    data: D,
+    capacity: usize,
+    used: usize,
+    peak: usize,
+}
+
+/// Get's the amount of blocks of `block` bytes (and their bits) that fit in `size` bytes.
+#[inline]
+const fn capacity(size: usize, block: usize) -> usize {
+    // Every 8 blocks need `8 * block + 1` bytes, and the rest get a byte of the bitmap for themselves.
+    let group: usize = block.saturating_mul(8).saturating_add(1);
+    let mut capacity: usize = size / group * 8;
+    let rest: usize = size % group;
+
+    if rest > block {
+        capacity += (rest - 1) / block;
+    }
+
+    capacity
+}
+
+impl<const BLOCK: usize, D: DerefMut<Target = DataSlice>> DataPool<BLOCK, D> {
+    /// Constructs a new [`DataPool`] with all of it's blocks free in the given data,
+    /// overwriting it's bitmap.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if `BLOCK` is `0` or if not even a single block fits in it.
+    pub fn new(mut data: D) -> Result<DataPool<BLOCK, D>, D> {
+        if BLOCK == 0 || capacity(data.size(), BLOCK) == 0 {
+            return Err(data)
+        }
+
+        let capacity: usize = capacity(data.size(), BLOCK);
+        data.inner[capacity * BLOCK..capacity * BLOCK + capacity.div_ceil(8)].fill(0);
+
+        Ok(DataPool { data, capacity, used: 0, peak: 0 })
+    }
+
+    /// Opens a pool that was constructed with [`new`](DataPool::new) in the given data before,
+    /// keeping the blocks that were used as used.
+    /// 
+    /// # ERRORS
+    /// Will return the data back if `BLOCK` is `0` or if not even a single block fits in it.
+    pub fn open(data: D) -> Result<DataPool<BLOCK, D>, D> {
+        if BLOCK == 0 || capacity(data.size(), BLOCK) == 0 {
+            return Err(data)
+        }
+
+        let mut this: DataPool<BLOCK, D> = DataPool { capacity: capacity(data.size(), BLOCK), data, used: 0, peak: 0 };
+        this.used = (0..this.capacity).filter(#[inline] |idx| this.is_used(*idx)).count();
+        this.peak = this.used;
+
+        Ok(this)
+    }
+
+    /// Get's the amount of blocks in the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get's the amount of used blocks.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Checks weather the block at the given index is used.
+    /// 
+    /// Will return false if there is no such block.
+    #[inline]
+    pub fn is_used(&self, idx: usize) -> bool {
+        idx < self.capacity && self.data.inner[self.capacity * BLOCK + idx / 8] & (1 << (idx % 8)) != 0
+    }
+
+    #[inline]
+    fn set_used(&mut self, idx: usize, used: bool) {
+        let byte: &mut u8 = &mut self.data.inner[self.capacity * BLOCK + idx / 8];
+
+        if used {
+            *byte |= 1 << (idx % 8);
+        } else {
+            *byte &= !(1 << (idx % 8));
+        }
+    }
+
+    #[inline]
+    fn block(&mut self, idx: usize) -> &mut DataSlice {
+        self.data.get_mut_const(Bound::Included(idx * BLOCK), Bound::Excluded((idx + 1) * BLOCK))
+            .expect("Every block is inside of the data.")
+    }
+
+    /// Marks the first free block as used and gives back it's index and the block,
+    /// or [None] if all the blocks are used.
+    /// 
+    /// The block keeps whatever it held before.
+    pub fn acquire(&mut self) -> Option<(usize, &mut DataSlice)> {
+        let bitmap: &[u8] = &self.data.inner[self.capacity * BLOCK..self.capacity * BLOCK + self.capacity.div_ceil(8)];
+        let byte: usize = bitmap.iter().position(#[inline] |byte| *byte != u8::MAX)?;
+        let idx: usize = byte * 8 + bitmap[byte].trailing_ones() as usize;
+
+        if idx >= self.capacity {
+            return None
+        }
+
+        self.set_used(idx, true);
+        self.used += 1;
+        self.peak = self.peak.max(self.used);
+
+        Some((idx, self.block(idx)))
+    }
+
+    /// Marks the block at the given index as free, so it can be acquired again.
+    /// 
+    /// Will return false if there is no such block or if it was already free.
+    pub fn release(&mut self, idx: usize) -> bool {
+        if !self.is_used(idx) {
+            return false
+        }
+
+        self.set_used(idx, false);
+        self.used -= 1;
+        true
+    }
+
+    /// Get's the used block at the given index, or [None] if it is free or there is no such block.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&DataSlice> {
+        if !self.is_used(idx) {
+            return None
+        }
+
+        self.data.get_const(Bound::Included(idx * BLOCK), Bound::Excluded((idx + 1) * BLOCK))
+    }
+
+    /// Get's the used block at the given index mutably, or [None] if it is free or there is no such block.
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut DataSlice> {
+        if !self.is_used(idx) {
+            return None
+        }
+
+        Some(self.block(idx))
+    }
+
+    /// Get's the usage of the pool since it was constructed or opened, counting whole blocks as allocated.
+    #[inline]
+    pub fn report(&self) -> ArenaStats {
+        ArenaStats {
+            allocated: self.used * BLOCK,
+            peak: self.peak * BLOCK,
+            allocations: self.used,
+            largest_free: if self.used < self.capacity { BLOCK } else { 0 },
+        }
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<const BLOCK: usize, D: DerefMut<Target = DataSlice>> core::fmt::Debug for DataPool<BLOCK, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataPool")
+            .field("block", &BLOCK)
+            .field("capacity", &self.capacity)
+            .field("used", &self.used)
+            .finish()
+    }
+}
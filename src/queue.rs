@@ -0,0 +1,298 @@
+
+/*!
+This module provides the [`DataQueue`] data structure and all it's associated
+functions, methods and items.
+
+A [`DataQueue`] is the first in first out counterpart of the [`DataStack`](crate::stack::DataStack):
+it enqueues values of any type at it's back in any [`RawDataStructure`] and dequeues them
+from it's front, like a queue of commands sent from one part of a program to another.
+The values in the same queue do not need to be of the same type.
+
+Every value is stored as a record, a header with it's size, a tag of the choice of the caller
+(eg: to tell which type of command it is) and it's padding, followed by the value aligned
+for it's type (relative to the start of the data structure). Records never straddle the end of
+the data structure, a record that does not fit before it starts back at the start of the data structure.
+
+Since a data structure that holds it's bytes inline (like a [`DataArray`](crate::array::DataArray))
+can move in memory, dequeuing never needs the value to be aligned in memory,
+but getting a refrence to it does.
+
+The values left in the queue when it is dropped or cleared are never dropped.
+ */
+
+use crate::{
+    layout,
+    slice::DataSlice,
+    DataStructureSlice,
+    RawDataStructure,
+};
+use core::mem::ManuallyDrop;
+
+const SIZE: usize = 0;
+const TAG: usize = 4;
+const PAD: usize = 8;
+const HEADER: usize = 12;
+const WRAP: u32 = u32::MAX;
+
+/// The header of a record: it's tag, the offset of it's value, the size of it's value
+/// and the offset of the next record.
+#[derive(Clone, Copy)]
+struct Record {
+    tag: u32,
+    start: usize,
+    size: usize,
+    next: usize,
+}
+
+/// A queue of values of any type inside of a data structure.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct DataQueue<D: RawDataStructure> {
+    data: D,
+    head: usize,
+    tail: usize,
+    len: usize,
+    wrapped: bool,
+}
+
+impl<D: RawDataStructure> DataQueue<D> {
+    /// Constructs a new empty [`DataQueue`] in the given data structure.
+    #[inline]
+    pub fn new(data: D) -> DataQueue<D> {
+        DataQueue { data, head: 0, tail: 0, len: 0, wrapped: false }
+    }
+
+    /// Get's the amount of bytes the queue can use.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Get's the amount of values in the queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the queue has no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get's the offset of the first record at or after the given offset, following a wrap.
+    #[inline]
+    fn resolve(&self, pos: usize) -> usize {
+        if pos + HEADER > self.data.size() {
+            return 0
+        }
+
+        // SAFETY: The header is inside of the data structure.
+        match unsafe { self.data.read_unaligned_value::<u32>(pos + SIZE) } {
+            Ok(WRAP) => 0,
+            _ => pos,
+        }
+    }
+
+    /// Reads the record at the given offset, which must be the offset of a record.
+    fn record(&self, pos: usize) -> Option<Record> {
+        // SAFETY: The header was written when the record was enqueued.
+        unsafe {
+            let size: usize = self.data.read_unaligned_value::<u32>(pos + SIZE).ok()? as usize;
+            let tag: u32 = self.data.read_unaligned_value::<u32>(pos + TAG).ok()?;
+            let pad: usize = self.data.read_unaligned_value::<u32>(pos + PAD).ok()? as usize;
+
+            let start: usize = pos + HEADER + pad;
+            let next: usize = start.checked_add(size).filter(#[inline] |next| *next <= self.data.size())?;
+
+            Some(Record { tag, start, size, next })
+        }
+    }
+
+    #[inline]
+    fn front(&self) -> Option<Record> {
+        if self.len == 0 {
+            return None
+        }
+
+        self.record(self.resolve(self.head))
+    }
+
+    /// Get's the offset a record of the given size and alignment would take at the given offset,
+    /// and the offset of it's value, if it fits before `end`.
+    #[inline]
+    fn fit(pos: usize, size: usize, align: usize, end: usize) -> Option<(usize, usize)> {
+        let start: usize = (pos + HEADER).checked_add(layout::padding_needed(pos + HEADER, align))?;
+
+        match start.checked_add(size) {
+            Some(next) if next <= end => Some((start, next)),
+            _ => None,
+        }
+    }
+
+    /// Enqueues the value at the back of the queue with a tag of `0`.
+    /// 
+    /// # ERRORS
+    /// Will return the value back if it's record does not fit in the free bytes.
+    #[inline]
+    pub fn enqueue_value<T>(&mut self, value: T) -> Result<(), T> {
+        self.enqueue_tagged(0, value)
+    }
+
+    /// Enqueues the value at the back of the queue with the given tag.
+    /// 
+    /// # ERRORS
+    /// Will return the value back if it's record does not fit in the free bytes,
+    /// or if the value is bigger then [u32::MAX] bytes.
+    pub fn enqueue_tagged<T>(&mut self, tag: u32, value: T) -> Result<(), T> {
+        let size: usize = core::mem::size_of::<T>();
+        let align: usize = core::mem::align_of::<T>();
+
+        if size >= WRAP as usize {
+            return Err(value)
+        }
+
+        if self.len == 0 {
+            self.head = 0;
+            self.tail = 0;
+            self.wrapped = false;
+        }
+
+        let (pos, start, next): (usize, usize, usize) = if self.wrapped {
+            match DataQueue::<D>::fit(self.tail, size, align, self.head) {
+                Some((start, next)) => (self.tail, start, next),
+                None => return Err(value),
+            }
+        } else if let Some((start, next)) = DataQueue::<D>::fit(self.tail, size, align, self.data.size()) {
+            (self.tail, start, next)
+        } else if let Some((start, next)) = DataQueue::<D>::fit(0, size, align, self.head).filter(#[inline] |_| self.len != 0) {
+            if self.tail + HEADER <= self.data.size() {
+                // SAFETY: The header is inside of the data structure.
+                unsafe { self.data.write_unaligned_value::<u32>(self.tail + SIZE, WRAP).ok(); }
+            }
+
+            self.wrapped = true;
+            (0, start, next)
+        } else {
+            return Err(value)
+        };
+
+        // SAFETY: The record fits in the free bytes of the data structure.
+        unsafe {
+            self.data.write_unaligned_value::<u32>(pos + SIZE, size as u32).ok();
+            self.data.write_unaligned_value::<u32>(pos + TAG, tag).ok();
+            self.data.write_unaligned_value::<u32>(pos + PAD, (start - pos - HEADER) as u32).ok();
+            self.data.read_mut_unchecked::<ManuallyDrop<T>>(start).write_unaligned(ManuallyDrop::new(value));
+        }
+
+        self.tail = next;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Get's the tag of the value at the front of the queue, or [None] if the queue is empty.
+    #[inline]
+    pub fn peek_tag(&self) -> Option<u32> {
+        self.front().map(#[inline] |record| record.tag)
+    }
+
+    /// Get's the size of the value at the front of the queue, or [None] if the queue is empty.
+    #[inline]
+    pub fn peek_size(&self) -> Option<usize> {
+        self.front().map(#[inline] |record| record.size)
+    }
+
+    /// Get's a refrence to the value at the front of the queue, or [None] if the queue is empty,
+    /// the value is not of the size of `T` or it is not aligned in memory.
+    /// 
+    /// # SAFETY
+    /// - Make sure the value at the front of the queue is a valid T
+    pub unsafe fn peek_ref<T>(&self) -> Option<&T> {
+        match self.front() {
+            Some(record) if record.size == core::mem::size_of::<T>() && self.data.align_validity(record.start, core::mem::align_of::<T>()).is_ok() => {
+                Some(self.data.read_ref_unchecked::<T>(record.start))
+            },
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn pop_front(&mut self, record: Record) {
+        if self.resolve(self.head) == 0 && self.head != 0 {
+            self.wrapped = false;
+        }
+
+        self.head = record.next;
+        self.len -= 1;
+    }
+
+    /// Dequeues the value at the front of the queue and gives it back,
+    /// or [None] if the queue is empty or the value is not of the size of `T` (leaving it in the queue).
+    /// 
+    /// # SAFETY
+    /// - Make sure the value at the front of the queue is a valid T
+    pub unsafe fn dequeue_value<T>(&mut self) -> Option<T> {
+        match self.front() {
+            Some(record) if record.size == core::mem::size_of::<T>() => {
+                let value: T = self.data.read_unchecked::<T>(record.start).read_unaligned();
+
+                self.pop_front(record);
+                Some(value)
+            },
+            _ => None,
+        }
+    }
+
+    /// Dequeues the value at the front of the queue without reading or dropping it.
+    /// 
+    /// Will return false if the queue is empty.
+    pub fn discard(&mut self) -> bool {
+        match self.front() {
+            Some(record) => {
+                self.pop_front(record);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Get's an iterator over the tags and the bytes of the values in the queue, from the front to the back.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &DataSlice)> + '_
+    where D: DataStructureSlice {
+        let mut pos: usize = self.head;
+
+        (0..self.len).map_while(
+            #[inline] move |_| {
+                let record: Record = self.record(self.resolve(pos))?;
+                pos = record.next;
+
+                self.data.get(record.start..record.next).map(#[inline] |bytes| (record.tag, bytes))
+            }
+        )
+    }
+
+    /// Dequeues all the values without dropping them.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.tail = 0;
+        self.len = 0;
+        self.wrapped = false;
+    }
+
+    /// Gives back the underlying data structure, without dropping the values in the queue.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: RawDataStructure> core::fmt::Debug for DataQueue<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataQueue")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
@@ -0,0 +1,225 @@
+
+/*!
+This module provides the [`DataRing`] circular buffer and all it's associated
+functions, methods and items.
+
+A [`DataRing`] is a first in first out buffer of bytes over a [`DataSlice`], like the ones
+that sit between an interrupt handler (or a DMA transfer) and the rest of a program.
+When the bytes reach the end of the data they continue at it's start, which is handled
+by every method, so a push or a pop may straddle the end of the data.
+
+Besides the plain bytes, a ring can hold frames: blocks of bytes prefixed by their length
+(as a little endian [u32]), which always come out whole.
+ */
+
+use crate::slice::DataSlice;
+use core::convert::TryFrom;
+use core::ops::DerefMut;
+
+const PREFIX: usize = core::mem::size_of::<u32>();
+
+/// The error given when bytes do not fit in the free bytes of a [`DataRing`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RingFullError {
+    /// The amount of bytes that were pushed (with the length prefix for frames).
+    pub needed: usize,
+    /// The amount of bytes that were free in the ring.
+    pub remaining: usize,
+}
+
+impl core::error::Error for RingFullError {}
+impl core::fmt::Display for RingFullError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Can not push `{needed}` bytes to a ring with only `{remaining}` bytes free.",
+            needed = self.needed,
+            remaining = self.remaining,
+        )
+    }
+}
+
+/// A circular buffer of bytes inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DataRing<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    head: usize,
+    len: usize,
+}
+
+impl<D: DerefMut<Target = DataSlice>> DataRing<D> {
+    /// Constructs a new empty [`DataRing`] in the given data.
+    #[inline]
+    pub fn new(data: D) -> DataRing<D> {
+        DataRing { data, head: 0, len: 0 }
+    }
+
+    /// Get's the amount of bytes the ring can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Get's the amount of bytes in the ring, length prefixes of frames included.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the ring has no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get's the amount of free bytes.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.len
+    }
+
+    /// Copies the bytes into the ring starting at the given offset from the head, which must fit.
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return
+        }
+
+        let pos: usize = (self.head + offset) % self.capacity();
+        let first: usize = bytes.len().min(self.capacity() - pos);
+
+        self.data.inner[pos..pos + first].copy_from_slice(&bytes[..first]);
+        self.data.inner[..bytes.len() - first].copy_from_slice(&bytes[first..]);
+    }
+
+    /// Copies the bytes of the ring starting at the given offset from the head, which must be in the ring.
+    fn read_at(&self, offset: usize, out: &mut [u8]) {
+        if out.is_empty() {
+            return
+        }
+
+        let pos: usize = (self.head + offset) % self.capacity();
+        let first: usize = out.len().min(self.capacity() - pos);
+        let rest: usize = out.len() - first;
+
+        out[..first].copy_from_slice(&self.data.inner[pos..pos + first]);
+        out[first..].copy_from_slice(&self.data.inner[..rest]);
+    }
+
+    #[inline]
+    fn consume(&mut self, amount: usize) {
+        self.len -= amount;
+        self.head = match self.len {
+            0 => 0,
+            _ => (self.head + amount) % self.capacity(),
+        };
+    }
+
+    /// Pushes all the bytes to the back of the ring.
+    /// 
+    /// # ERRORS
+    /// Will return an error (pushing nothing) if the bytes do not fit in the free bytes.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), RingFullError> {
+        if bytes.len() > self.remaining() {
+            return Err(RingFullError { needed: bytes.len(), remaining: self.remaining() })
+        }
+
+        self.write_at(self.len, bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    /// Pops as many bytes as fit in `out` (or as there are) from the front of the ring,
+    /// and gives back the amount of bytes popped.
+    pub fn pop_bytes(&mut self, out: &mut [u8]) -> usize {
+        let amount: usize = out.len().min(self.len);
+
+        self.read_at(0, &mut out[..amount]);
+        self.consume(amount);
+        amount
+    }
+
+    /// Copies as many bytes as fit in `out` (or as there are) from the front of the ring without popping them,
+    /// and gives back the amount of bytes copied.
+    pub fn peek_bytes(&self, out: &mut [u8]) -> usize {
+        let amount: usize = out.len().min(self.len);
+
+        self.read_at(0, &mut out[..amount]);
+        amount
+    }
+
+    /// Pushes the bytes as a single frame to the back of the ring.
+    /// 
+    /// # ERRORS
+    /// Will return an error (pushing nothing) if the frame with it's length prefix does not fit in the free bytes,
+    /// or if it's length does not fit in a [u32].
+    pub fn push_frame(&mut self, bytes: &[u8]) -> Result<(), RingFullError> {
+        let needed: usize = bytes.len().saturating_add(PREFIX);
+
+        let len: u32 = match u32::try_from(bytes.len()) {
+            Ok(len) if needed <= self.remaining() => len,
+            _ => return Err(RingFullError { needed, remaining: self.remaining() }),
+        };
+
+        self.write_at(self.len, &len.to_le_bytes());
+        self.write_at(self.len + PREFIX, bytes);
+        self.len += needed;
+        Ok(())
+    }
+
+    /// Get's the length of the frame at the front of the ring (without it's prefix),
+    /// or [None] if there are not enough bytes for a whole frame.
+    pub fn peek_frame_len(&self) -> Option<usize> {
+        if self.len < PREFIX {
+            return None
+        }
+
+        let mut prefix: [u8; PREFIX] = [0; PREFIX];
+        self.read_at(0, &mut prefix);
+
+        usize::try_from(u32::from_le_bytes(prefix)).ok()
+            .filter(#[inline] |len| PREFIX + len <= self.len)
+    }
+
+    /// Pops the frame at the front of the ring into `out` and gives back it's length.
+    /// 
+    /// Will return [None] (popping nothing) if there is no whole frame
+    /// or if the frame does not fit in `out`, see [`peek_frame_len`](DataRing::peek_frame_len).
+    pub fn pop_frame(&mut self, out: &mut [u8]) -> Option<usize> {
+        let len: usize = self.peek_frame_len().filter(#[inline] |len| *len <= out.len())?;
+
+        self.read_at(PREFIX, &mut out[..len]);
+        self.consume(PREFIX + len);
+        Some(len)
+    }
+
+    /// Get's the bytes in the ring in order, as the part up to the end of the data and the part that wrapped around.
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        let first: usize = self.len.min(self.capacity() - self.head);
+
+        (&self.data.inner[self.head..self.head + first], &self.data.inner[..self.len - first])
+    }
+
+    /// Pops all the bytes.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for DataRing<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataRing")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
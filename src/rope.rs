@@ -0,0 +1,279 @@
+
+/*!
+This module provides the [`DataRope`] data structure and all it's associated
+functions, methods and items.
+
+A [`DataRope`] holds it's bytes in chunks which are the nodes of a balanced tree
+(a treap ordered by the position of the chunks, with the amount of bytes under every node),
+so inserting or removing bytes anywhere only splits and joins chunks along a path of the tree
+instead of moving all the bytes after them, like the buffer of a text editor.
+Inserting bytes into the middle of a [`DataBoxed`] reallocates and moves all of it every time.
+
+The bytes can be read without copying them with [`slice`](DataRope::slice), which gives a
+[`GatherView`] over the chunks that hold them, or copied into a single [`DataBoxed`] with
+[`flatten`](DataRope::flatten).
+ */
+
+use crate::{
+    boxed::DataBoxed,
+    chain::GatherView,
+    idx::{
+        self,
+        Idx,
+    },
+    slice::DataSlice,
+};
+use crate::alloc::{
+    boxed::Box,
+    collections::TryReserveError,
+    vec::Vec,
+};
+
+type Tree = Option<Box<Node>>;
+
+/// A chunk of the rope, with the chunks before it on it's left and the ones after it on it's right.
+struct Node {
+    chunk: Vec<u8>,
+    priority: u32,
+    /// The amount of bytes of this node and all the nodes under it.
+    size: usize,
+    /// The amount of chunks of this node and all the nodes under it.
+    count: usize,
+    left: Tree,
+    right: Tree,
+}
+
+#[inline]
+fn size(tree: &Tree) -> usize {
+    tree.as_ref().map_or(0, #[inline] |node| node.size)
+}
+
+#[inline]
+fn count(tree: &Tree) -> usize {
+    tree.as_ref().map_or(0, #[inline] |node| node.count)
+}
+
+impl Node {
+    #[inline]
+    fn update(&mut self) {
+        self.size = size(&self.left) + self.chunk.len() + size(&self.right);
+        self.count = count(&self.left) + 1 + count(&self.right);
+    }
+}
+
+/// Get's the priority of a new node (a xorshift of the last one).
+#[inline]
+fn priority(seed: &mut u32) -> u32 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 17;
+    *seed ^= *seed << 5;
+    *seed
+}
+
+/// Splits the tree into the bytes before `at` and the ones after it, splitting a chunk if needed.
+fn split(tree: Tree, at: usize, seed: &mut u32) -> (Tree, Tree) {
+    let mut node: Box<Node> = match tree {
+        Some(node) => node,
+        None => return (None, None),
+    };
+
+    let left: usize = size(&node.left);
+
+    if at <= left {
+        let (before, after): (Tree, Tree) = split(node.left.take(), at, seed);
+        node.left = after;
+        node.update();
+        (before, Some(node))
+    } else if at >= left + node.chunk.len() {
+        let (before, after): (Tree, Tree) = split(node.right.take(), at - left - node.chunk.len(), seed);
+        node.right = before;
+        node.update();
+        (Some(node), after)
+    } else {
+        // The tail of the chunk gets a priority of it's own and is merged with the chunks after it,
+        // so splitting the same chunk many times does not unbalance the tree.
+        let chunk: Vec<u8> = node.chunk.split_off(at - left);
+        node.chunk.shrink_to_fit();
+
+        let tail: Box<Node> = Box::new(Node {
+            size: chunk.len(),
+            chunk,
+            priority: priority(seed),
+            count: 1,
+            left: None,
+            right: None,
+        });
+        let after: Tree = merge(Some(tail), node.right.take());
+        node.update();
+        (Some(node), after)
+    }
+}
+
+/// Joins two trees, with all the bytes of `before` ending up before the ones of `after`.
+fn merge(before: Tree, after: Tree) -> Tree {
+    match (before, after) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut before), Some(mut after)) => {
+            if before.priority >= after.priority {
+                before.right = merge(before.right.take(), Some(after));
+                before.update();
+                Some(before)
+            } else {
+                after.left = merge(Some(before), after.left.take());
+                after.update();
+                Some(after)
+            }
+        },
+    }
+}
+
+/// Pushes the parts of the chunks in `start..end` (relative to the tree) to the view in order.
+fn gather<'a>(tree: &'a Tree, start: usize, end: usize, view: &mut GatherView<'a>) {
+    let node: &Node = match tree {
+        Some(node) if start < end => node,
+        _ => return,
+    };
+
+    let left: usize = size(&node.left);
+    let right: usize = left + node.chunk.len();
+
+    if start < left {
+        gather(&node.left, start, end.min(left), view);
+    }
+
+    if start < right && end > left {
+        view.push(DataSlice::from_slice(&node.chunk[start.max(left) - left..end.min(right) - left]));
+    }
+
+    if end > right {
+        gather(&node.right, start.saturating_sub(right), end - right, view);
+    }
+}
+
+/// Copies the bytes of the tree into `out`, which must be of the size of the tree.
+fn copy(tree: &Tree, out: &mut [u8]) {
+    if let Some(node) = tree {
+        let left: usize = size(&node.left);
+        let right: usize = left + node.chunk.len();
+
+        copy(&node.left, &mut out[..left]);
+        out[left..right].copy_from_slice(&node.chunk);
+        copy(&node.right, &mut out[right..]);
+    }
+}
+
+/// A list of bytes that can be inserted into and removed from anywhere cheaply.
+/// 
+/// Go to the [module level documentation](self) for more details.
+pub struct DataRope {
+    root: Tree,
+    seed: u32,
+}
+
+impl DataRope {
+    /// Constructs a new empty [`DataRope`].
+    #[inline]
+    pub fn new() -> DataRope {
+        DataRope { root: None, seed: 0x9E37_79B9 }
+    }
+
+    /// Get's the amount of bytes in the rope.
+    #[inline]
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Get's the amount of chunks the bytes of the rope are split into.
+    #[inline]
+    pub fn chunks(&self) -> usize {
+        count(&self.root)
+    }
+
+    /// Checks weather the rope has no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Copies the bytes into the rope, so they start at the byte `at`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if `at` is after the end of the rope.
+    pub fn insert_bytes(&mut self, at: usize, bytes: &DataSlice) -> Result<(), idx::IdxError> {
+        if at > self.size() {
+            return Err(idx::IdxError::new(at, self.size(), bytes.size()))
+        }
+
+        if bytes.size() == 0 {
+            return Ok(())
+        }
+
+        let node: Box<Node> = Box::new(Node {
+            chunk: bytes.inner.to_vec(),
+            priority: priority(&mut self.seed),
+            size: bytes.size(),
+            count: 1,
+            left: None,
+            right: None,
+        });
+
+        let (before, after): (Tree, Tree) = split(self.root.take(), at, &mut self.seed);
+        self.root = merge(merge(before, Some(node)), after);
+        Ok(())
+    }
+
+    /// Removes the bytes in the range from the rope.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the range is not valid for the rope.
+    pub fn remove(&mut self, range: impl Idx) -> Result<(), idx::IdxError> {
+        let range: core::ops::Range<usize> = range.resolve(self.size())?;
+
+        if range.is_empty() {
+            return Ok(())
+        }
+
+        let (before, rest): (Tree, Tree) = split(self.root.take(), range.start, &mut self.seed);
+        let (_, after): (Tree, Tree) = split(rest, range.end - range.start, &mut self.seed);
+        self.root = merge(before, after);
+        Ok(())
+    }
+
+    /// Get's a view of the bytes in the range, over the chunks that hold them.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the range is not valid for the rope.
+    pub fn slice(&self, range: impl Idx) -> Result<GatherView<'_>, idx::IdxError> {
+        let range: core::ops::Range<usize> = range.resolve(self.size())?;
+        let mut view: GatherView<'_> = GatherView::new();
+
+        gather(&self.root, range.start, range.end, &mut view);
+        Ok(view)
+    }
+
+    /// Copies all the bytes of the rope into a single [`DataBoxed`].
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation fails.
+    pub fn flatten(&self) -> Result<DataBoxed, TryReserveError> {
+        let mut data: DataBoxed = DataBoxed::zeroed(self.size())?;
+
+        copy(&self.root, &mut data.inner);
+        Ok(data)
+    }
+}
+
+impl Default for DataRope {
+    #[inline] fn default() -> Self {
+        DataRope::new()
+    }
+}
+
+impl core::fmt::Debug for DataRope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataRope")
+            .field("size", &self.size())
+            .field("chunks", &self.chunks())
+            .finish()
+    }
+}
@@ -0,0 +1,312 @@
+
+/*!
+This module provides the [`RecordLayout`] and all it's associated
+functions, methods and items.
+
+A record is described at runtime as a list of [`Field`]s (a name, a size and an alignment),
+and the [`RecordLayout`] computes where each of them goes, one after another,
+each at an offset aligned for it (the same way [`OffsetBuilder`](crate::layout::OffsetBuilder) does).
+
+The fields can then be accessed in any [`DataSlice`] by their name or by their [`FieldId`],
+so binary formats that are only known at runtime (like the ones of plugins or scripts)
+can be read and written without generating any code for them.
+ */
+
+use crate::{
+    idx,
+    layout::ByteOffset,
+    slice::DataSlice,
+    RawDataStructure,
+};
+use crate::alloc::vec::Vec;
+
+/// The description of a single field of a record.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field<'name> {
+    /// The name of the field.
+    pub name: &'name str,
+    /// The size of the field in bytes.
+    pub size: usize,
+    /// The alignment the field needs, which must be a power of two.
+    pub align: usize,
+}
+
+impl<'name> Field<'name> {
+    /// Constructs a new [`Field`].
+    #[inline]
+    pub const fn new(name: &'name str, size: usize, align: usize) -> Field<'name> {
+        Field { name, size, align }
+    }
+
+    /// Constructs a new [`Field`] with the size and the alignment of `T`.
+    #[inline]
+    pub const fn of<T>(name: &'name str) -> Field<'name> {
+        Field::new(name, core::mem::size_of::<T>(), core::mem::align_of::<T>())
+    }
+}
+
+/// The position of a field in the list of fields of a [`RecordLayout`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldId(pub usize);
+
+/// The error given when a record can not be laid out or a field of it can not be accessed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchemaError {
+    /// The alignment of a field is not a power of two.
+    InvalidAlign {
+        /// The field with the invalid alignment.
+        field: FieldId,
+        /// The alignment it was given.
+        align: usize,
+    },
+    /// The size of the record does not fit in a [usize].
+    Overflow {
+        /// The field that overflowed.
+        field: FieldId,
+    },
+    /// No field has the given name or id.
+    UnknownField,
+    /// The field was read or written as a value of a diferent size then it's own.
+    DiferentSizes {
+        /// The size of the field.
+        expected: usize,
+        /// The size of the value.
+        gotten: usize,
+    },
+    /// The record does not fit in the data.
+    Idx(idx::IdxError),
+}
+
+impl core::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            SchemaError::Idx(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SchemaError::InvalidAlign { field, align } => write!(f, "The alignment `{align}` of the field `{field}` is not a power of two.", field = field.0),
+            SchemaError::Overflow { field } => write!(f, "The record overflowed at the field `{field}`.", field = field.0),
+            SchemaError::UnknownField => f.write_str("The record has no such field."),
+            SchemaError::DiferentSizes { expected, gotten } => write!(f, "Expected a value of size `{expected}` but got a value of size `{gotten}`."),
+            SchemaError::Idx(err) => core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl From<idx::IdxError> for SchemaError {
+    #[inline] fn from(err: idx::IdxError) -> SchemaError {
+        SchemaError::Idx(err)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A way to name a field of a [`RecordLayout`], eather by it's name ([`&str`](str)) or by it's [`FieldId`].
+pub trait FieldKey: sealed::Sealed {
+    /// Get's the id of the field in the given layout, if it has it.
+    fn id(&self, layout: &RecordLayout<'_>) -> Option<FieldId>;
+}
+
+impl sealed::Sealed for FieldId {}
+impl FieldKey for FieldId {
+    #[inline] fn id(&self, layout: &RecordLayout<'_>) -> Option<FieldId> {
+        if self.0 < layout.fields.len() { Some(*self) } else { None }
+    }
+}
+
+impl sealed::Sealed for &str {}
+impl FieldKey for &str {
+    #[inline] fn id(&self, layout: &RecordLayout<'_>) -> Option<FieldId> {
+        layout.id(self)
+    }
+}
+
+/// The computed layout of a record, with the offset of each of it's fields.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordLayout<'name> {
+    fields: Vec<(Field<'name>, usize)>,
+    size: usize,
+    align: usize,
+}
+
+impl<'name> RecordLayout<'name> {
+    /// Lays out the given fields one after another, each at an offset aligned for it.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the alignment of a field is not a power of two
+    /// or if the size of the record does not fit in a [usize].
+    pub fn new(fields: impl IntoIterator<Item = Field<'name>>) -> Result<RecordLayout<'name>, SchemaError> {
+        let mut laid: Vec<(Field<'name>, usize)> = Vec::new();
+        let mut end: ByteOffset = ByteOffset::ZERO;
+        let mut align: usize = 1;
+
+        for field in fields {
+            let id: FieldId = FieldId(laid.len());
+
+            if !field.align.is_power_of_two() {
+                return Err(SchemaError::InvalidAlign { field: id, align: field.align })
+            }
+
+            let offset: ByteOffset = end.align_up(field.align).ok_or(SchemaError::Overflow { field: id })?;
+            end = offset.add(field.size).ok_or(SchemaError::Overflow { field: id })?;
+            align = align.max(field.align);
+
+            laid.push((field, offset.get()));
+        }
+
+        Ok(RecordLayout { fields: laid, size: end.get(), align })
+    }
+
+    /// Get's the amount of bytes the record needs.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get's the largest alignment of the fields of the record.
+    #[inline]
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Get's the amount of fields in the record.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Checks weather the record has no fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get's the id of the first field with the given name.
+    pub fn id(&self, name: &str) -> Option<FieldId> {
+        self.fields.iter().position(#[inline] |(field, _)| field.name == name).map(FieldId)
+    }
+
+    /// Get's the description of the given field.
+    #[inline]
+    pub fn field(&self, key: impl FieldKey) -> Option<&Field<'name>> {
+        key.id(self).map(#[inline] |id| &self.fields[id.0].0)
+    }
+
+    /// Get's the offset of the given field from the start of the record.
+    #[inline]
+    pub fn offset(&self, key: impl FieldKey) -> Option<usize> {
+        key.id(self).map(#[inline] |id| self.fields[id.0].1)
+    }
+
+    /// Get's an iterator over the fields of the record and their offsets, in order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (FieldId, &Field<'name>, usize)> + '_ {
+        self.fields.iter().enumerate().map(#[inline] |(at, (field, offset))| (FieldId(at), field, *offset))
+    }
+
+    #[inline]
+    fn region(&self, key: impl FieldKey) -> Result<core::ops::Range<usize>, SchemaError> {
+        let id: FieldId = key.id(self).ok_or(SchemaError::UnknownField)?;
+        let (field, offset) = &self.fields[id.0];
+
+        Ok(*offset..*offset + field.size)
+    }
+
+    /// Get's the bytes of the given field in the record at the start of `data`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no such field or if the field does not fit in the data.
+    pub fn get<'data>(&self, data: &'data DataSlice, key: impl FieldKey) -> Result<&'data DataSlice, SchemaError> {
+        let region: core::ops::Range<usize> = self.region(key)?;
+        let size: usize = data.size();
+
+        match data.get_const(core::ops::Bound::Included(region.start), core::ops::Bound::Excluded(region.end)) {
+            Some(field) => Ok(field),
+            None => Err(SchemaError::Idx(idx::IdxError::new(region.start, size, region.len()))),
+        }
+    }
+
+    /// Get's the bytes of the given field in the record at the start of `data` mutably.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no such field or if the field does not fit in the data.
+    pub fn get_mut<'data>(&self, data: &'data mut DataSlice, key: impl FieldKey) -> Result<&'data mut DataSlice, SchemaError> {
+        let region: core::ops::Range<usize> = self.region(key)?;
+        let size: usize = data.size();
+
+        match data.get_mut_const(core::ops::Bound::Included(region.start), core::ops::Bound::Excluded(region.end)) {
+            Some(field) => Ok(field),
+            None => Err(SchemaError::Idx(idx::IdxError::new(region.start, size, region.len()))),
+        }
+    }
+
+    /// Copies the given bytes into the given field of the record at the start of `data`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no such field, if the field does not fit in the data
+    /// or if the amount of bytes is not the size of the field.
+    pub fn write_bytes(&self, data: &mut DataSlice, key: impl FieldKey, bytes: &[u8]) -> Result<(), SchemaError> {
+        let field: &mut DataSlice = self.get_mut(data, key)?;
+
+        if field.size() != bytes.len() {
+            return Err(SchemaError::DiferentSizes { expected: field.size(), gotten: bytes.len() })
+        }
+
+        field.inner.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Reads a copy of the value in the given field of the record at the start of `data`.
+    /// 
+    /// The field does not need to be aligned in memory.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no such field, if the field does not fit in the data
+    /// or if the size of `T` is not the size of the field.
+    /// 
+    /// # SAFETY
+    /// - Make sure the data gotten from inside is a valid T
+    pub unsafe fn read<T: Copy>(&self, data: &DataSlice, key: impl FieldKey) -> Result<T, SchemaError> {
+        let field: &DataSlice = self.get(data, key)?;
+
+        if field.size() != core::mem::size_of::<T>() {
+            return Err(SchemaError::DiferentSizes { expected: field.size(), gotten: core::mem::size_of::<T>() })
+        }
+
+        field.read_unaligned_value::<T>(0).map_err(#[inline] |err| SchemaError::Idx(err.error))
+    }
+
+    /// Writes the given value in the given field of the record at the start of `data`.
+    /// 
+    /// The field does not need to be aligned in memory.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no such field, if the field does not fit in the data
+    /// or if the size of `T` is not the size of the field.
+    /// 
+    /// # SAFETY
+    /// Make sure for all the data inside to follow the
+    /// ownership and borrowing rules and guarantees.
+    pub unsafe fn write<T: Copy>(&self, data: &mut DataSlice, key: impl FieldKey, value: T) -> Result<(), SchemaError> {
+        let field: &mut DataSlice = self.get_mut(data, key)?;
+
+        if field.size() != core::mem::size_of::<T>() {
+            return Err(SchemaError::DiferentSizes { expected: field.size(), gotten: core::mem::size_of::<T>() })
+        }
+
+        field.write_unaligned_value::<T>(0, value).map_err(#[inline] |err| SchemaError::Idx(err.error))
+    }
+}
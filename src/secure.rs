@@ -0,0 +1,70 @@
+
+/*!
+This module provides the [`ZeroizeOnDrop`] wrapper, made for data structures that
+hold secret material (keys, passwords, nonces) which should not be left in memory.
+
+The erasing is done with [`DataSlice::secure_erase`], which uses volatile writes so
+the compiler can not optimize them away, unlike [`write_zeroes`](DataSlice::write_zeroes)
+which is removed when the data is never read again (like right before it is dropped).
+
+With the `zeroize` feature the owning data structures also implement
+[`Zeroize`](zeroize::Zeroize) and the wrapper implements
+[`zeroize::ZeroizeOnDrop`], so they can be used with the rest of the `zeroize` ecosystem.
+
+## Limitations
+- Only the bytes the data structure has when it is dropped are erased, copies left behind
+  by moving a [`DataArray`](crate::array::DataArray) or by growing a
+  [`DataVec`](crate::vec::DataVec) are not.
+ */
+
+use crate::slice::DataSlice;
+use core::ops::{
+    Deref,
+    DerefMut,
+};
+
+/// A typeless chunk of data that is erased with [`DataSlice::secure_erase`] when dropped.
+#[must_use]
+pub struct ZeroizeOnDrop<D: DerefMut<Target = DataSlice>> {
+    data: D,
+}
+
+impl<D: DerefMut<Target = DataSlice>> ZeroizeOnDrop<D> {
+    /// Constructs a new [`ZeroizeOnDrop`] wrapping the given data.
+    #[inline]
+    pub const fn new(data: D) -> ZeroizeOnDrop<D> {
+        ZeroizeOnDrop { data }
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> Drop for ZeroizeOnDrop<D> {
+    #[inline] fn drop(&mut self) {
+        self.data.secure_erase();
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> Deref for ZeroizeOnDrop<D> {
+    type Target = DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> DerefMut for ZeroizeOnDrop<D> {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+/// Does not show the bytes, since they are secret.
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for ZeroizeOnDrop<D> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ZeroizeOnDrop")
+            .field("size", &self.data.size())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<D: DerefMut<Target = DataSlice>> zeroize::ZeroizeOnDrop for ZeroizeOnDrop<D> {}
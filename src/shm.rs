@@ -0,0 +1,295 @@
+
+/*!
+This module provides the [`DataShm`] data structure and all it's associated
+functions, methods and items.
+
+[`DataShm`] is made to be used when two (or more) processes want to exchange
+data through a named shared memory segment, using the same API as for local buffers.
+
+Under the surface a [`DataShm`] is a POSIX shared memory object (`shm_open` + `mmap`)
+on unix and a named file mapping (`CreateFileMappingW` + `MapViewOfFile`) on windows.
+
+## Atomicity
+Nothing in this crate synchronizes the accesses of different processes,
+every read and write is a plain (non atomic) copy of bytes, so another process
+can see a value half written or change a value while it is being read.
+The processes have to agree on their own way of synchronizing (a lock, a flag
+written last, a ring of slots owned by one process at a time, ...) before touching
+the same bytes, that is also why the constructors are unsafe.
+ */
+
+use crate::std::io;
+use crate::slice::DataSlice;
+use core::ptr::NonNull;
+
+/// A typeless chunk of data living in named shared memory.
+/// 
+/// The mapping is unmapped when this struct is dropped, but the segment it'self
+/// keeps existing on unix until [`DataShm::unlink`] is called.
+/// 
+/// Go to the [module level documentation](self) for the atomicity caveats.
+#[must_use]
+pub struct DataShm {
+    ptr: NonNull<u8>,
+    size: usize,
+}
+
+// SAFETY: A DataShm owns it's mapping and only hands out references to it
+// through the usual borrowing rules, just like a Box<[u8]>.
+unsafe impl Send for DataShm {}
+unsafe impl Sync for DataShm {}
+
+impl DataShm {
+    /// Creates a new shared memory segment with the given name and size, filled with `0`'s.
+    /// 
+    /// On unix the name should start with a `/` and contain no other `/`,
+    /// on windows it can have a `Local\` or `Global\` prefix.
+    /// 
+    /// # ERRORS
+    /// Will return an error if a segment with that name already exists,
+    /// if the size is `0` or if the operating system refuses to create the segment.
+    /// 
+    /// # SAFETY
+    /// Make sure no other process accesses the segment in a way that
+    /// breaks the borrowing rules of the [`DataShm`] (go to the module documentation).
+    pub unsafe fn create(name: &str, size: usize) -> io::Result<DataShm> {
+        if size == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "A shared memory segment can not be empty."))
+        }
+
+        sys::map(name, size, true).map(|ptr| DataShm { ptr, size })
+    }
+
+    /// Opens an existing shared memory segment with the given name, mapping the first `size` bytes of it.
+    /// 
+    /// Go to [`DataShm::create`] for the names that can be used.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no segment with that name, if the size is `0`,
+    /// if the segment is smaller then `size` (only checked on unix) or if the operating
+    /// system refuses to map the segment.
+    /// 
+    /// # SAFETY
+    /// Make sure no other process accesses the segment in a way that
+    /// breaks the borrowing rules of the [`DataShm`] (go to the module documentation).
+    pub unsafe fn open(name: &str, size: usize) -> io::Result<DataShm> {
+        if size == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "A shared memory segment can not be empty."))
+        }
+
+        sys::map(name, size, false).map(|ptr| DataShm { ptr, size })
+    }
+
+    /// Removes the name of the shared memory segment, the memory is freed once all the
+    /// processes that have it mapped drop their [`DataShm`].
+    /// 
+    /// On windows this does nothing since named file mappings are freed automatically.
+    /// 
+    /// # ERRORS
+    /// Will return an error if there is no segment with that name.
+    #[inline]
+    pub fn unlink(name: &str) -> io::Result<()> {
+        sys::unlink(name)
+    }
+
+    /// Get's the size of the mapped data.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Drop for DataShm {
+    #[inline] fn drop(&mut self) {
+        // SAFETY: The pointer and size are the ones the mapping was made with.
+        unsafe { sys::unmap(self.ptr, self.size) }
+    }
+}
+
+impl core::ops::Deref for DataShm {
+    type Target = DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        DataSlice::from_slice(unsafe {
+            // SAFETY: The mapping is valid for `size` bytes for as long as self lives.
+            core::slice::from_raw_parts(self.ptr.as_ptr(), self.size)
+        })
+    }
+}
+
+impl core::ops::DerefMut for DataShm {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        DataSlice::from_slice_mut(unsafe {
+            // SAFETY: The mapping is valid for `size` bytes for as long as self lives.
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.size)
+        })
+    }
+}
+
+impl core::fmt::Debug for DataShm {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <DataSlice as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use crate::std::{
+        ffi::CString,
+        io,
+    };
+    use core::ptr::NonNull;
+
+    fn c_name(name: &str) -> io::Result<CString> {
+        CString::new(name).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "The name can not contain a nul byte."))
+    }
+
+    pub(super) unsafe fn map(name: &str, size: usize, create: bool) -> io::Result<NonNull<u8>> {
+        let name: CString = c_name(name)?;
+        let flags: libc::c_int = if create { libc::O_RDWR | libc::O_CREAT | libc::O_EXCL } else { libc::O_RDWR };
+
+        let fd: libc::c_int = libc::shm_open(name.as_ptr(), flags, 0o600 as libc::mode_t);
+        if fd < 0 {
+            return Err(io::Error::last_os_error())
+        }
+
+        let result: io::Result<NonNull<u8>> = map_fd(fd, size, create);
+        // The mapping keeps the segment alive, the descriptor is not needed anymore.
+        libc::close(fd);
+
+        if result.is_err() && create {
+            libc::shm_unlink(name.as_ptr());
+        }
+
+        result
+    }
+
+    unsafe fn map_fd(fd: libc::c_int, size: usize, create: bool) -> io::Result<NonNull<u8>> {
+        if create {
+            if libc::ftruncate(fd, size as libc::off_t) != 0 {
+                return Err(io::Error::last_os_error())
+            }
+        } else {
+            let mut stat: libc::stat = core::mem::zeroed();
+
+            if libc::fstat(fd, &mut stat) != 0 {
+                return Err(io::Error::last_os_error())
+            }
+
+            if (stat.st_size as u64) < size as u64 {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "The shared memory segment is smaller then the requested size."))
+            }
+        }
+
+        let ptr: *mut libc::c_void = libc::mmap(
+            core::ptr::null_mut(),
+            size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED,
+            fd,
+            0,
+        );
+
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error())
+        }
+
+        Ok(NonNull::new_unchecked(ptr.cast::<u8>()))
+    }
+
+    pub(super) unsafe fn unmap(ptr: NonNull<u8>, size: usize) {
+        libc::munmap(ptr.as_ptr().cast::<libc::c_void>(), size);
+    }
+
+    pub(super) fn unlink(name: &str) -> io::Result<()> {
+        let name: CString = c_name(name)?;
+
+        // SAFETY: The name is a valid nul terminated string.
+        if unsafe { libc::shm_unlink(name.as_ptr()) } != 0 {
+            return Err(io::Error::last_os_error())
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use crate::std::{
+        io,
+        vec::Vec,
+    };
+    use core::ptr::NonNull;
+
+    use windows_sys::Win32::{
+        Foundation::{
+            CloseHandle,
+            GetLastError,
+            ERROR_ALREADY_EXISTS,
+            HANDLE,
+            INVALID_HANDLE_VALUE,
+        },
+        System::Memory::{
+            CreateFileMappingW,
+            MapViewOfFile,
+            OpenFileMappingW,
+            UnmapViewOfFile,
+            FILE_MAP_ALL_ACCESS,
+            MEMORY_MAPPED_VIEW_ADDRESS,
+            PAGE_READWRITE,
+        },
+    };
+
+    fn wide_name(name: &str) -> io::Result<Vec<u16>> {
+        if name.contains('\0') {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "The name can not contain a nul byte."))
+        }
+
+        Ok(name.encode_utf16().chain(Some(0)).collect())
+    }
+
+    pub(super) unsafe fn map(name: &str, size: usize, create: bool) -> io::Result<NonNull<u8>> {
+        let name: Vec<u16> = wide_name(name)?;
+
+        let handle: HANDLE = if create {
+            let handle: HANDLE = CreateFileMappingW(
+                INVALID_HANDLE_VALUE,
+                core::ptr::null(),
+                PAGE_READWRITE,
+                ((size as u64) >> 32) as u32,
+                size as u32,
+                name.as_ptr(),
+            );
+
+            if !handle.is_null() && GetLastError() == ERROR_ALREADY_EXISTS {
+                CloseHandle(handle);
+                return Err(io::Error::new(io::ErrorKind::AlreadyExists, "A shared memory segment with that name already exists."))
+            }
+
+            handle
+        } else {
+            OpenFileMappingW(FILE_MAP_ALL_ACCESS, 0, name.as_ptr())
+        };
+
+        if handle.is_null() {
+            return Err(io::Error::last_os_error())
+        }
+
+        let view: MEMORY_MAPPED_VIEW_ADDRESS = MapViewOfFile(handle, FILE_MAP_ALL_ACCESS, 0, 0, size);
+        let result: io::Result<NonNull<u8>> = NonNull::new(view.Value.cast::<u8>()).ok_or_else(io::Error::last_os_error);
+
+        // The view keeps the mapping alive, the handle is not needed anymore.
+        CloseHandle(handle);
+
+        result
+    }
+
+    pub(super) unsafe fn unmap(ptr: NonNull<u8>, _size: usize) {
+        UnmapViewOfFile(MEMORY_MAPPED_VIEW_ADDRESS { Value: ptr.as_ptr().cast() });
+    }
+
+    #[inline]
+    pub(super) fn unlink(_name: &str) -> io::Result<()> {
+        Ok(())
+    }
+}
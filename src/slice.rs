@@ -61,6 +61,11 @@ type Slice<T> = [T];
 /// This provides most of the functionality of the crate.
 /// 
 /// This struct is just a [\[u8\]](Slice<u8>) underneeth the hood.
+/// 
+/// The `const` accessors ([`write`](DataSlice::write), [`read`](DataSlice::read), [`take`](DataSlice::take), ...)
+/// can't emit the events of the `trace` feature, as logging isn't possible in a `const fn`.
+/// Call them through [`RawDataStructure`](crate::RawDataStructure) (e.g. `RawDataStructure::write(slice, idx, value)`)
+/// to get them traced.
 #[must_use]
 #[repr(transparent)]
 pub struct DataSlice {
@@ -141,6 +146,47 @@ impl DataSlice {
         self.inner.len()
     }
 
+    /// Get's a pointer to the first byte of the data structure.
+    /// 
+    /// The pointer is valid for reads of [`size`](DataSlice::size) bytes
+    /// for as long as the data structure is not mutably borrowed or moved.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.inner.as_ptr()
+    }
+
+    /// Get's a mutable pointer to the first byte of the data structure.
+    /// 
+    /// The pointer is valid for reads and writes of [`size`](DataSlice::size) bytes
+    /// for as long as the data structure is not borrowed or moved some other way.
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.inner.as_mut_ptr()
+    }
+
+    /// Get's the first index of the data structure where the data is aligned for `T`,
+    /// or [None] if there is no such index (the end of the data structure included).
+    /// 
+    /// Unlike the helpers of the [`layout`](mod@crate::layout) module this looks at the address in memory,
+    /// so the gotten index can be used with [`read_ref_aligned`](crate::RawDataStructure::read_ref_aligned).
+    #[inline]
+    pub fn align_offset<T>(&self) -> Option<usize> {
+        let offset: usize = crate::layout::padding_needed(self.as_ptr().addr(), core::mem::align_of::<T>());
+
+        if offset > self.size() {
+            return None
+        }
+
+        Some(offset)
+    }
+
+    /// Get's the range of pointers the data structure spans,
+    /// the end pointer being one past the last byte.
+    #[inline]
+    pub const fn ptr_range(&self) -> core::ops::Range<*const u8> {
+        self.inner.as_ptr_range()
+    }
+
     /// Writes the given value at the given index.
     /// 
     /// If you want to store a [?Sized](Sized) value use [write_unsized](DataSlice::write_unsized)
@@ -153,17 +199,17 @@ impl DataSlice {
     /// # SAFETY
     /// Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
-    pub const unsafe fn write<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<(), (ManuallyDrop<T>, idx::IdxError)> {
+    pub const unsafe fn write<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<(), (ManuallyDrop<T>, idx::TypedIdxError)> {
         let type_size: usize = core::mem::size_of::<T>();
 
         if match idx.checked_add(type_size) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err((value, idx::IdxError { idx, data_size: self.size(), type_size }))
+            return Err((value, idx::IdxError::new(idx, self.size(), type_size).typed::<T>(idx::Access::Write)))
         }
         
-        let ptr: *const u8 = (&value as *const ManuallyDrop<T>).cast();
+        let ptr: *const u8 = core::ptr::from_ref(&value).cast();
         let mut at: usize = 0;
 
         while at < type_size {
@@ -187,7 +233,9 @@ impl DataSlice {
     /// ownership and borrowing rules and guarantees.
     /// - Make sure no data is written to a region outside of the specified data structure.
     pub const unsafe fn write_unchecked<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) {
-        let ptr: *const u8 = (&value as *const ManuallyDrop<T>).cast();
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+
+        let ptr: *const u8 = core::ptr::from_ref(&value).cast();
         let mut at: usize = 0;
 
         while at < core::mem::size_of::<T>() {
@@ -212,10 +260,10 @@ impl DataSlice {
     /// ownership and borrowing rules and guarantees.
     pub const unsafe fn write_zeroes(&mut self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
         if match idx.checked_add(size) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: size })
+            return Err(idx::IdxError::new(idx, self.size(), size))
         }
         
         let mut at: usize = 0;
@@ -240,6 +288,8 @@ impl DataSlice {
     /// ownership and borrowing rules and guarantees.
     /// - Make sure no data is written to a region outside of the specified data structure.
     pub const unsafe fn write_zeroes_unchecked(&mut self, idx: usize, size: usize) {
+        debug_check_bounds!(self, idx, size);
+
         let mut at: usize = 0;
 
         while at < size {
@@ -260,10 +310,10 @@ impl DataSlice {
     /// ownership and borrowing rules and guarantees.
     pub const unsafe fn write_ones(&mut self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
         if match idx.checked_add(size) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: size })
+            return Err(idx::IdxError::new(idx, self.size(), size))
         }
         
         let mut at: usize = 0;
@@ -288,6 +338,8 @@ impl DataSlice {
     /// ownership and borrowing rules and guarantees.
     /// - Make sure no data is written to a region outside of the specified data structure.
     pub const unsafe fn write_ones_unchecked(&mut self, idx: usize, size: usize) {
+        debug_check_bounds!(self, idx, size);
+
         let mut at: usize = 0;
 
         while at < size {
@@ -296,6 +348,25 @@ impl DataSlice {
         }
     }
 
+    /// Fills all the bytes with `0`'s using volatile writes, so the compiler can not
+    /// optimize them away even if the data is never read again.
+    /// 
+    /// Made for erasing secret material, go to the [`secure`](crate::secure) module for the details.
+    pub fn secure_erase(&mut self) {
+        let ptr: *mut u8 = self.inner.as_mut_ptr();
+
+        for at in 0..self.size() {
+            // SAFETY: The byte is inside of the data structure.
+            unsafe { ptr.add(at).write_volatile(0x00) }
+        }
+
+        // Keeps the writes from being moved after whatever comes next (like a deallocation).
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr(), self.size());
+    }
+
     /// Writes the given value at the given index.
     /// 
     /// This method performs a shallow copy (the)
@@ -328,10 +399,10 @@ impl DataSlice {
         );
 
         if match idx.checked_add(type_size) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size })
+            return Err(idx::IdxError::new(idx, self.size(), type_size))
         }
         
         let ptr: *const u8 = value.cast();
@@ -373,6 +444,7 @@ impl DataSlice {
                 None => unimplemented!(),
             }
         );
+        debug_check_bounds!(self, idx, type_size);
         
         let ptr: *const u8 = value.cast();
         let mut at: usize = 0;
@@ -392,12 +464,12 @@ impl DataSlice {
     /// This is safe because accesing it'self from a raw pointer is unsafe,
     /// and the user should mark then that the safety of the operation.
     // Not using NonNull is intentional (NonNull is *mut, not *const)
-    pub const fn read<T: Sized>(&self, idx: usize) -> Result<*const T, idx::IdxError> {
+    pub const fn read<T: Sized>(&self, idx: usize) -> Result<*const T, idx::TypedIdxError> {
         if match idx.checked_add(core::mem::size_of::<T>()) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: core::mem::size_of::<T>() })
+            return Err(idx::IdxError::new(idx, self.size(), core::mem::size_of::<T>()).typed::<T>(idx::Access::Read))
         }
 
         Ok(
@@ -405,7 +477,7 @@ impl DataSlice {
                 // SAFETY: The addr of this ptr + idx is guaranteed to be in
                 // the data region given to self.inner, which is guaranteed
                 // to be in a valid address by the fact that is exists.
-                (&self.inner as *const [u8]).cast::<T>().add(idx)
+                self.inner.as_ptr().add(idx).cast::<T>()
             }
         )
     }
@@ -418,9 +490,11 @@ impl DataSlice {
     /// Make sure data isn't read from outside the data structure
     // Not using NonNull is intentional (NonNull is *mut, not *const)
     pub const unsafe fn read_unchecked<T: Sized>(&self, idx: usize) -> *const T {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+
         unsafe {
             // SAFETY: Must be upheld by the caller.
-            (&self.inner as *const [u8]).cast::<T>().add(idx)
+            self.inner.as_ptr().add(idx).cast::<T>()
         }
     }
 
@@ -431,12 +505,12 @@ impl DataSlice {
     /// This is safe because accesing it'self from a raw pointer is unsafe,
     /// and the user should mark then that the safety of the operation.
     // Not using NonNull is intentional (consistancy with read)
-    pub const fn read_mut<T: Sized>(&mut self, idx: usize) -> Result<*mut T, idx::IdxError> {
+    pub const fn read_mut<T: Sized>(&mut self, idx: usize) -> Result<*mut T, idx::TypedIdxError> {
         if match idx.checked_add(core::mem::size_of::<T>()) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: core::mem::size_of::<T>() })
+            return Err(idx::IdxError::new(idx, self.size(), core::mem::size_of::<T>()).typed::<T>(idx::Access::Read))
         }
 
         Ok(
@@ -444,7 +518,7 @@ impl DataSlice {
                 // SAFETY: The addr of this ptr + idx is guaranteed to be in
                 // the data region given to self.inner, which is guaranteed
                 // to be in a valid address by the fact that is exists.
-                (&mut self.inner as *mut [u8]).cast::<T>().add(idx)
+                self.inner.as_mut_ptr().add(idx).cast::<T>()
             }
         )
     }
@@ -457,14 +531,78 @@ impl DataSlice {
     /// Make sure data isn't read from outside the data structure
     // Not using NonNull is intentional (consistancy with read)
     pub const unsafe fn read_mut_unchecked<T: Sized>(&mut self, idx: usize) -> *mut T {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+
         unsafe {
             // SAFETY: The addr of this ptr + idx is guaranteed to be in
             // the data region given to self.inner, which is guaranteed
             // to be in a valid address by the fact that is exists.
-            (&mut self.inner as *mut [u8]).cast::<T>().add(idx)
+            self.inner.as_mut_ptr().add(idx).cast::<T>()
+        }
+    }
+
+    /// Returns a [`NonNull`](core::ptr::NonNull) pointer to the specified data region.
+    /// 
+    /// Since the pointer comes from a shared refrence it must NOT be written through,
+    /// use [read_mut_nonnull](DataSlice::read_mut_nonnull) for that.
+    #[inline]
+    pub const fn read_nonnull<T: Sized>(&self, idx: usize) -> Result<core::ptr::NonNull<T>, idx::TypedIdxError> {
+        match self.read::<T>(idx) {
+            // SAFETY: read can never return a null ptr.
+            Ok(ptr) => Ok(unsafe { core::ptr::NonNull::new_unchecked(ptr.cast_mut()) }),
+            Err(err) => Err(err),
         }
     }
 
+    /// Returns a mutable [`NonNull`](core::ptr::NonNull) pointer to the specified data region.
+    #[inline]
+    pub const fn read_mut_nonnull<T: Sized>(&mut self, idx: usize) -> Result<core::ptr::NonNull<T>, idx::TypedIdxError> {
+        match self.read_mut::<T>(idx) {
+            // SAFETY: read_mut can never return a null ptr.
+            Ok(ptr) => Ok(unsafe { core::ptr::NonNull::new_unchecked(ptr) }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Get's the address of the byte at the given index, without exposing the provenance of the data structure.
+    /// 
+    /// The index is not checked, an index out of bounds just gives the address that byte would have.
+    #[inline]
+    pub fn addr_of_idx(&self, idx: usize) -> usize {
+        self.inner.as_ptr().wrapping_add(idx).addr()
+    }
+
+    /// Get's the index of the byte at the given address.
+    /// 
+    /// Returns [None] if the address is not inside of the data structure.
+    #[inline]
+    pub fn idx_of_addr(&self, addr: usize) -> Option<usize> {
+        match addr.checked_sub(self.addr_of_idx(0)) {
+            Some(idx) if idx < self.size() => Some(idx),
+            _ => None,
+        }
+    }
+
+    /// Makes a pointer to the given address with the provenance of this data structure,
+    /// the strict provenance replacement of `addr as *const u8`.
+    /// 
+    /// The pointer can only be used to access this data structure,
+    /// so it's only usable if the address is inside of it.
+    #[inline]
+    pub fn with_addr(&self, addr: usize) -> *const u8 {
+        self.inner.as_ptr().with_addr(addr)
+    }
+
+    /// Makes a mutable pointer to the given address with the provenance of this data structure,
+    /// the strict provenance replacement of `addr as *mut u8`.
+    /// 
+    /// The pointer can only be used to access this data structure,
+    /// so it's only usable if the address is inside of it.
+    #[inline]
+    pub fn with_addr_mut(&mut self, addr: usize) -> *mut u8 {
+        self.inner.as_mut_ptr().with_addr(addr)
+    }
+
     /// Returns a pointer to the specified data region with the provided metadata.
     /// 
     /// If you know T is sized use [read](DataSlice::read) instead.
@@ -472,24 +610,24 @@ impl DataSlice {
     /// This is safe because accesing it'self from a raw pointer is unsafe,
     /// and the user should mark then that the safety of the operation.
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     pub fn read_unsized<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> Result<*const T, idx::IdxError>
     where T::Metadata: crate::GetSizeOf<T>
     {
         if match idx.checked_add(meta.size()) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: meta.size() })
+            return Err(idx::IdxError::new(idx, self.size(), meta.size()))
         }
 
+        trace_access!(self, "read", idx, meta.size(), T);
         Ok(
             core::ptr::from_raw_parts(
                 unsafe {
                     // SAFETY: The addr of this ptr + idx is guaranteed to be in
                     // the data region given to self.inner, which is guaranteed
                     // to be in a valid address by the fact that is exists.
-                    (&self.inner as *const [u8]).cast::<u8>().add(idx)
+                    self.inner.as_ptr().add(idx)
                 },
                 meta,
             )
@@ -503,13 +641,15 @@ impl DataSlice {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     pub const unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T
     {
+        // The size of the value can not be gotten from the metadata in a const context, so only the index is checked.
+        debug_check_bounds!(self, idx, 0);
+
         core::ptr::from_raw_parts(
             unsafe {
                 // SAFETY: The safety must be upheld by the caller.
-                (&self.inner as *const [u8]).cast::<u8>().add(idx)
+                self.inner.as_ptr().add(idx)
             },
             meta,
         )
@@ -522,24 +662,24 @@ impl DataSlice {
     /// This is safe because accesing it'self from a raw pointer is unsafe,
     /// and the user should mark then that the safety of the operation.
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     pub fn read_unsized_mut<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> Result<*mut T, idx::IdxError>
     where T::Metadata: crate::GetSizeOf<T>
     {
         if match idx.checked_add(meta.size()) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: meta.size() })
+            return Err(idx::IdxError::new(idx, self.size(), meta.size()))
         }
 
+        trace_access!(self, "read_mut", idx, meta.size(), T);
         Ok(
             core::ptr::from_raw_parts_mut(
                 unsafe {
                     // SAFETY: The addr of this ptr + idx is guaranteed to be in
                     // the data region given to self.inner, which is guaranteed
                     // to be in a valid address by the fact that is exists.
-                    (&mut self.inner as *mut [u8]).cast::<u8>().add(idx)
+                    self.inner.as_mut_ptr().add(idx)
                 },
                 meta,
             )
@@ -553,13 +693,15 @@ impl DataSlice {
     /// # SAFETY
     /// Make sure data isn't read from outside the data structure
     #[cfg(feature = "ptr_metadata")]
-    #[allow(private_bounds)]
     pub const unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T
     {
+        // The size of the value can not be gotten from the metadata in a const context, so only the index is checked.
+        debug_check_bounds!(self, idx, 0);
+
         core::ptr::from_raw_parts_mut(
             unsafe {
                 // SAFETY: The safety must be upheld by the caller.
-                (&mut self.inner as *mut [u8]).cast::<u8>().add(idx)
+                self.inner.as_mut_ptr().add(idx)
             },
             meta,
         )
@@ -573,12 +715,12 @@ impl DataSlice {
     /// - Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
     /// - Make sure the data gotten from inside is a valid T
-    pub const unsafe fn take<T: Sized>(&self, idx: usize) -> Result<T, idx::IdxError> {
+    pub const unsafe fn take<T: Sized>(&self, idx: usize) -> Result<T, idx::TypedIdxError> {
         if match idx.checked_add(core::mem::size_of::<T>()) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: core::mem::size_of::<T>() })
+            return Err(idx::IdxError::new(idx, self.size(), core::mem::size_of::<T>()).typed::<T>(idx::Access::Take))
         }
 
         use core::mem::MaybeUninit;
@@ -593,7 +735,7 @@ impl DataSlice {
                 // SAFETY: The addr of this ptr + idx is guaranteed to be in
                 // the data region given to self.inner, which is guaranteed
                 // to be in a valid address by the fact that is exists.
-                (&self.inner as *const [u8]).cast::<u8>().add(idx)
+                self.inner.as_ptr().add(idx)
             },
             core::mem::size_of::<T>(),
         );
@@ -616,6 +758,8 @@ impl DataSlice {
     /// - Make sure the data gotten from inside is a valid T
     /// - Make sure data isn't read from outside the data structure
     pub const unsafe fn take_unchecked<T: Sized>(&self, idx: usize) -> T {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+
         use core::mem::MaybeUninit;
 
         let mut value: MaybeUninit<T> = MaybeUninit::uninit();
@@ -628,7 +772,7 @@ impl DataSlice {
                 // SAFETY: The addr of this ptr + idx is guaranteed to be in
                 // the data region given to self.inner, which is guaranteed
                 // to be in a valid address by the fact that is exists.
-                (&self.inner as *const [u8]).cast::<u8>().add(idx)
+                self.inner.as_ptr().add(idx)
             },
             core::mem::size_of::<T>(),
         );
@@ -645,12 +789,12 @@ impl DataSlice {
     /// - Make sure for all the data inside to follow the
     /// ownership and borrowing rules and guarantees.
     /// - Make sure the data gotten from inside is a valid T
-    pub const unsafe fn replace<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<T, idx::IdxError> {
+    pub const unsafe fn replace<T: Sized>(&mut self, idx: usize, value: ManuallyDrop<T>) -> Result<T, idx::TypedIdxError> {
         if match idx.checked_add(core::mem::size_of::<T>()) {
-            Some(size) => size >= self.size(),
+            Some(size) => size > self.size(),
             None => true,
         } {
-            return Err(idx::IdxError { idx, data_size: self.size(), type_size: core::mem::size_of::<T>() })
+            return Err(idx::IdxError::new(idx, self.size(), core::mem::size_of::<T>()).typed::<T>(idx::Access::Replace))
         }
         
         Ok(
@@ -665,39 +809,111 @@ impl DataSlice {
                     // SAFETY: The addr of this ptr + idx is guaranteed to be in
                     // the data region given to self.inner, which is guaranteed
                     // to be in a valid address by the fact that is exists.
-                    (&mut self.inner as *mut [u8]).cast::<T>().add(idx),
+                    self.inner.as_mut_ptr().add(idx).cast::<T>(),
                     ManuallyDrop::into_inner(value)
                 )
             }
         )
     }
 
-    /// Get's a subslice of the data structure in a const context.
-    pub const fn get_const(&self, start: core::ops::Bound<usize>, end: core::ops::Bound<usize>) -> Option<&DataSlice> {
-        if self.size() == 0 { return None }
-        
-        use core::ops::Bound::*;
+    /// Takes the value from the specified region and writes a new value in it's palce.
+    /// 
+    /// The old bytes are copied into `out` and the new value is written
+    /// in their place, the size of the region beeing the size of the given value.
+    /// 
+    /// If you want to replace a [Sized] value it
+    /// is recomended to use [replace](DataSlice::replace) instead.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the replace function catches
+    /// it'self trying to acces a memory region that is
+    /// not assigned to the data structure.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [ManuallyDrop])
+    /// - Make sure `out` is valid for writes of as many bytes as the given value has.
+    pub const unsafe fn replace_unsized<T: ?Sized>(&mut self, idx: usize, value: *const ManuallyDrop<T>, out: *mut T) -> Result<(), idx::IdxError> {
+        let type_size: usize = core::mem::size_of_val::<ManuallyDrop<T>>(
+            value.as_ref().expect("A null pointer was given to replace_unsized.")
+        );
 
-        // included
-        let start: usize = match start {
-            Unbounded => 0,
-            Included(idx) => if idx < self.size() { idx } else { return None },
-            Excluded(idx) => if idx.saturating_add(1) < self.size() { idx + 1 } else { return None },
-        };
+        if match idx.checked_add(type_size) {
+            Some(size) => size > self.size(),
+            None => true,
+        } {
+            return Err(idx::IdxError::new(idx, self.size(), type_size))
+        }
+
+        self.replace_unsized_unchecked(idx, value, out);
 
-        // excluded
-        let end: usize = match end {
-            Unbounded => self.size(),
-            Included(idx) => if idx < self.size() { idx.saturating_sub(1) } else { return None },
-            Excluded(idx) => if idx <= self.size() { idx } else { return None },
+        Ok(())
+    }
+
+    /// Takes the value from the specified region and writes a new value in it's palce.
+    /// 
+    /// The old bytes are copied into `out` and the new value is written
+    /// in their place, the size of the region beeing the size of the given value.
+    /// 
+    /// If you want to replace a [Sized] value it
+    /// is recomended to use [replace](DataSlice::replace) instead.
+    /// 
+    /// # PANICS
+    /// Will panic if a null pointer is given.
+    /// 
+    /// # Safety
+    /// - Make sure for all the data inside to follow the
+    ///   ownership and borrowing rules and guarantees.
+    /// - Make sure the data gotten from inside is a valid T
+    /// - Make sure that the value is not used again after being given to this funtion
+    ///   (eg: using [`mem::forget`](core::mem::forget) or moving the value into a [ManuallyDrop])
+    /// - Make sure `out` is valid for writes of as many bytes as the given value has.
+    /// - Make sure data isn't taken from outside the data structure.
+    pub const unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const ManuallyDrop<T>, out: *mut T) {
+        let type_size: usize = core::mem::size_of_val::<ManuallyDrop<T>>(
+            value.as_ref().expect("A null pointer was given to replace_unsized_unchecked.")
+        );
+        debug_check_bounds!(self, idx, type_size);
+
+        let ptr: *const u8 = value.cast();
+        let out: *mut u8 = out.cast();
+        let mut at: usize = 0;
+
+        while at < type_size {
+            unsafe {
+                // SAFETY: The caller must make sure out can hold type_size bytes.
+                *out.add(at) = self.inner[at + idx];
+            }
+            self.inner[at + idx] = unsafe {
+                *ptr.add(at)
+            };
+            at += 1;
+        }
+    }
+
+    /// Get's a subslice of the data structure in a const context.
+    /// 
+    /// # Errors
+    /// Will return [None] if the given bounds get out of bounds,
+    /// bounds that resolve to an empty range give an empty slice.
+    pub const fn get_const(&self, start: core::ops::Bound<usize>, end: core::ops::Bound<usize>) -> Option<&DataSlice> {
+        let core::ops::Range { start, end } = match idx::resolve((start, end), self.size()) {
+            Ok(range) => range,
+            Err(_) => return None,
         };
 
         Some (
             DataSlice::from_slice(
                 unsafe {
                     core::slice::from_raw_parts(
-                        (&self.inner as *const [u8]).cast::<u8>().add(start),
-                        end.saturating_sub(start),
+                        self.inner.as_ptr().add(start),
+                        end - start,
                     )
                 }
             )
@@ -705,31 +921,22 @@ impl DataSlice {
     }
 
     /// Get's a mutable subslice of the data structure in a const context.
+    /// 
+    /// # Errors
+    /// Will return [None] if the given bounds get out of bounds,
+    /// bounds that resolve to an empty range give an empty slice.
     pub const fn get_mut_const(&mut self, start: core::ops::Bound<usize>, end: core::ops::Bound<usize>) -> Option<&mut DataSlice> {
-        if self.size() == 0 { return None }
-        
-        use core::ops::Bound::*;
-
-        // included
-        let start: usize = match start {
-            Unbounded => 0,
-            Included(idx) => if idx < self.size() { idx } else { return None },
-            Excluded(idx) => if idx.saturating_add(1) < self.size() { idx + 1 } else { return None },
-        };
-
-        // excluded
-        let end: usize = match end {
-            Unbounded => self.size(),
-            Included(idx) => if idx < self.size() { idx.saturating_sub(1) } else { return None },
-            Excluded(idx) => if idx <= self.size() { idx } else { return None },
+        let core::ops::Range { start, end } = match idx::resolve((start, end), self.size()) {
+            Ok(range) => range,
+            Err(_) => return None,
         };
 
         Some (
             DataSlice::from_slice_mut(
                 unsafe {
                     core::slice::from_raw_parts_mut(
-                        (&mut self.inner as *mut [u8]).cast::<u8>().add(start),
-                        end.saturating_sub(start),
+                        self.inner.as_mut_ptr().add(start),
+                        end - start,
                     )
                 }
             )
@@ -744,7 +951,10 @@ impl DataSlice {
     /// Will return [None] if the given index gets out of bounds.
     #[inline]
     pub fn get(&self, idx: impl idx::Idx) -> Option<&DataSlice> {
-        self.get_const(idx.start(), idx.end())
+        match idx.resolve(self.size()) {
+            Ok(range) => self.get_const(core::ops::Bound::Included(range.start), core::ops::Bound::Excluded(range.end)),
+            Err(_) => None,
+        }
     }
 
     /// Get's a mutable refrence to a subslice of the data structure.
@@ -755,7 +965,10 @@ impl DataSlice {
     /// Will return [None] if the given index gets out of bounds.
     #[inline]
     pub fn get_mut(&mut self, idx: impl idx::Idx) -> Option<&mut DataSlice> {
-        self.get_mut_const(idx.start(), idx.end())
+        match idx.resolve(self.size()) {
+            Ok(range) => self.get_mut_const(core::ops::Bound::Included(range.start), core::ops::Bound::Excluded(range.end)),
+            Err(_) => None,
+        }
     }
 
     /// Get's the iterator that iterates over the data structure.
@@ -769,6 +982,425 @@ impl DataSlice {
     pub fn iter_mut<'data>(&'data mut self) -> core::slice::IterMut<'data, u8> {
         self.into_iter()
     }
+
+    /// Get's the nibble (4 bits) at the given nibble index.
+    /// 
+    /// The nibble at an even index is the high half of the byte `idx / 2`
+    /// and the nibble at an odd index is the low half, so the nibbles are
+    /// in the same order as the hex digits of the bytes.
+    /// 
+    /// Returns [None] if the index is out of bounds.
+    #[inline]
+    pub const fn get_nibble(&self, idx: usize) -> Option<u8> {
+        if idx / 2 >= self.size() {
+            return None
+        }
+
+        let byte: u8 = self.inner[idx / 2];
+
+        Some(if idx.is_multiple_of(2) { byte >> 4 } else { byte & 0x0F })
+    }
+
+    /// Set's the nibble (4 bits) at the given nibble index.
+    /// 
+    /// Only the lowest 4 bits of `value` are used.
+    /// Go to [get_nibble](DataSlice::get_nibble) for how the nibbles are ordered.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is out of bounds,
+    /// the index and sizes of the error are in nibbles.
+    #[inline]
+    pub const fn set_nibble(&mut self, idx: usize, value: u8) -> Result<(), idx::IdxError> {
+        if idx / 2 >= self.size() {
+            return Err(idx::IdxError::new(idx, self.size() * 2, 1))
+        }
+
+        let byte: &mut u8 = &mut self.inner[idx / 2];
+
+        *byte = if idx.is_multiple_of(2) {
+            (*byte & 0x0F) | (value << 4)
+        } else {
+            (*byte & 0xF0) | (value & 0x0F)
+        };
+
+        Ok(())
+    }
+
+    /// Writes the given value as a LEB128 variable length integer at the given index.
+    /// 
+    /// Returns the amount of bytes written.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not fit in the data structure,
+    /// in which case nothing is written.
+    pub const fn write_varint_u64(&mut self, idx: usize, value: u64) -> Result<usize, idx::IdxError> {
+        let len: usize = crate::varint::encoded_len_u64(value);
+
+        if match idx.checked_add(len) {
+            Some(size) => size > self.size(),
+            None => true,
+        } {
+            return Err(idx::IdxError::new(idx, self.size(), len))
+        }
+
+        let mut value: u64 = value;
+        let mut at: usize = 0;
+
+        while at < len {
+            let byte: u8 = (value & 0x7F) as u8;
+            value >>= 7;
+            self.inner[idx + at] = if at + 1 < len { byte | 0x80 } else { byte };
+            at += 1;
+        }
+
+        Ok(len)
+    }
+
+    /// Reads a LEB128 variable length integer at the given index.
+    /// 
+    /// Returns the value and the amount of bytes read.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not end inside
+    /// the data structure or if it does not fit in a [u64].
+    pub const fn read_varint_u64(&self, idx: usize) -> Result<(u64, usize), crate::varint::VarintError> {
+        use crate::varint::{VarintError, MAX_LEN_U64};
+
+        let mut value: u64 = 0;
+        let mut at: usize = 0;
+
+        loop {
+            if at == MAX_LEN_U64 {
+                return Err(VarintError::Overflow)
+            }
+
+            let pos: usize = match idx.checked_add(at) {
+                Some(pos) if pos < self.size() => pos,
+                _ => return Err(VarintError::OutOfBounds(idx::IdxError::new(idx, self.size(), at + 1))),
+            };
+
+            let byte: u8 = self.inner[pos];
+
+            // The 10th byte can only hold the highest bit of a u64.
+            if at == MAX_LEN_U64 - 1 && byte > 0x01 {
+                return Err(VarintError::Overflow)
+            }
+
+            value |= ((byte & 0x7F) as u64) << (at * 7);
+            at += 1;
+
+            if byte & 0x80 == 0 {
+                return Ok((value, at))
+            }
+        }
+    }
+
+    /// Writes the given value as a zig-zag encoded LEB128 variable length integer at the given index.
+    /// 
+    /// Returns the amount of bytes written.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not fit in the data structure,
+    /// in which case nothing is written.
+    #[inline]
+    pub const fn write_varint_i64(&mut self, idx: usize, value: i64) -> Result<usize, idx::IdxError> {
+        self.write_varint_u64(idx, crate::varint::zigzag_encode(value))
+    }
+
+    /// Reads a zig-zag encoded LEB128 variable length integer at the given index.
+    /// 
+    /// Returns the value and the amount of bytes read.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the encoded value does not end inside
+    /// the data structure or if it does not fit in an [i64].
+    #[inline]
+    pub const fn read_varint_i64(&self, idx: usize) -> Result<(i64, usize), crate::varint::VarintError> {
+        match self.read_varint_u64(idx) {
+            Ok((value, len)) => Ok((crate::varint::zigzag_decode(value), len)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the CRC-32 ([`Crc32::ISO_HDLC`](crate::checksum::Crc32::ISO_HDLC)) of all the bytes
+    /// before the given index at that index, as a little endian [u32].
+    /// 
+    /// Returns the CRC written.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the CRC does not fit in the data structure,
+    /// in which case nothing is written.
+    pub const fn write_crc32_at(&mut self, idx: usize) -> Result<u32, idx::IdxError> {
+        let size: usize = core::mem::size_of::<u32>();
+
+        if match idx.checked_add(size) {
+            Some(end) => end > self.size(),
+            None => true,
+        } {
+            return Err(idx::IdxError::new(idx, self.size(), size))
+        }
+
+        let crc: u32 = crate::checksum::Crc32::ISO_HDLC.compute(DataSlice::from_slice(self.inner.split_at(idx).0));
+        let bytes: [u8; 4] = crc.to_le_bytes();
+        let mut at: usize = 0;
+
+        while at < size {
+            self.inner[idx + at] = bytes[at];
+            at += 1;
+        }
+
+        Ok(crc)
+    }
+
+    /// Checks weather the little endian [u32] at the given index is the CRC-32
+    /// ([`Crc32::ISO_HDLC`](crate::checksum::Crc32::ISO_HDLC)) of all the bytes before it,
+    /// as written by [`write_crc32_at`](DataSlice::write_crc32_at).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the CRC does not fit in the data structure.
+    pub const fn check_crc32_at(&self, idx: usize) -> Result<bool, idx::IdxError> {
+        let size: usize = core::mem::size_of::<u32>();
+
+        if match idx.checked_add(size) {
+            Some(end) => end > self.size(),
+            None => true,
+        } {
+            return Err(idx::IdxError::new(idx, self.size(), size))
+        }
+
+        let crc: u32 = crate::checksum::Crc32::ISO_HDLC.compute(DataSlice::from_slice(self.inner.split_at(idx).0));
+
+        Ok(crc == u32::from_le_bytes([self.inner[idx], self.inner[idx + 1], self.inner[idx + 2], self.inner[idx + 3]]))
+    }
+
+    /// Checks weather the bits that are set in the mask are the same in both data structures,
+    /// the other bits being ignored (eg: for sequence numbers or timestamps in captured frames).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the other data structure or the mask is not of the same size as this one.
+    pub const fn eq_masked(&self, other: &DataSlice, mask: &DataSlice) -> Result<bool, idx::SizeMismatch> {
+        if other.size() != self.size() {
+            return Err(idx::SizeMismatch { expected: self.size(), gotten: other.size() })
+        }
+        if mask.size() != self.size() {
+            return Err(idx::SizeMismatch { expected: self.size(), gotten: mask.size() })
+        }
+
+        let mut at: usize = 0;
+
+        while at < self.inner.len() {
+            if (self.inner[at] ^ other.inner[at]) & mask.inner[at] != 0 {
+                return Ok(false)
+            }
+            at += 1;
+        }
+
+        Ok(true)
+    }
+
+    /// Hashes all the bytes of the data structure with 64 bit FNV-1a.
+    /// 
+    /// The hash only depends on the bytes, so it is the same on every platform and run,
+    /// to hash a range of the data structure get it first (eg: with [`get`](DataSlice::get)).
+    /// 
+    /// This is not a cryptographic hash.
+    pub const fn hash_fnv1a(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+        const PRIME: u64 = 0x00000100000001B3;
+
+        let mut hash: u64 = OFFSET_BASIS;
+        let mut at: usize = 0;
+
+        while at < self.inner.len() {
+            hash ^= self.inner[at] as u64;
+            hash = hash.wrapping_mul(PRIME);
+            at += 1;
+        }
+
+        hash
+    }
+
+    /// Hashes all the bytes of the data structure with XXH64 and the given seed.
+    /// 
+    /// The hash only depends on the bytes and the seed, so it is the same on every platform and run,
+    /// to hash a range of the data structure get it first (eg: with [`get`](DataSlice::get)).
+    /// 
+    /// This is not a cryptographic hash.
+    #[cfg(feature = "xxhash")]
+    #[inline]
+    pub fn hash_xx64(&self, seed: u64) -> u64 {
+        xxhash_rust::xxh64::xxh64(&self.inner, seed)
+    }
+
+    /// Fills all the bytes of the data structure with random bytes from the
+    /// entropy source of the system (the OS or the hardware).
+    /// 
+    /// # ERRORS
+    /// Will return an error if the entropy source could not be used,
+    /// in which case the bytes may be partially written.
+    #[cfg(feature = "getrandom")]
+    pub fn fill_random(&mut self) -> Result<(), getrandom::Error> {
+        getrandom::fill(&mut self.inner)?;
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr(), self.size());
+
+        Ok(())
+    }
+
+    /// Writes the bytes of the data structure as lowercase hex text (two digits per byte) into the given writer.
+    /// 
+    /// To write the text into another [`DataSlice`] use a [`FmtWriter`](crate::fmt::FmtWriter).
+    /// 
+    /// # ERRORS
+    /// Will return any error the writer returns.
+    pub fn encode_hex<W: core::fmt::Write + ?Sized>(&self, out: &mut W) -> core::fmt::Result {
+        for byte in self.inner.iter().copied() {
+            out.write_char(crate::hex::DIGITS[(byte >> 4) as usize] as char)?;
+            out.write_char(crate::hex::DIGITS[(byte & 0xF) as usize] as char)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get's a view of the data structure indexed by bits.
+    #[inline]
+    pub const fn bits(&self) -> &crate::bits::BitSliceView {
+        crate::bits::BitSliceView::from_data_slice(self)
+    }
+
+    /// Get's a mutable view of the data structure indexed by bits.
+    #[inline]
+    pub const fn bits_mut(&mut self) -> &mut crate::bits::BitSliceView {
+        crate::bits::BitSliceView::from_data_slice_mut(self)
+    }
+
+    /// Get's the data structure as a slice of atomic bytes,
+    /// so it can be shared between threads that update it at the same time.
+    /// 
+    /// This takes `&mut self` on purpose: the bytes of a `&DataSlice` are not inside of an
+    /// [`UnsafeCell`](core::cell::UnsafeCell), so writing to them (even atomically) while a
+    /// shared refrence exists is undefined behaviour. Borrowing mutably guarantees that
+    /// the returned atomics are the only way to access the bytes for as long as they live,
+    /// and the `&[AtomicU8]` it'self can be freely shared (for example with scoped threads).
+    #[inline]
+    #[cfg(target_has_atomic = "8")]
+    pub const fn as_atomic_bytes(&mut self) -> &[core::sync::atomic::AtomicU8] {
+        unsafe {
+            // SAFETY: AtomicU8 has the same size and alignment as u8,
+            // and the bytes are borrowed mutably for the whole lifetime.
+            core::slice::from_raw_parts(
+                self.inner.as_mut_ptr().cast::<core::sync::atomic::AtomicU8>(),
+                self.size(),
+            )
+        }
+    }
+
+    /// Get's the data structure as a slice of atomic [u32]'s,
+    /// so it can be shared between threads that update it at the same time.
+    /// 
+    /// Go to [as_atomic_bytes](DataSlice::as_atomic_bytes) for why this takes `&mut self`.
+    /// 
+    /// Returns [None] if the data structure is not aligned to `4` bytes in memory
+    /// or if it's size is not a multiple of `4`.
+    #[inline]
+    #[cfg(target_has_atomic = "32")]
+    pub fn as_atomic_u32s(&mut self) -> Option<&[core::sync::atomic::AtomicU32]> {
+        let ptr: *mut core::sync::atomic::AtomicU32 = self.inner.as_mut_ptr().cast::<core::sync::atomic::AtomicU32>();
+
+        if !ptr.is_aligned() || !self.size().is_multiple_of(4) {
+            return None
+        }
+
+        Some(
+            unsafe {
+                // SAFETY: The pointer is aligned, the size fits exactly
+                // and the bytes are borrowed mutably for the whole lifetime.
+                core::slice::from_raw_parts(ptr, self.size() / 4)
+            }
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl DataSlice {
+    /// Reads from the given reader until the data structure is full or the reader has nothing left.
+    /// 
+    /// Returns the amount of bytes read, those are always at the start of the data structure.
+    /// 
+    /// # ERRORS
+    /// Will return any error the reader returns, except for [`Interrupted`](crate::std::io::ErrorKind::Interrupted)
+    /// ones, which just retry the read.
+    pub fn read_from(&mut self, reader: &mut impl crate::std::io::Read) -> crate::std::io::Result<usize> {
+        let mut filled: usize = 0;
+
+        while filled < self.size() {
+            match reader.read(&mut self.inner[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(err) if err.kind() == crate::std::io::ErrorKind::Interrupted => {},
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(filled)
+    }
+
+    /// Reads exactly enough bytes from the given reader to fill the given range.
+    /// 
+    /// # ERRORS
+    /// Will return an [`InvalidInput`](crate::std::io::ErrorKind::InvalidInput) error if the range gets out of bounds,
+    /// an [`UnexpectedEof`](crate::std::io::ErrorKind::UnexpectedEof) error if the reader ends before the range is filled
+    /// or any other error the reader returns.
+    pub fn read_exact_into(&mut self, range: impl idx::Idx, reader: &mut impl crate::std::io::Read) -> crate::std::io::Result<()> {
+        match self.get_mut(range) {
+            Some(data) => {
+                trace_access!(data, "write", 0, data.size(), [u8]);
+                reader.read_exact(&mut data.inner)
+            },
+            None => Err(crate::std::io::Error::new(
+                crate::std::io::ErrorKind::InvalidInput,
+                "The given range gets out of the DataSlice.",
+            )),
+        }
+    }
+
+    /// Writes all the bytes of the data structure into the given writer.
+    /// 
+    /// # ERRORS
+    /// Will return any error the writer returns.
+    #[inline]
+    pub fn write_to(&self, writer: &mut impl crate::std::io::Write) -> crate::std::io::Result<()> {
+        writer.write_all(&self.inner)
+    }
+
+    /// Writes the bytes in the given range into the given writer.
+    /// 
+    /// # ERRORS
+    /// Will return an [`InvalidInput`](crate::std::io::ErrorKind::InvalidInput) error if the range gets out of bounds
+    /// or any error the writer returns.
+    pub fn write_range_to(&self, range: impl idx::Idx, writer: &mut impl crate::std::io::Write) -> crate::std::io::Result<()> {
+        match self.get(range) {
+            Some(data) => {
+                trace_access!(data, "read", 0, data.size(), [u8]);
+                writer.write_all(&data.inner)
+            },
+            None => Err(crate::std::io::Error::new(
+                crate::std::io::ErrorKind::InvalidInput,
+                "The given range gets out of the DataSlice.",
+            )),
+        }
+    }
+
+    /// Writes the same hex rendering as [`Debug`](core::fmt::Debug) into the given writer,
+    /// with `width` bytes per line (or all in one line if `width` is `0`), followed by a new line.
+    /// 
+    /// # ERRORS
+    /// Will return any error the writer returns.
+    #[inline]
+    pub fn write_hexdump_to(&self, writer: &mut impl crate::std::io::Write, width: usize) -> crate::std::io::Result<()> {
+        writeln!(writer, "{:width$?}", self, width = width)
+    }
 }
 
 impl Default for &DataSlice {
@@ -820,7 +1452,7 @@ impl From<Vec<u8>> for Box<DataSlice> {
 #[cfg(not(feature = "allocator_api"))]
 impl From<DataBoxed> for Box<DataSlice> {
     #[inline] fn from(boxed: DataBoxed) -> Box<DataSlice> {
-        DataSlice::from_boxed_slice(boxed.inner)
+        DataSlice::from_boxed_slice(boxed.into_inner())
     }
 }
 
@@ -828,7 +1460,7 @@ impl From<DataBoxed> for Box<DataSlice> {
 #[cfg(feature = "allocator_api")]
 impl<A: Allocator> From<DataBoxed<A>> for Box<DataSlice, A> {
     #[inline] fn from(boxed: DataBoxed<A>) -> Box<DataSlice, A> {
-        DataSlice::from_boxed_slice(boxed.inner)
+        DataSlice::from_boxed_slice(boxed.into_inner())
     }
 }
 
@@ -836,7 +1468,7 @@ impl<A: Allocator> From<DataBoxed<A>> for Box<DataSlice, A> {
 #[cfg(not(feature = "allocator_api"))]
 impl From<DataBoxed> for Arc<DataSlice> {
     #[inline] fn from(boxed: DataBoxed) -> Arc<DataSlice> {
-        DataSlice::from_boxed_slice(boxed.inner).into()
+        DataSlice::from_boxed_slice(boxed.into_inner()).into()
     }
 }
 
@@ -844,7 +1476,7 @@ impl From<DataBoxed> for Arc<DataSlice> {
 #[cfg(feature = "allocator_api")]
 impl<A: Allocator> From<DataBoxed<A>> for Arc<DataSlice, A> {
     #[inline] fn from(boxed: DataBoxed<A>) -> Arc<DataSlice, A> {
-        DataSlice::from_boxed_slice(boxed.inner).into()
+        DataSlice::from_boxed_slice(boxed.into_inner()).into()
     }
 }
 
@@ -868,7 +1500,7 @@ impl<A: Allocator> From<DataBoxed<A>> for Arc<DataSlice, A> {
 #[cfg(feature = "allocator_api")]
 impl<A: Allocator> From<DataBoxed<A>> for Rc<DataSlice, A> {
     #[inline] fn from(boxed: DataBoxed<A>) -> Rc<DataSlice, A> {
-        DataSlice::from_boxed_slice(boxed.inner).into()
+        DataSlice::from_boxed_slice(boxed.into_inner()).into()
     }
 }
 
@@ -928,75 +1560,246 @@ impl<const SIZE: usize> From<DataArray<SIZE>> for Rc<DataSlice> {
     }
 }
 
+/// Get's the character shown for the given byte in the ASCII columns, `.` if it is not printable.
+#[inline]
+fn printable(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for DataSlice {
+    #[inline] fn zeroize(&mut self) {
+        self.secure_erase()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::Fill for DataSlice {
+    #[inline]
+    fn fill<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        rng.fill_bytes(&mut self.inner);
+
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr(), self.size());
+    }
+}
+
+/// Renders the bytes in hex separated by spaces (eg: `0A 1B 2C`).
+/// 
+/// - The width sets the amount of bytes per line (all in one line if not given),
+///   and the alignment sets where the bytes of the last line go.
+/// - The precision groups the bytes of each line, putting an extra space between the groups.
+/// - The `+` flag adds a leading column with the offset of the first byte of each line.
+/// - The `#` flag (`{:#?}`) adds a trailing column with the bytes as ASCII
+///   (with `.` for the bytes that are not printable).
+/// 
+/// ```text
+/// {:+8.4?}
+/// 00000000: 01 02 03 04  05 06 07 08
+/// 00000008: 09 0A
+/// ```
 impl core::fmt::Debug for DataSlice {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use core::fmt::Write;
 
-        match f.width() {
-            None | Some(0) => {
-                let mut iter = self.inner.iter().copied();
-                
-                if let Some(byte) = iter.next() {
-                    write!(f, "{:02X}", byte)?;
+        let width: usize = match f.width() {
+            None | Some(0) => self.size().max(1),
+            Some(width) => width,
+        };
+        let group: usize = match f.precision() {
+            None | Some(0) => usize::MAX,
+            Some(group) => group,
+        };
+
+        let lines: usize = self.size().div_ceil(width);
+
+        for (line, chunk) in self.inner.chunks(width).enumerate() {
+            if line != 0 {
+                f.write_char('\n')?;
+            }
+
+            if f.sign_plus() {
+                write!(f, "{:08X}: ", line * width)?;
+            }
+
+            use core::fmt::Alignment::*;
+
+            // Only the last line can be shorter then the width.
+            let padding: usize = match f.align() {
+                _ if line + 1 != lines => 0,
+                None | Some(Left) => 0,
+                Some(Center) => (width - chunk.len()) / 2,
+                Some(Right) => width - chunk.len(),
+            };
+
+            for slot in 0..padding + chunk.len() {
+                if slot != 0 {
+                    f.write_str(if slot % group == 0 { "  " } else { " " })?;
                 }
-                
-                for byte in iter {
-                    write!(f, " {:02X}", byte)?;
+
+                match slot.checked_sub(padding) {
+                    Some(at) => write!(f, "{:02X}", chunk[at])?,
+                    None => f.write_str("  ")?,
                 }
             }
-            Some(width) => {
-                let mut iter = self.inner.chunks(width).map(|window| window.iter().copied());
-                
-                let mut last = match iter.next_back() {
-                    Some(last) => last,
-                    None => return Ok(()),
-                };
-
-                if let Some(mut first) = iter.next() {
-                    write!(f, "{:02X}", unsafe {
-                        // SAFETY: width can not be 0
-                        first.next().unwrap_unchecked()
-                    })?;
-
-                    for byte in first {
-                        write!(f, " {:02X}", byte)?;
-                    }
+
+            if f.alternate() {
+                for slot in padding + chunk.len()..width {
+                    f.write_str(if slot % group == 0 { "    " } else { "   " })?;
                 }
 
-                for mut chunk in iter {
-                    write!(f, "\n{:02X}", unsafe {
-                        // SAFETY: width can not be 0
-                        chunk.next().unwrap_unchecked()
-                    })?;
-                    
-                    for byte in chunk {
-                        write!(f, " {:02X}", byte)?;
-                    }
+                f.write_str(" | ")?;
+
+                for _ in 0..padding {
+                    f.write_char(' ')?;
                 }
+                for byte in chunk.iter().copied() {
+                    f.write_char(printable(byte))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
 
-                use core::fmt::Alignment::*;
-                
-                let padding: usize = match f.align().unwrap_or(Left) {
-                    Left => 0,
-                    Center => (width - self.size() % width) / 2,
-                    Right => width - self.size() % width,
-                };
+/// Renders the bytes as contiguous lowercase hex (eg: `0a1b2c`), with `#` adding a `0x` prefix.
+impl core::fmt::LowerHex for DataSlice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.inner.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders the bytes as contiguous uppercase hex (eg: `0A1B2C`), with `#` adding a `0x` prefix.
+impl core::fmt::UpperHex for DataSlice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.inner.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders each byte as it's 8 bits (most significant first) separated by spaces,
+/// the width setting the amount of bytes per line (all in one line if not given):
+/// 
+/// ```text
+/// 00001010 00011011
+/// 00101100
+/// ```
+impl core::fmt::Binary for DataSlice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        let width: usize = match f.width() {
+            None | Some(0) => usize::MAX,
+            Some(width) => width,
+        };
+
+        for (at, byte) in self.inner.iter().enumerate() {
+            if at != 0 {
+                f.write_char(if at % width == 0 { '\n' } else { ' ' })?;
+            }
+
+            write!(f, "{:08b}", byte)?;
+        }
 
+        Ok(())
+    }
+}
+
+/// Renders the bytes in hex separated by spaces (eg: `0A 1B 2C`), like [`Debug`](core::fmt::Debug) without any flags.
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for DataSlice {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        for (at, byte) in self.inner.iter().copied().enumerate() {
+            let digits: [u8; 3] = [
+                b' ',
+                crate::hex::DIGITS[(byte >> 4) as usize].to_ascii_uppercase(),
+                crate::hex::DIGITS[(byte & 0xF) as usize].to_ascii_uppercase(),
+            ];
+
+            // SAFETY: Hex digits and spaces are ASCII.
+            f.write_str(unsafe { core::str::from_utf8_unchecked(&digits[(at == 0) as usize..]) })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders the bytes as contiguous lowercase hex (eg: `0a1b2c`), like [`LowerHex`](core::fmt::LowerHex) without any flags.
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for DataSlice {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        for byte in self.inner.iter().copied() {
+            let digits: [u8; 2] = [
+                crate::hex::DIGITS[(byte >> 4) as usize],
+                crate::hex::DIGITS[(byte & 0xF) as usize],
+            ];
+
+            // SAFETY: Hex digits are ASCII.
+            f.write_str(unsafe { core::str::from_utf8_unchecked(&digits) })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders a classic hexdump, each line being the offset of it's first byte,
+/// the bytes in hex and the bytes as ASCII (with `.` for the bytes that are not printable):
+/// 
+/// ```text
+/// 00000000: 48 65 6C 6C 6F 2C 20 77 6F 72 6C 64 21 0A 00 01 | Hello, world!...
+/// 00000010: 02 03                                           | ..
+/// ```
+/// 
+/// The width sets the amount of bytes per line (`16` by default)
+/// and the precision sets the maximum amount of bytes shown.
+impl core::fmt::Display for DataSlice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        let width: usize = match f.width() {
+            None | Some(0) => 16,
+            Some(width) => width,
+        };
+        let shown: &[u8] = match f.precision() {
+            Some(precision) if precision < self.size() => &self.inner[..precision],
+            _ => &self.inner,
+        };
+
+        for (line, chunk) in shown.chunks(width).enumerate() {
+            if line != 0 {
                 f.write_char('\n')?;
+            }
 
-                for _ in 0..padding {
-                    f.write_str("   ")?;
-                }
+            write!(f, "{:08X}:", line * width)?;
 
-                write!(f, "{:02X}", unsafe {
-                    // SAFETY: last would not be given if it had 0 elements
-                    last.next().unwrap_unchecked()
-                })?;
-                
-                for byte in last {
-                    write!(f, " {:02X}", byte)?;
-                }
-            },
+            for byte in chunk {
+                write!(f, " {:02X}", byte)?;
+            }
+            for _ in chunk.len()..width {
+                f.write_str("   ")?;
+            }
+
+            f.write_str(" | ")?;
+
+            for byte in chunk.iter().copied() {
+                f.write_char(printable(byte))?;
+            }
         }
 
         Ok(())
@@ -1029,12 +1832,12 @@ unsafe impl crate::RawDataStructure for DataSlice {
 
     fn read_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
         if match idx.checked_add(size) {
-            Some(size) => size < self.size(),
+            Some(size) => size <= self.size(),
             None => false,
         } {
             Ok(())
         } else {
-            Err(idx::IdxError { idx, data_size: self.size(), type_size: size })
+            Err(idx::IdxError::new(idx, self.size(), size))
         }
     }
 
@@ -1044,21 +1847,33 @@ unsafe impl crate::RawDataStructure for DataSlice {
     }
 
     unsafe fn clone_from_unchecked(&mut self, data: &Self) {
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr(), self.size());
+
         self.inner.copy_from_slice( &data.inner )
     }
 
     #[inline]
     unsafe fn write_zeroes_unchecked(&mut self, idx: usize, size: usize) {
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr().wrapping_add(idx), size);
+
         self.write_zeroes_unchecked(idx, size)
     }
 
     #[inline]
     unsafe fn write_ones_unchecked(&mut self, idx: usize, size: usize) {
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr().wrapping_add(idx), size);
+
         self.write_ones_unchecked(idx, size)
     }
 
     #[inline]
     unsafe fn write_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) {
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr().wrapping_add(idx), core::mem::size_of_val(&*value));
+
         self.write_unsized_unchecked(idx, value)
     }
 
@@ -1069,6 +1884,10 @@ unsafe impl crate::RawDataStructure for DataSlice {
 
     #[inline]
     unsafe fn read_mut_unchecked<T: Sized>(&mut self, idx: usize) -> *mut T {
+        // The pointer may be written through, so the bytes count as written.
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr().wrapping_add(idx), core::mem::size_of::<T>());
+
         self.read_mut_unchecked(idx)
     }
 
@@ -1086,9 +1905,23 @@ unsafe impl crate::RawDataStructure for DataSlice {
 
     #[inline]
     unsafe fn take_unchecked<T: Sized>(&self, idx: usize) -> T {
+        #[cfg(feature = "init-tracking")]
+        crate::init::check_read(self.as_ptr().wrapping_add(idx), core::mem::size_of::<T>());
+
         self.take_unchecked(idx)
     }
 
+    #[inline]
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        #[cfg(feature = "init-tracking")]
+        {
+            crate::init::check_read(self.as_ptr().wrapping_add(idx), core::mem::size_of_val(&*value));
+            crate::init::mark_written(self.as_ptr().wrapping_add(idx), core::mem::size_of_val(&*value));
+        }
+
+        self.replace_unsized_unchecked(idx, value, out)
+    }
+
     type DataByte = u8;
 
     #[inline]
@@ -1098,6 +1931,9 @@ unsafe impl crate::RawDataStructure for DataSlice {
 
     #[inline]
     unsafe fn set_at_idx(&mut self, idx: usize, byte: u8) {
+        #[cfg(feature = "init-tracking")]
+        crate::init::mark_written(self.as_ptr().wrapping_add(idx), 1);
+
         self.inner[idx] = byte;
     }
 }
@@ -1105,27 +1941,13 @@ unsafe impl crate::RawDataStructure for DataSlice {
 impl crate::DataStructureSlice for DataSlice {
     #[inline]
     unsafe fn get_unchecked(&self, idx: impl idx::Idx) -> *const DataSlice {
-        use core::ops::Bound::*;
-
-        // included
-        let start: usize = match idx.start() {
-            Unbounded => 0,
-            Included(idx) => idx,
-            Excluded(idx) => idx.saturating_add(1),
-        };
-
-        // excluded
-        let end: usize = match idx.end() {
-            Unbounded => self.size(),
-            Included(idx) => idx.saturating_sub(1),
-            Excluded(idx) => idx,
-        };
+        let core::ops::Range { start, end } = idx::resolve_unchecked(&idx, self.size());
 
         DataSlice::from_slice(
             unsafe {
                 core::slice::from_raw_parts(
-                    (&self.inner as *const [u8]).cast::<u8>().add(start),
-                    end.saturating_sub(start),
+                    self.inner.as_ptr().add(start),
+                    end - start,
                 )
             }
         )
@@ -1133,27 +1955,13 @@ impl crate::DataStructureSlice for DataSlice {
 
     #[inline]
     unsafe fn get_mut_unchecked(&mut self, idx: impl idx::Idx) -> *mut DataSlice {
-        use core::ops::Bound::*;
-
-        // included
-        let start: usize = match idx.start() {
-            Unbounded => 0,
-            Included(idx) => idx,
-            Excluded(idx) => idx.saturating_add(1),
-        };
-
-        // excluded
-        let end: usize = match idx.end() {
-            Unbounded => self.size(),
-            Included(idx) => idx.saturating_sub(1),
-            Excluded(idx) => idx,
-        };
+        let core::ops::Range { start, end } = idx::resolve_unchecked(&idx, self.size());
 
         DataSlice::from_slice_mut(
             unsafe {
                 core::slice::from_raw_parts_mut(
-                    (&mut self.inner as *mut [u8]).cast::<u8>().add(start),
-                    end.saturating_sub(start),
+                    self.inner.as_mut_ptr().add(start),
+                    end - start,
                 )
             }
         )
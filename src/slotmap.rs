@@ -0,0 +1,267 @@
+
+/*!
+This module provides the [`DataSlotMap`] data structure and all it's associated
+functions, methods and items.
+
+A [`DataSlotMap`] stores values of a single type in the slots of any [`RawDataStructure`],
+and gives out a [`Key`] for every inserted value. Every slot has a generation counter that is
+bumped when it's value is removed, so a key of a removed value never finds the value that
+reused it's slot, it just finds nothing.
+
+Every slot holds the index of the next free slot, the generation and the value,
+so the map needs no memory other then the data structure it is given.
+A slot whose generation would overflow is never used again.
+
+Since a data structure that holds it's bytes inline (like a [`DataArray`](crate::array::DataArray))
+can move in memory, removing a value never needs it to be aligned in memory,
+but inserting a value or getting a refrence to it does.
+ */
+
+use crate::{
+    layout,
+    RawDataStructure,
+};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+
+/// The key of a value in a [`DataSlotMap`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    idx: usize,
+    generation: u32,
+}
+
+impl Key {
+    /// Get's the index of the slot of the value.
+    #[inline]
+    pub const fn idx(&self) -> usize {
+        self.idx
+    }
+
+    /// Get's the generation of the slot the key was given at.
+    #[inline]
+    pub const fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+const NONE: usize = usize::MAX;
+const NEXT: usize = 0;
+const GENERATION: usize = core::mem::size_of::<usize>();
+const HEADER: usize = GENERATION + core::mem::size_of::<u32>();
+
+/// A map of values of type `T` stored in the slots of a data structure,
+/// with keys that fail safely once their value is removed.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct DataSlotMap<T, D: RawDataStructure> {
+    data: D,
+    free: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T, D: RawDataStructure> DataSlotMap<T, D> {
+    const VALUE: usize = match layout::next_aligned(HEADER, core::mem::align_of::<T>()) {
+        Some(offset) => offset,
+        None => panic!("The slot of a `DataSlotMap` overflowed."),
+    };
+
+    const STRIDE: usize = match Self::VALUE.checked_add(core::mem::size_of::<T>()) {
+        Some(end) => match layout::next_aligned(end, core::mem::align_of::<T>()) {
+            Some(stride) => stride,
+            None => panic!("The slot of a `DataSlotMap` overflowed."),
+        },
+        None => panic!("The slot of a `DataSlotMap` overflowed."),
+    };
+
+    /// Constructs a new empty [`DataSlotMap`] with as many slots as fit in the given data structure.
+    /// 
+    /// # ERRORS
+    /// Will return the data structure back if it's start is not aligned for `T`.
+    pub fn new(mut data: D) -> Result<DataSlotMap<T, D>, D> {
+        if data.align_validity(0, core::mem::align_of::<T>()).is_err() {
+            return Err(data)
+        }
+
+        let capacity: usize = data.size() / Self::STRIDE;
+
+        for slot in 0..capacity {
+            let next: usize = if slot + 1 == capacity { NONE } else { slot + 1 };
+
+            // SAFETY: The headers of all the slots are inside of the data structure.
+            unsafe {
+                data.write_unaligned_value::<usize>(slot * Self::STRIDE + NEXT, next).ok();
+                data.write_unaligned_value::<u32>(slot * Self::STRIDE + GENERATION, 0).ok();
+            }
+        }
+
+        Ok(DataSlotMap {
+            data,
+            free: if capacity == 0 { NONE } else { 0 },
+            len: 0,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get's the amount of slots.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size() / Self::STRIDE
+    }
+
+    /// Get's the amount of values in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the map has no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn generation(&self, slot: usize) -> u32 {
+        // SAFETY: The header was written when the map was constructed.
+        unsafe { self.data.read_unaligned_value::<u32>(slot * Self::STRIDE + GENERATION) }.unwrap_or(0)
+    }
+
+    /// Checks weather the start of the data structure is still aligned for `T`, as it can move in memory.
+    #[inline]
+    fn is_aligned(&self) -> bool {
+        self.data.align_validity(0, core::mem::align_of::<T>()).is_ok()
+    }
+
+    #[inline]
+    fn is_live(&self, key: Key) -> bool {
+        key.idx < self.capacity() && key.generation % 2 == 1 && self.generation(key.idx) == key.generation
+    }
+
+    /// Inserts the value in a free slot and gives back it's key.
+    /// 
+    /// # ERRORS
+    /// Will return the value back if there are no free slots or if the data structure is not aligned in memory anymore.
+    pub fn insert(&mut self, value: T) -> Result<Key, T> {
+        if self.free == NONE || !self.is_aligned() {
+            return Err(value)
+        }
+
+        let slot: usize = self.free;
+        let generation: u32 = self.generation(slot) + 1;
+
+        // SAFETY: The slot is free and inside of the data structure, and the value is aligned
+        // since the start of the data structure was checked to be aligned and the stride is a multiple of the alignment.
+        unsafe {
+            self.free = self.data.read_unaligned_value::<usize>(slot * Self::STRIDE + NEXT).unwrap_or(NONE);
+            self.data.write_unaligned_value::<u32>(slot * Self::STRIDE + GENERATION, generation).ok();
+            self.data.write_unchecked::<T>(slot * Self::STRIDE + Self::VALUE, ManuallyDrop::new(value));
+        }
+
+        self.len += 1;
+        Ok(Key { idx: slot, generation })
+    }
+
+    /// Checks weather the key still has a value.
+    #[inline]
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.is_live(key)
+    }
+
+    /// Get's a refrence to the value of the key, or [None] if it was removed
+    /// or if the data structure is not aligned in memory anymore.
+    #[inline]
+    pub fn get(&self, key: Key) -> Option<&T> {
+        if !self.is_live(key) || !self.is_aligned() {
+            return None
+        }
+
+        // SAFETY: The slot holds a value, which is aligned since the start of the data structure was checked to be.
+        Some(unsafe { self.data.read_ref_unchecked::<T>(key.idx * Self::STRIDE + Self::VALUE) })
+    }
+
+    /// Get's a mutable refrence to the value of the key, or [None] if it was removed
+    /// or if the data structure is not aligned in memory anymore.
+    #[inline]
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        if !self.is_live(key) || !self.is_aligned() {
+            return None
+        }
+
+        // SAFETY: The slot holds a value, which is aligned since the start of the data structure was checked to be.
+        Some(unsafe { self.data.read_ref_mut_unchecked::<T>(key.idx * Self::STRIDE + Self::VALUE) })
+    }
+
+    /// Removes the value of the key and gives it back, or [None] if it was already removed.
+    /// 
+    /// All the keys of the value stop working, even once the slot is reused.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        if !self.is_live(key) {
+            return None
+        }
+
+        let slot: usize = key.idx;
+
+        // SAFETY: The slot holds a value and it's header is inside of the data structure.
+        unsafe {
+            let value: T = self.data.read_unchecked::<T>(slot * Self::STRIDE + Self::VALUE).read_unaligned();
+
+            self.data.write_unaligned_value::<u32>(slot * Self::STRIDE + GENERATION, key.generation.wrapping_add(1)).ok();
+
+            // A slot that would repeat generations is retired.
+            if key.generation != u32::MAX {
+                self.data.write_unaligned_value::<usize>(slot * Self::STRIDE + NEXT, self.free).ok();
+                self.free = slot;
+            }
+
+            self.len -= 1;
+            Some(value)
+        }
+    }
+
+    /// Removes all the values, all the keys stop working.
+    pub fn clear(&mut self) {
+        for idx in 0..self.capacity() {
+            let generation: u32 = self.generation(idx);
+            self.remove(Key { idx, generation });
+        }
+    }
+
+    /// Get's an iterator over the keys and the values in the map, in the order of their slots.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Key, &T)> + '_ {
+        (0..self.capacity()).filter_map(
+            #[inline] move |idx| {
+                let key: Key = Key { idx, generation: self.generation(idx) };
+                self.get(key).map(#[inline] |value| (key, value))
+            }
+        )
+    }
+
+    /// Drops all the values and gives back the underlying data structure.
+    #[inline]
+    pub fn into_inner(mut self) -> D {
+        self.clear();
+        let this: ManuallyDrop<DataSlotMap<T, D>> = ManuallyDrop::new(self);
+
+        // SAFETY: self is never used or dropped again.
+        unsafe { core::ptr::read(&this.data) }
+    }
+}
+
+impl<T, D: RawDataStructure> Drop for DataSlotMap<T, D> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
+
+impl<T: core::fmt::Debug, D: RawDataStructure> core::fmt::Debug for DataSlotMap<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
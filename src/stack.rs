@@ -0,0 +1,211 @@
+
+/*!
+This module provides the [`DataStack`] data structure and all it's associated
+functions, methods and items.
+
+A [`DataStack`] pushes values of any type one after another in any [`RawDataStructure`]
+and pops them back in the reverse order (LIFO), like the stack of an interpreter or a virtual machine.
+The values on the same stack do not need to be of the same type.
+
+Every value is aligned for it's type (relative to the start of the data structure) and followed
+by the offset the stack ended at before it was pushed and it's size, so the stack can be popped
+without knowing the types of the values under the top one, and popping it as a type of
+a diferent size is caught.
+
+Since a data structure that holds it's bytes inline (like a [`DataArray`](crate::array::DataArray))
+can move in memory, popping never needs the value to be aligned in memory,
+but getting a refrence to it does.
+
+The values left on the stack when it is dropped or cleared are never dropped.
+ */
+
+use crate::{
+    layout,
+    RawDataStructure,
+};
+use core::mem::ManuallyDrop;
+
+const WORD: usize = core::mem::size_of::<usize>();
+const TRAILER: usize = WORD * 2;
+
+/// A stack of values of any type inside of a data structure.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct DataStack<D: RawDataStructure> {
+    data: D,
+    top: usize,
+    len: usize,
+}
+
+impl<D: RawDataStructure> DataStack<D> {
+    /// Constructs a new empty [`DataStack`] in the given data structure.
+    #[inline]
+    pub fn new(data: D) -> DataStack<D> {
+        DataStack { data, top: 0, len: 0 }
+    }
+
+    /// Get's the amount of bytes the stack can use.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Get's the amount of bytes used by the values on the stack, padding and bookkeeping included.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.top
+    }
+
+    /// Get's the amount of bytes that are not used yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.size() - self.top
+    }
+
+    /// Get's the amount of values on the stack.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the stack has no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get's the offset the stack ended at before the top value, the offset of the top value and it's size.
+    #[inline]
+    fn entry(&self) -> Option<(usize, usize, usize)> {
+        if self.len == 0 {
+            return None
+        }
+
+        let trailer: usize = self.top - TRAILER;
+
+        // SAFETY: The trailer was written when the value was pushed.
+        unsafe {
+            let prev: usize = self.data.read_unaligned_value::<usize>(trailer).ok()?;
+            let size: usize = self.data.read_unaligned_value::<usize>(trailer + WORD).ok()?;
+
+            Some((prev, trailer.checked_sub(size)?, size))
+        }
+    }
+
+    /// Pushes the value on top of the stack.
+    /// 
+    /// # ERRORS
+    /// Will return the value back if it (with it's padding and bookkeeping) does not fit in the remaining bytes.
+    pub fn push_value<T>(&mut self, value: T) -> Result<(), T> {
+        let start: usize = match self.top.checked_add(layout::padding_needed(self.top, core::mem::align_of::<T>())) {
+            Some(start) => start,
+            None => return Err(value),
+        };
+
+        let end: usize = match start.checked_add(core::mem::size_of::<T>()) {
+            Some(end) if end.checked_add(TRAILER).is_some_and(#[inline] |top| top <= self.data.size()) => end,
+            _ => return Err(value),
+        };
+
+        // SAFETY: The value and the trailer are inside of the data structure.
+        unsafe {
+            self.data.read_mut_unchecked::<ManuallyDrop<T>>(start).write_unaligned(ManuallyDrop::new(value));
+            self.data.write_unaligned_value::<usize>(end, self.top).ok();
+            self.data.write_unaligned_value::<usize>(end + WORD, core::mem::size_of::<T>()).ok();
+        }
+
+        self.top = end + TRAILER;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Get's the size of the value on top of the stack, or [None] if the stack is empty.
+    #[inline]
+    pub fn peek_size(&self) -> Option<usize> {
+        self.entry().map(#[inline] |(_, _, size)| size)
+    }
+
+    /// Get's a refrence to the value on top of the stack, or [None] if the stack is empty,
+    /// the value is not of the size of `T` or it is not aligned in memory.
+    /// 
+    /// # SAFETY
+    /// - Make sure the value on top of the stack is a valid T
+    pub unsafe fn peek_ref<T>(&self) -> Option<&T> {
+        match self.entry() {
+            Some((_, start, size)) if size == core::mem::size_of::<T>() && self.data.align_validity(start, core::mem::align_of::<T>()).is_ok() => {
+                Some(self.data.read_ref_unchecked::<T>(start))
+            },
+            _ => None,
+        }
+    }
+
+    /// Get's a mutable refrence to the value on top of the stack, or [None] if the stack is empty,
+    /// the value is not of the size of `T` or it is not aligned in memory.
+    /// 
+    /// # SAFETY
+    /// - Make sure the value on top of the stack is a valid T
+    pub unsafe fn peek_mut<T>(&mut self) -> Option<&mut T> {
+        match self.entry() {
+            Some((_, start, size)) if size == core::mem::size_of::<T>() && self.data.align_validity(start, core::mem::align_of::<T>()).is_ok() => {
+                Some(self.data.read_ref_mut_unchecked::<T>(start))
+            },
+            _ => None,
+        }
+    }
+
+    /// Pops the value on top of the stack and gives it back,
+    /// or [None] if the stack is empty or the value is not of the size of `T` (leaving it on the stack).
+    /// 
+    /// # SAFETY
+    /// - Make sure the value on top of the stack is a valid T
+    pub unsafe fn pop_value<T>(&mut self) -> Option<T> {
+        match self.entry() {
+            Some((prev, start, size)) if size == core::mem::size_of::<T>() => {
+                let value: T = self.data.read_unchecked::<T>(start).read_unaligned();
+
+                self.top = prev;
+                self.len -= 1;
+                Some(value)
+            },
+            _ => None,
+        }
+    }
+
+    /// Pops the value on top of the stack without reading or dropping it.
+    /// 
+    /// Will return false if the stack is empty.
+    pub fn discard(&mut self) -> bool {
+        match self.entry() {
+            Some((prev, _, _)) => {
+                self.top = prev;
+                self.len -= 1;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Pops all the values without dropping them.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.top = 0;
+        self.len = 0;
+    }
+
+    /// Gives back the underlying data structure, without dropping the values on the stack.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+impl<D: RawDataStructure> core::fmt::Debug for DataStack<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataStack")
+            .field("len", &self.len)
+            .field("used", &self.top)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
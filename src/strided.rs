@@ -0,0 +1,141 @@
+
+/*!
+This module provides the [`StridedView`] data structure and all it's associated
+functions, methods and items.
+
+A [`StridedView`] is a view of regions of the same size that are evenly spaced in a
+single [`DataSlice`], as described by a [`Strided`] index, for interleaved data
+such as the samples of one audio channel or one field of an array of structs.
+
+It is made with [`get_strided`](crate::DataStructureSlice::get_strided).
+ */
+
+use crate::idx::{
+    self,
+    Strided,
+};
+use crate::slice::DataSlice;
+
+/// A view of evenly spaced regions of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[derive(Clone, Copy)]
+pub struct StridedView<'data> {
+    data: &'data DataSlice,
+    strided: Strided,
+    size: usize,
+}
+
+impl<'data> StridedView<'data> {
+    /// Constructs a new view of the regions of `size` bytes described by `strided`.
+    /// 
+    /// # ERRORS
+    /// Will return an error if any of the regions gets out of the data.
+    pub fn new(data: &'data DataSlice, strided: Strided, size: usize) -> Result<StridedView<'data>, idx::IdxError> {
+        strided.resolve(size, data.size())?;
+
+        Ok(StridedView { data, strided, size })
+    }
+
+    /// Get's the amount of regions in the view.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.strided.count
+    }
+
+    /// Checks weather the view has no regions.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.strided.count == 0
+    }
+
+    /// Get's the size of each region.
+    #[inline]
+    pub const fn region_size(&self) -> usize {
+        self.size
+    }
+
+    /// Get's the [`Strided`] index that describes the regions.
+    #[inline]
+    pub const fn strided(&self) -> Strided {
+        self.strided
+    }
+
+    /// Get's the region `n`, or [None] if `n` is not smaller then the amount of regions.
+    #[inline]
+    pub fn get(&self, n: usize) -> Option<&'data DataSlice> {
+        let start: usize = self.strided.idx(n)?;
+
+        // All the regions were checked when the view was constructed.
+        Some(DataSlice::from_slice(&self.data.inner[start..start + self.size]))
+    }
+
+    /// Get's an iterator over the regions.
+    #[inline]
+    pub fn iter(&self) -> StridedIter<'data> {
+        StridedIter { view: *self, front: 0, back: self.strided.count }
+    }
+}
+
+impl<'data> IntoIterator for StridedView<'data> {
+    type Item = &'data DataSlice;
+    type IntoIter = StridedIter<'data>;
+
+    #[inline] fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'data> IntoIterator for &StridedView<'data> {
+    type Item = &'data DataSlice;
+    type IntoIter = StridedIter<'data>;
+
+    #[inline] fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl core::fmt::Debug for StridedView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the regions of a [`StridedView`].
+#[derive(Debug, Clone)]
+pub struct StridedIter<'data> {
+    view: StridedView<'data>,
+    front: usize,
+    back: usize,
+}
+
+impl<'data> Iterator for StridedIter<'data> {
+    type Item = &'data DataSlice;
+
+    #[inline] fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None
+        }
+
+        self.front += 1;
+        self.view.get(self.front - 1)
+    }
+
+    #[inline] fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl DoubleEndedIterator for StridedIter<'_> {
+    #[inline] fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None
+        }
+
+        self.back -= 1;
+        self.view.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for StridedIter<'_> {}
+impl core::iter::FusedIterator for StridedIter<'_> {}
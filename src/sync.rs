@@ -0,0 +1,289 @@
+
+/*!
+This module provides data structures made to be shared between threads.
+
+The simplest way to share a data structure is to put it inside of a [`Mutex`](crate::std::sync::Mutex),
+but then every access locks the whole buffer, even if two threads work on parts of it that
+have nothing to do with each other.
+
+[`DataMutexed`] splits the buffer into a number of stripes each with it's own lock,
+so only the stripes that cover the accessed region are locked.
+
+[`DataLog`] is an append only log that any amount of threads can write records to
+at the same time without locking, while others read the records that were finished.
+ */
+
+use crate::{
+    idx,
+    boxed::DataBoxed,
+    slice::DataSlice,
+};
+use crate::alloc::boxed::Box;
+use crate::std::sync::{
+    Mutex,
+    MutexGuard,
+};
+use core::cell::UnsafeCell;
+use core::sync::atomic::{
+    AtomicU32,
+    AtomicUsize,
+    Ordering,
+};
+
+/// An owned typeless chunk of data split into stripes that are locked separately.
+/// 
+/// Stripes are always locked from the first to the last, so regions that overlap
+/// can never deadlock each other. If a thread panics while holding a stripe the lock is
+/// not considered poisoned, since the bytes inside can not be left in an invalid state.
+pub struct DataMutexed {
+    data: Box<[UnsafeCell<u8>]>,
+    locks: Box<[Mutex<()>]>,
+    stripe_size: usize,
+}
+
+// SAFETY: The bytes are only ever accessed while holding the locks of all the stripes
+// they are in (or through `&mut self`), so no two threads can access the same byte at once.
+unsafe impl Sync for DataMutexed {}
+
+impl DataMutexed {
+    /// Constructs a new [`DataMutexed`] from the given data split into (at most) `stripes` stripes.
+    /// 
+    /// There is always at least one stripe and never more stripes then bytes.
+    pub fn new(data: DataBoxed, stripes: usize) -> DataMutexed {
+        let size: usize = data.size();
+        let stripe_size: usize = size.div_ceil(stripes.max(1)).max(1);
+        let stripes: usize = size.div_ceil(stripe_size).max(1);
+
+        DataMutexed {
+            data: unsafe {
+                // SAFETY: UnsafeCell<u8> has the same layout as u8.
+                Box::from_raw(Box::into_raw(data.into_inner()) as *mut [UnsafeCell<u8>])
+            },
+            locks: (0..stripes).map(|_| Mutex::new(())).collect(),
+            stripe_size,
+        }
+    }
+
+    /// Get's the size of the data structure.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Get's the amount of stripes the data structure is split into.
+    #[inline]
+    pub fn stripes(&self) -> usize {
+        self.locks.len()
+    }
+
+    /// Get's the amount of bytes in each stripe (the last one may be smaller).
+    #[inline]
+    pub fn stripe_size(&self) -> usize {
+        self.stripe_size
+    }
+
+    /// Locks all the stripes that cover the given range and calls `f` with that region.
+    /// 
+    /// An empty range does not lock anything.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the range gets out of bounds, in which case `f` is not called.
+    pub fn with_region<R>(&self, range: impl idx::Idx, f: impl FnOnce(&mut DataSlice) -> R) -> Result<R, idx::IdxError> {
+        let core::ops::Range { start, end } = range.resolve(self.size())?;
+
+        let stripes: core::ops::Range<usize> = if start == end {
+            0..0
+        } else {
+            start / self.stripe_size..(end - 1) / self.stripe_size + 1
+        };
+
+        let _guards: crate::alloc::vec::Vec<MutexGuard<'_, ()>> = self.locks[stripes]
+            .iter()
+            .map(#[inline] |lock| lock.lock().unwrap_or_else(#[inline] |poison| poison.into_inner()))
+            .collect();
+
+        let region: &mut DataSlice = DataSlice::from_slice_mut(unsafe {
+            // SAFETY: All the stripes covering the region are locked,
+            // so nothing else can access these bytes until the guards are dropped.
+            core::slice::from_raw_parts_mut(UnsafeCell::raw_get(self.data.as_ptr().add(start)), end - start)
+        });
+
+        Ok(f(region))
+    }
+
+    /// Get's the whole data structure without locking anything,
+    /// since the mutable borrow already guarantees no other thread can access it.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut DataSlice {
+        DataSlice::from_slice_mut(unsafe {
+            // SAFETY: The mutable borrow guarantees exclusive access.
+            core::slice::from_raw_parts_mut(UnsafeCell::raw_get(self.data.as_ptr()), self.data.len())
+        })
+    }
+
+    /// Gives back the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> DataBoxed {
+        DataBoxed {
+            inner: unsafe {
+                // SAFETY: UnsafeCell<u8> has the same layout as u8.
+                Box::from_raw(Box::into_raw(self.data) as *mut [u8])
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for DataMutexed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataMutexed")
+            .field("size", &self.size())
+            .field("stripes", &self.stripes())
+            .field("stripe_size", &self.stripe_size)
+            .finish()
+    }
+}
+
+/// The error given when a record does not fit in the remaining space of a [`DataLog`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogFullError {
+    /// The amount of bytes the record needed (with it's header and padding).
+    pub needed: usize,
+    /// The amount of bytes that were left in the log.
+    pub remaining: usize,
+}
+
+impl core::error::Error for LogFullError {}
+impl core::fmt::Display for LogFullError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Can not append a record that needs `{needed}` bytes to a log with only `{remaining}` bytes left.",
+            needed = self.needed,
+            remaining = self.remaining,
+        )
+    }
+}
+
+/// A lock free append only log of byte records.
+/// 
+/// Each record is stored as a 4 byte header followed by it's bytes, padded to a multiple of 4.
+/// Space is reserved by moving an atomic write head, then the bytes are written and at last
+/// the header is set, which is what commits the record. Readers only ever see the records
+/// up to the first one that is not committed yet.
+/// 
+/// To share it between threads put it in an [`Arc`](crate::alloc::sync::Arc) or use scoped threads.
+pub struct DataLog {
+    words: Box<[AtomicU32]>,
+    head: AtomicUsize,
+}
+
+impl DataLog {
+    /// Constructs a new empty [`DataLog`] with space for (at least) `capacity` bytes,
+    /// headers included.
+    pub fn new(capacity: usize) -> DataLog {
+        DataLog {
+            words: (0..capacity.div_ceil(4)).map(#[inline] |_| AtomicU32::new(0)).collect(),
+            head: AtomicUsize::new(0),
+        }
+    }
+
+    /// Get's the amount of bytes the log can hold, headers included.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.words.len() * 4
+    }
+
+    /// Get's the amount of bytes reserved so far, headers included.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.head.load(Ordering::Relaxed)
+    }
+
+    /// Appends a record with the given bytes.
+    /// 
+    /// Returns the offset of the record inside of the log.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the record does not fit in the remaining space,
+    /// in which case no space is used up.
+    pub fn append(&self, bytes: &[u8]) -> Result<usize, LogFullError> {
+        let needed: usize = bytes.len().div_ceil(4).saturating_add(1).saturating_mul(4);
+
+        let pos: usize = self.head.fetch_update(Ordering::Relaxed, Ordering::Relaxed, #[inline] |head| {
+            match head.checked_add(needed) {
+                Some(end) if end <= self.capacity() && bytes.len() < u32::MAX as usize => Some(end),
+                _ => None,
+            }
+        }).map_err(|head| LogFullError { needed, remaining: self.capacity() - head })?;
+
+        unsafe {
+            // SAFETY: The space was reserved by this call only, and readers
+            // do not look at it until the header is set. The bytes are inside of
+            // atomics (so an UnsafeCell) so writing through a shared refrence is fine.
+            core::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                self.words.as_ptr().cast::<u8>().add(pos + 4).cast_mut(),
+                bytes.len(),
+            );
+        }
+
+        // The length is stored plus one so that `0` always means not committed.
+        self.words[pos / 4].store(bytes.len() as u32 + 1, Ordering::Release);
+
+        Ok(pos)
+    }
+
+    /// Get's an iterator over all the committed records, in the order their space was reserved.
+    #[inline]
+    pub fn iter(&self) -> DataLogIter<'_> {
+        DataLogIter { log: self, pos: 0 }
+    }
+
+    /// Removes all the records, this can only be done when no other thread uses the log.
+    pub fn clear(&mut self) {
+        for word in self.words.iter_mut() {
+            *word.get_mut() = 0;
+        }
+
+        *self.head.get_mut() = 0;
+    }
+}
+
+impl core::fmt::Debug for DataLog {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataLog")
+            .field("used", &self.used())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// An iterator over the committed records of a [`DataLog`].
+#[derive(Debug, Clone)]
+pub struct DataLogIter<'log> {
+    log: &'log DataLog,
+    pos: usize,
+}
+
+impl<'log> Iterator for DataLogIter<'log> {
+    type Item = &'log DataSlice;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header: u32 = self.log.words.get(self.pos / 4)?.load(Ordering::Acquire);
+
+        if header == 0 {
+            return None
+        }
+
+        let len: usize = header as usize - 1;
+        let record: &DataSlice = DataSlice::from_slice(unsafe {
+            // SAFETY: The header was set with release ordering after the bytes were written
+            // and they are never written again while the log is shared.
+            core::slice::from_raw_parts(self.log.words.as_ptr().cast::<u8>().add(self.pos + 4), len)
+        });
+
+        self.pos += (len.div_ceil(4) + 1) * 4;
+        Some(record)
+    }
+}
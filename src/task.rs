@@ -0,0 +1,191 @@
+
+/*!
+This module provides the [`TaskQueue`] data structure and all it's associated
+functions, methods and items.
+
+A [`TaskQueue`] stores closures (of any type) one after another in a [`DataSlice`] and runs them later
+in the order they were pushed, without a heap allocation for every task, like the deferred work
+a thread pool or an event loop collects.
+
+Every task is kept as a thunk (a function made for the type of the closure that either runs it
+or drops it), the size of the closure and the bytes of the closure. Since the closure is moved
+out before it is run or dropped it does not need to be aligned in the data.
+
+The tasks left in the queue when it is dropped or cleared are dropped without being run.
+The space of the tasks that ran is reused once the queue is empty.
+
+The bytes of a task (which can have uninitialized padding) are zeroed once it is taken out of the queue,
+so the data given back by [`into_inner`](TaskQueue::into_inner) (or borrowed again) is all initialized.
+ */
+
+use crate::slice::DataSlice;
+use core::ops::DerefMut;
+
+/// The function that runs (or only drops) the closure at the given pointer.
+type Thunk = unsafe fn(*mut u8, bool);
+
+const WORD: usize = core::mem::size_of::<usize>();
+const HEADER: usize = core::mem::size_of::<Thunk>() + WORD;
+
+/// Moves the closure out of the pointer, zeroes it's bytes and runs it, or only drops it.
+/// 
+/// # SAFETY
+/// - Make sure the pointer points to a valid F that is not used again
+unsafe fn thunk<F: FnOnce()>(ptr: *mut u8, run: bool) {
+    let task: F = ptr.cast::<F>().read_unaligned();
+    ptr.write_bytes(0, core::mem::size_of::<F>());
+
+    if run {
+        task();
+    }
+}
+
+/// A queue of closures of any type inside of a [`DataSlice`].
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct TaskQueue<D: DerefMut<Target = DataSlice>> {
+    data: D,
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+impl<D: DerefMut<Target = DataSlice>> TaskQueue<D> {
+    /// Constructs a new empty [`TaskQueue`] in the given data.
+    #[inline]
+    pub fn new(data: D) -> TaskQueue<D> {
+        TaskQueue { data, head: 0, tail: 0, len: 0 }
+    }
+
+    /// Get's the amount of bytes the queue can use.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Get's the amount of bytes that can still be used before the queue is empty again.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.size() - self.tail
+    }
+
+    /// Get's the amount of tasks in the queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks weather the queue has no tasks.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Pushes the closure to the back of the queue.
+    /// 
+    /// # ERRORS
+    /// Will return the closure back if it (with it's thunk and size) does not fit in the remaining bytes.
+    pub fn push<F: FnOnce() + Send + 'static>(&mut self, task: F) -> Result<(), F> {
+        let size: usize = core::mem::size_of::<F>();
+
+        let end: usize = match (HEADER + size).checked_add(self.tail) {
+            Some(end) if end <= self.data.size() => end,
+            _ => return Err(task),
+        };
+
+        let at: *mut u8 = self.data.inner[self.tail..end].as_mut_ptr();
+
+        // SAFETY: The thunk, the size and the closure fit in the data.
+        unsafe {
+            at.cast::<Thunk>().write_unaligned(thunk::<F> as Thunk);
+            at.add(core::mem::size_of::<Thunk>()).cast::<usize>().write_unaligned(size);
+            at.add(HEADER).cast::<F>().write_unaligned(task);
+        }
+
+        self.tail = end;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Takes the task at the front of the queue out of it and runs it or drops it.
+    /// 
+    /// Will return false if the queue is empty.
+    fn pop(&mut self, run: bool) -> bool {
+        if self.len == 0 {
+            return false
+        }
+
+        let at: *mut u8 = self.data.inner[self.head..self.tail].as_mut_ptr();
+
+        // SAFETY: The task was written by push, and is taken out of the queue before it is run,
+        // so a task that panics is never run or dropped again.
+        unsafe {
+            let task: Thunk = at.cast::<Thunk>().read_unaligned();
+            let size: usize = at.add(core::mem::size_of::<Thunk>()).cast::<usize>().read_unaligned();
+            at.write_bytes(0, HEADER);
+
+            self.head += HEADER + size;
+            self.len -= 1;
+            if self.len == 0 {
+                self.head = 0;
+                self.tail = 0;
+            }
+
+            task(at.add(HEADER), run);
+        }
+
+        true
+    }
+
+    /// Runs the task at the front of the queue.
+    /// 
+    /// Will return false if the queue is empty.
+    #[inline]
+    pub fn run_one(&mut self) -> bool {
+        self.pop(true)
+    }
+
+    /// Runs all the tasks in the queue, in the order they were pushed, and gives back the amount of tasks ran.
+    /// 
+    /// If a task panics, the tasks after it are left in the queue.
+    pub fn drain(&mut self) -> usize {
+        let mut ran: usize = 0;
+
+        while self.pop(true) {
+            ran += 1;
+        }
+
+        ran
+    }
+
+    /// Drops all the tasks in the queue without running them.
+    pub fn clear(&mut self) {
+        while self.pop(false) {}
+    }
+
+    /// Gives back the underlying data, dropping the tasks in the queue without running them.
+    pub fn into_inner(self) -> D {
+        let mut this: core::mem::ManuallyDrop<TaskQueue<D>> = core::mem::ManuallyDrop::new(self);
+        this.clear();
+
+        // SAFETY: The queue is never used again and all of it's tasks were dropped.
+        unsafe { core::ptr::read(&this.data) }
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> Drop for TaskQueue<D> {
+    #[inline] fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<D: DerefMut<Target = DataSlice>> core::fmt::Debug for TaskQueue<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TaskQueue")
+            .field("len", &self.len)
+            .field("remaining", &self.remaining())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
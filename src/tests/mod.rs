@@ -44,3 +44,2473 @@ fn ownership() {
 
     // let moved = value;
 }
+
+#[test]
+fn replace_unsized() {
+    let mut data: DataArray<16> = DataArray::zeroed();
+    let mut out: [u8; 4] = [0xFF; 4];
+
+    unsafe {
+        data.write(0, ManuallyDrop::new([1u8, 2, 3, 4])).unwrap();
+        let new: &ManuallyDrop<[u8]> = &ManuallyDrop::new([5u8, 6, 7, 8]);
+        data.replace_unsized(0, new as *const ManuallyDrop<[u8]>, &mut out as *mut [u8]).unwrap();
+
+        assert_eq!(out, [1, 2, 3, 4]);
+        assert_eq!(data.take::<[u8; 4]>(0).unwrap(), [5, 6, 7, 8]);
+    }
+}
+
+#[test]
+fn copy_from() {
+    let mut array: DataArray<8> = DataArray::zeroed();
+    let boxed: DataBoxed = DataBoxed::filled(8, 0xAB).unwrap();
+
+    unsafe {
+        RawDataStructure::copy_from(&mut array, &boxed).unwrap();
+        assert!(array.iter().all(|byte| byte == 0xAB));
+
+        assert_eq!(RawDataStructure::copy_from(&mut array, &DataBoxed::zeroed(4).unwrap()), Err((8, 4)));
+
+        let small: DataBoxed = DataBoxed::filled(4, 0x01).unwrap();
+        RawDataStructure::copy_range_from(&mut array, 2, &small, 1, 2).unwrap();
+        assert_eq!(array.take::<[u8; 4]>(1).unwrap(), [0xAB, 0x01, 0x01, 0xAB]);
+    }
+}
+
+#[test]
+fn cursor() {
+    use cursor::DataCursor;
+
+    let mut data: DataArray<16> = DataArray::zeroed();
+    let mut cursor = DataCursor::new(&mut data);
+
+    unsafe {
+        cursor.write_value(ManuallyDrop::new(0xAAu8)).unwrap();
+        cursor.align_to(4).unwrap();
+        cursor.write_value(ManuallyDrop::new(0x12345678u32)).unwrap();
+        assert_eq!(cursor.position(), 8);
+        assert!(cursor.skip(9).is_err());
+        assert_eq!(cursor.position(), 8);
+        assert_eq!(cursor.claim(2), Ok(8));
+        assert_eq!(cursor.written().size(), 10);
+        assert!(cursor.claim(7).is_err());
+
+        cursor.set_position(0).unwrap();
+        assert_eq!(cursor.read_value::<u8>().unwrap(), 0xAA);
+        cursor.align_to(4).unwrap();
+        assert_eq!(cursor.read_value::<u32>().unwrap(), 0x12345678);
+        assert_eq!(cursor.remaining(), 8);
+    }
+}
+
+#[test]
+fn bit_cursors() {
+    use cursor::{BitReader, BitWriter};
+
+    let mut data: DataArray<4> = DataArray::zeroed();
+    let mut writer = BitWriter::new(&mut data);
+
+    writer.write_bits(0b101, 3).unwrap();
+    writer.write_bits(0x1FF, 9).unwrap();
+    writer.align_to_byte();
+    writer.write_bit(true).unwrap();
+    assert!(writer.write_bits(0, 16).is_err());
+
+    assert_eq!(data.inner, [0b1011_1111, 0b1111_0000, 0b1000_0000, 0]);
+
+    let mut reader = BitReader::new(&data);
+    assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+    assert_eq!(reader.peek_bits(9).unwrap(), 0x1FF);
+    reader.skip_bits(9).unwrap();
+    reader.align_to_byte();
+    assert!(reader.read_bit().unwrap());
+    assert_eq!(reader.remaining(), 15);
+}
+
+#[test]
+fn bit_slice_view() {
+    let mut data: DataArray<4> = DataArray::zeroed();
+    let bits = data.bits_mut();
+
+    bits.set_bit(3, true).unwrap();
+    bits.set_range(6..=17, true).unwrap();
+    assert!(bits.set_bit(32, true).is_err());
+
+    assert_eq!(bits.get_bit(3), Some(true));
+    assert_eq!(bits.get_bit(4), Some(false));
+    assert_eq!(bits.get_bit(32), None);
+    assert_eq!(bits.count_ones(), 13);
+    assert_eq!(bits.rank(8), 3);
+    assert_eq!(bits.select(0), Some(3));
+    assert_eq!(bits.select(12), Some(17));
+    assert_eq!(bits.select(13), None);
+    assert_eq!(data.inner, [0b1100_1000, 0xFF, 0b0000_0011, 0]);
+}
+
+#[test]
+fn varint() {
+    use varint::VarintError;
+
+    let mut data: DataArray<16> = DataArray::zeroed();
+
+    assert_eq!(data.write_varint_u64(0, 300), Ok(2));
+    assert_eq!(data.inner[..2], [0xAC, 0x02]);
+    assert_eq!(data.read_varint_u64(0), Ok((300, 2)));
+
+    assert_eq!(data.write_varint_u64(2, u64::MAX), Ok(10));
+    assert_eq!(data.read_varint_u64(2), Ok((u64::MAX, 10)));
+    assert!(data.write_varint_u64(12, u64::MAX).is_err());
+
+    assert_eq!(data.write_varint_i64(12, -2), Ok(1));
+    assert_eq!(data.read_varint_i64(12), Ok((-2, 1)));
+
+    data.inner = [0xFF; 16];
+    assert_eq!(data.read_varint_u64(0), Err(VarintError::Overflow));
+    assert!(matches!(data.read_varint_u64(8), Err(VarintError::OutOfBounds(_))));
+}
+
+#[test]
+fn tlv() {
+    use tlv::{TlvReader, TlvWriter};
+
+    let mut data: DataArray<32> = DataArray::zeroed();
+    let mut writer: TlvWriter<'_> = TlvWriter::new(&mut data);
+
+    assert!(writer.push(1, DataSlice::from_slice(&[1, 2, 3])).is_ok());
+    assert!(writer.push_nested(2, |nested| {
+        nested.push(3, DataSlice::from_slice(&[4]))?;
+        nested.push(4, DataSlice::from_slice(&[]))
+    }).is_ok());
+    assert!(writer.push(5, DataSlice::from_slice(&[0; 32])).is_err());
+    assert_eq!(writer.finish(), 12);
+
+    assert_eq!(data.inner[..12], [1, 3, 1, 2, 3, 2, 5, 3, 1, 4, 4, 0]);
+
+    let entries: std::vec::Vec<(u64, &DataSlice)> = TlvReader::new(data.get(..12).unwrap()).map(Result::unwrap).collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].0, 1);
+    assert_eq!(entries[0].1.inner, [1, 2, 3]);
+
+    let nested: std::vec::Vec<u64> = TlvReader::new(entries[1].1).map(|entry| entry.unwrap().0).collect();
+    assert_eq!(nested, [3, 4]);
+    assert_eq!(TlvReader::new(entries[1].1).find(3).map(|value| &value.inner), Some(&[4][..]));
+
+    let mut truncated: TlvReader<'_> = TlvReader::new(data.get(..4).unwrap());
+    assert!(matches!(truncated.next(), Some(Err(_))));
+    assert!(truncated.next().is_none());
+}
+
+#[test]
+fn packet_builder() {
+    use packet::PacketBuilder;
+
+    let mut frame: DataArray<12> = DataArray::zeroed();
+    let mut builder: PacketBuilder<'_> = PacketBuilder::new(&mut frame);
+
+    let length: usize = builder.put_u8(0x45).unwrap().reserve(2).unwrap();
+    builder.put_u32_be(0xDEAD_BEEF).unwrap().put_bytes(&[1, 2, 3]).unwrap();
+    assert!(builder.put_u32_be(0).is_err());
+    assert_eq!(builder.position(), 10);
+
+    let written: usize = builder.finish_with(|packet| {
+        let size: u16 = packet.size() as u16;
+        packet.inner[length..length + 2].copy_from_slice(&size.to_be_bytes());
+    });
+
+    assert_eq!(written, 10);
+    assert_eq!(frame.inner, [0x45, 0, 10, 0xDE, 0xAD, 0xBE, 0xEF, 1, 2, 3, 0, 0]);
+}
+
+#[test]
+fn fmt_writer() {
+    use core::fmt::Write;
+    use fmt::FmtWriter;
+
+    let mut data: DataArray<8> = DataArray::zeroed();
+    let mut writer: FmtWriter<'_> = FmtWriter::new(&mut data);
+
+    let name: &str = "ab";
+    assert!(write!(writer, "{}-{}", 12, name).is_ok());
+    assert_eq!(writer.as_str(), "12-ab");
+    assert!(write!(writer, "long").is_err());
+    assert_eq!(writer.finish(), 5);
+    assert_eq!(data.inner[..5], *b"12-ab");
+}
+
+#[test]
+fn io_traits() {
+    use std::io::{Read, Seek, SeekFrom, Write};
+    use cursor::DataCursor;
+    use vec::DataVec;
+
+    let mut data: DataArray<6> = DataArray::zeroed();
+    let mut cursor: DataCursor<'_> = DataCursor::new(&mut data);
+
+    assert_eq!(cursor.write(&[1, 2, 3, 4]).unwrap(), 4);
+    assert_eq!(cursor.write(&[5, 6, 7]).unwrap(), 2);
+    assert_eq!(cursor.seek(SeekFrom::End(-4)).unwrap(), 2);
+    assert!(cursor.seek(SeekFrom::Current(5)).is_err());
+
+    let mut buf: [u8; 8] = [0; 8];
+    assert_eq!(cursor.read(&mut buf).unwrap(), 4);
+    assert_eq!(buf[..4], [3, 4, 5, 6]);
+
+    let mut vec: DataVec = DataVec::new();
+    vec.write_all(&data.inner).unwrap();
+    write!(vec, "!").unwrap();
+    assert_eq!(vec.size(), 7);
+    assert_eq!(vec.inner, [1, 2, 3, 4, 5, 6, b'!']);
+    assert_eq!(vec.into_data_boxed().size(), 7);
+}
+
+#[test]
+#[cfg(feature = "embedded-io")]
+fn embedded_io_traits() {
+    use embedded_io::{ErrorKind, Read, Seek, SeekFrom, Write};
+    use cursor::DataCursor;
+
+    let mut data: DataArray<4> = DataArray::zeroed();
+    let mut cursor: DataCursor<'_> = DataCursor::new(&mut data);
+
+    assert_eq!(Write::write(&mut cursor, &[1, 2, 3, 4, 5]), Ok(4));
+    assert_eq!(Write::write(&mut cursor, &[6]), Err(ErrorKind::WriteZero));
+    assert_eq!(Seek::seek(&mut cursor, SeekFrom::Start(1)), Ok(1));
+    assert_eq!(Seek::seek(&mut cursor, SeekFrom::End(1)), Err(ErrorKind::InvalidInput));
+
+    let mut buf: [u8; 4] = [0; 4];
+    assert_eq!(Read::read(&mut cursor, &mut buf), Ok(3));
+    assert_eq!(buf[..3], [2, 3, 4]);
+}
+
+#[test]
+fn read_from() {
+    let mut data: DataArray<6> = DataArray::zeroed();
+    let mut source: &[u8] = &[1, 2, 3, 4];
+
+    assert_eq!(data.read_from(&mut source).unwrap(), 4);
+    assert_eq!(data.inner, [1, 2, 3, 4, 0, 0]);
+
+    let mut source: &[u8] = &[7, 8, 9];
+    assert!(data.read_exact_into(1..3, &mut source).is_ok());
+    assert_eq!(data.inner, [1, 7, 8, 4, 0, 0]);
+    assert_eq!(data.read_exact_into(1..3, &mut source).unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    assert_eq!(data.read_exact_into(4..9, &mut source).unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn write_to() {
+    let data: DataArray<4> = DataArray::filled(0xAB);
+    let mut out: std::vec::Vec<u8> = std::vec::Vec::new();
+
+    data.write_to(&mut out).unwrap();
+    data.write_range_to(..2, &mut out).unwrap();
+    assert_eq!(out, [0xAB; 6]);
+    assert!(data.write_range_to(2..6, &mut out).is_err());
+
+    out.clear();
+    data.write_hexdump_to(&mut out, 0).unwrap();
+    assert_eq!(out, b"AB AB AB AB\n");
+}
+
+#[test]
+#[cfg(all(feature = "shm", unix))]
+fn shm() {
+    use shm::DataShm;
+
+    let name: std::string::String = std::format!("/typeless-data-test-{}", std::process::id());
+
+    let mut first: DataShm = unsafe { DataShm::create(&name, 16) }.unwrap();
+    assert!(unsafe { DataShm::create(&name, 16) }.is_err());
+    assert!(unsafe { DataShm::open(&name, 32) }.is_err());
+
+    let second: DataShm = unsafe { DataShm::open(&name, 16) }.unwrap();
+    first.write_varint_u64(0, 300).unwrap();
+    assert_eq!(second.read_varint_u64(0), Ok((300, 2)));
+    assert_eq!(RawDataStructure::size(&second), 16);
+
+    DataShm::unlink(&name).unwrap();
+    assert!(DataShm::unlink(&name).is_err());
+}
+
+#[test]
+fn volatile_data() {
+    use volatile::VolatileData;
+
+    let mut registers: [u32; 4] = [0; 4];
+    let mut data: VolatileData = unsafe { VolatileData::from_raw_parts(registers.as_mut_ptr().cast::<u8>(), 16) };
+
+    unsafe {
+        assert!(data.volatile_write::<u32>(4, 0xABCD).is_ok());
+        assert!(data.volatile_write::<u32>(16, 0).is_err());
+        assert!(data.volatile_modify::<u32>(4, |value| value | 0x1_0000).is_ok());
+        assert_eq!(data.volatile_read::<u32>(4), Ok(0x1_ABCD));
+        assert!(data.volatile_read::<u32>(13).is_err());
+    }
+
+    assert!(data.write_byte(15, 7).is_ok());
+    assert_eq!(data.read_byte(16), None);
+
+    assert_eq!(registers[1], 0x1_ABCD);
+    assert_eq!(registers[3].to_ne_bytes()[3], 7);
+}
+
+#[test]
+fn volatile_trait_methods() {
+    let mut data: DataBoxed = DataBoxed::zeroed(16).unwrap();
+
+    unsafe {
+        assert!(RawDataStructure::write_volatile(&mut data, 8, ManuallyDrop::new(0x1234_u32)).is_ok());
+        assert_eq!(RawDataStructure::read_volatile::<u32>(&data, 8), Ok(0x1234));
+        assert!(RawDataStructure::write_volatile(&mut data, 16, ManuallyDrop::new(0_u32)).is_err());
+        assert_eq!(RawDataStructure::read_volatile_unchecked::<u8>(&data, 0), 0);
+    }
+}
+
+#[test]
+fn read_nonnull() {
+    let mut data: DataArray<16> = DataArray::zeroed();
+
+    unsafe {
+        data.read_mut_nonnull::<u32>(4).unwrap().write_unaligned(0x0403_0201);
+        assert_eq!(data.inner[4..8], 0x0403_0201_u32.to_ne_bytes());
+        assert_eq!(data.read_nonnull::<u32>(4).unwrap().read_unaligned(), 0x0403_0201);
+        assert_eq!(RawDataStructure::read_nonnull::<u32>(&data, 4).unwrap().read_unaligned(), 0x0403_0201);
+    }
+
+    assert!(data.read_nonnull::<u32>(14).is_err());
+}
+
+#[test]
+fn ptr_accessors() {
+    const SIZE: usize = {
+        let data: DataArray<4> = DataArray::from_array([1, 2, 3, 4]);
+        let range: core::ops::Range<*const u8> = data.ptr_range();
+        unsafe { range.end.offset_from(range.start) as usize }
+    };
+    assert_eq!(SIZE, 4);
+
+    let mut data: DataBoxed = DataBoxed::zeroed(4).unwrap();
+    unsafe { data.as_mut_ptr().add(3).write(7) };
+
+    assert_eq!(unsafe { *data.as_ptr().add(3) }, 7);
+    assert_eq!(data.ptr_range().start, data.as_ptr());
+}
+
+#[test]
+fn provenance() {
+    let mut data: DataArray<8> = DataArray::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let addr: usize = data.addr_of_idx(5);
+    assert_eq!(data.idx_of_addr(addr), Some(5));
+    assert_eq!(data.idx_of_addr(data.addr_of_idx(8)), None);
+    assert_eq!(unsafe { *data.with_addr(addr) }, 5);
+
+    unsafe { *data.with_addr_mut(addr) = 9 };
+    assert_eq!(data.inner[5], 9);
+}
+
+#[test]
+fn read_pin() {
+    struct Node {
+        value: u8,
+        _pin: core::marker::PhantomPinned,
+    }
+
+    let mut data: DataBoxed = DataBoxed::zeroed(4).unwrap();
+
+    unsafe {
+        let mut node: core::pin::Pin<&mut Node> = RawDataStructure::read_pin::<Node>(&mut data, 2).unwrap();
+        node.as_mut().get_unchecked_mut().value = 7;
+        assert_eq!(node.value, 7);
+
+        assert!(RawDataStructure::read_pin::<Node>(&mut data, 4).is_err());
+    }
+
+    let moved: DataBoxed = data;
+    assert_eq!(moved.inner[2], 7);
+}
+
+#[test]
+fn atomic_views() {
+    use core::sync::atomic::Ordering;
+
+    let mut data: DataArray<8> = DataArray::zeroed();
+
+    {
+        let bytes = data.as_atomic_bytes();
+        std::thread::scope(|scope| {
+            for (idx, byte) in bytes[..4].iter().enumerate() {
+                scope.spawn(move || byte.fetch_or(1 << idx, Ordering::Relaxed));
+            }
+        });
+        assert_eq!(bytes[3].load(Ordering::Relaxed), 1 << 3);
+    }
+
+    let mut words: [u32; 3] = [0; 3];
+    let slice: &mut DataSlice = DataSlice::from_slice_mut(unsafe {
+        core::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), 12)
+    });
+    assert_eq!(slice.as_atomic_u32s().map(<[_]>::len), Some(3));
+    assert!(slice.get_mut(1..9).unwrap().as_atomic_u32s().is_none());
+    assert!(slice.get_mut(4..10).unwrap().as_atomic_u32s().is_none());
+}
+
+#[test]
+fn data_mutexed() {
+    use sync::DataMutexed;
+
+    let data: DataMutexed = DataMutexed::new(DataBoxed::zeroed(64).unwrap(), 8);
+    assert_eq!((data.stripes(), data.stripe_size()), (8, 8));
+
+    std::thread::scope(|scope| {
+        for thread in 0..8_u8 {
+            let data: &DataMutexed = &data;
+            scope.spawn(move || {
+                let start: usize = thread as usize * 8;
+                data.with_region(start..start + 8, |region| region.inner.fill(thread)).unwrap();
+            });
+        }
+    });
+
+    assert_eq!(data.with_region(6..10, |region| region.inner.to_vec()), Ok(std::vec![0, 0, 1, 1]));
+    assert!(data.with_region(60..65, |_| ()).is_err());
+    assert_eq!(data.with_region(64.., |region| region.size()), Ok(0));
+    assert_eq!(data.into_inner().inner[63], 7);
+
+    assert_eq!(DataMutexed::new(DataBoxed::zeroed(3).unwrap(), 8).stripes(), 3);
+}
+
+#[test]
+fn data_log() {
+    use sync::{DataLog, LogFullError};
+
+    let log: DataLog = DataLog::new(256);
+
+    std::thread::scope(|scope| {
+        for thread in 0..4_u8 {
+            let log: &DataLog = &log;
+            scope.spawn(move || {
+                for len in 1..5 {
+                    log.append(&[thread; 8][..len]).unwrap();
+                }
+            });
+        }
+    });
+
+    assert_eq!(log.used(), 4 * 4 * 8);
+    assert_eq!(log.iter().count(), 16);
+    assert!(log.iter().all(|record| record.iter().all(|byte| byte == record.inner[0])));
+
+    assert_eq!(log.append(&[0; 200]), Err(LogFullError { needed: 204, remaining: 128 }));
+    assert_eq!(log.append(&[9; 3]), Ok(128));
+    assert_eq!(log.iter().last().map(|record| &record.inner), Some(&[9, 9, 9][..]));
+}
+
+#[test]
+fn guard_impls() {
+    fn assert_data_structure<D: RawDataStructure + DataStructureSlice + ?Sized>() {}
+
+    assert_data_structure::<std::sync::MutexGuard<'static, DataSlice>>();
+    assert_data_structure::<std::sync::RwLockWriteGuard<'static, DataSlice>>();
+    assert_data_structure::<core::cell::RefMut<'static, DataSlice>>();
+
+    #[cfg(feature = "parking_lot")]
+    assert_data_structure::<parking_lot::RwLockWriteGuard<'static, DataSlice>>();
+}
+
+#[test]
+fn data_cells() {
+    use cells::{DataCells, BorrowError};
+
+    let cells: DataCells = DataCells::new(DataBoxed::zeroed(16).unwrap(), 4);
+    assert_eq!((cells.regions(), cells.region_size()), (4, 4));
+
+    unsafe {
+        let first = cells.borrow::<[u8; 2]>(0).unwrap();
+        let second = cells.borrow::<[u8; 2]>(1).unwrap();
+        assert_eq!(cells.borrow_mut::<u8>(3).err(), Some(BorrowError::AlreadyBorrowed));
+
+        let mut other = cells.borrow_mut::<[u8; 4]>(4).unwrap();
+        *other = [1, 2, 3, 4];
+        assert_eq!(cells.borrow::<u8>(7).err(), Some(BorrowError::AlreadyMutablyBorrowed));
+        assert_eq!((*first, *second), ([0, 0], [0, 0]));
+
+        drop((first, second, other));
+        assert_eq!(*cells.borrow_mut::<[u8; 8]>(0).unwrap(), [0, 0, 0, 0, 1, 2, 3, 4]);
+        assert!(matches!(cells.borrow::<u8>(16), Err(BorrowError::OutOfBounds(_))));
+    }
+
+    assert_eq!(cells.into_inner().inner[4..8], [1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "checked-unchecked"))]
+#[should_panic]
+fn unchecked_bounds_assert() {
+    let data: DataArray<4> = DataArray::zeroed();
+
+    let _ = unsafe { data.read_unchecked::<u32>(1) };
+}
+
+#[test]
+#[cfg(all(feature = "init-tracking", debug_assertions))]
+fn init_tracking() {
+    let mut data: DataBoxed = DataBoxed::uninit(16).unwrap();
+    assert!(!init::is_written(&data));
+
+    unsafe {
+        RawDataStructure::write(&mut data, 0, ManuallyDrop::new(7_u32)).unwrap();
+        assert_eq!(RawDataStructure::take::<u32>(&data, 0), Ok(7));
+        assert!(init::is_written(&data.get(0..4).unwrap()));
+    }
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| unsafe {
+        let _ = RawDataStructure::take::<u32>(&data, 2);
+    }));
+    assert!(result.is_err());
+
+    init::forget(&data);
+    assert!(init::is_written(&data));
+
+    // Memory freed by a tracked DataBoxed and reused by the next allocation must not report unwritten bytes.
+    for _ in 0..8 {
+        drop(DataBoxed::uninit(16).unwrap());
+        let reused: std::boxed::Box<[u8]> = std::vec![7; 16].into_boxed_slice();
+        assert!(init::is_written(DataSlice::from_slice(&reused)));
+    }
+}
+
+#[test]
+fn guarded_data() {
+    use guarded::{GuardedData, CanaryError, GUARD_SIZE};
+
+    let mut data: GuardedData<DataBoxed> = GuardedData::new(DataBoxed::zeroed(GUARD_SIZE * 2 + 8).unwrap()).unwrap();
+    assert_eq!((data.size(), RawDataStructure::size(&data)), (8, 8));
+
+    unsafe { data.write_ones_unchecked(0, 8) };
+    assert_eq!(data.check_canaries(), Ok(()));
+
+    unsafe { *data.as_mut_ptr().add(8) = 0 };
+    assert_eq!(data.check_canaries(), Err(CanaryError { after: true, distance: 0 }));
+
+    let inner: DataBoxed = data.into_inner();
+    assert_eq!(inner.inner[GUARD_SIZE..GUARD_SIZE + 9], [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0]);
+
+    assert!(GuardedData::new(DataBoxed::zeroed(GUARD_SIZE).unwrap()).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "guard-pages", any(unix, windows)))]
+fn guard_pages() {
+    use guard_pages::GuardPagedData;
+
+    let mut data: GuardPagedData = GuardPagedData::uninit(13).unwrap();
+    assert_eq!(data.size(), 13);
+
+    unsafe { data.write_ones_unchecked(0, 13) };
+    assert_eq!(data.inner[12], 0xFF);
+    assert_eq!(RawDataStructure::size(&data), 13);
+
+    assert_eq!(GuardPagedData::uninit(0).unwrap().size(), 0);
+    assert!(GuardPagedData::uninit(usize::MAX).is_err());
+
+    #[cfg(feature = "allocator_api")]
+    {
+        let mut data: DataBoxed<guard_pages::GuardPages> = DataBoxed::uninit_guarded(13).unwrap();
+        assert_eq!(data.size(), 13);
+
+        unsafe { data.write_ones_unchecked(0, 13) };
+        assert_eq!(data.inner[12], 0xFF);
+
+        let empty: DataBoxed<guard_pages::GuardPages> = DataBoxed::uninit_guarded(0).unwrap();
+        assert_eq!(empty.size(), 0);
+    }
+}
+
+#[test]
+#[cfg(feature = "trace")]
+fn trace_events() {
+    use std::{string::{String, ToString}, sync::Mutex, vec::Vec};
+
+    static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct Recorder;
+
+    impl log::Log for Recorder {
+        fn enabled(&self, meta: &log::Metadata<'_>) -> bool {
+            meta.target() == "typeless_data"
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            if self.enabled(record.metadata()) {
+                EVENTS.lock().unwrap().push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    let _ = log::set_logger(&Recorder);
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let mut data: DataBoxed = DataBoxed::zeroed(8).unwrap();
+    let addr: String = std::format!("{:p}", data.as_ptr());
+
+    unsafe {
+        RawDataStructure::write(&mut data, 2, ManuallyDrop::new(7_u16)).unwrap();
+        assert_eq!(RawDataStructure::take::<u16>(&data, 2), Ok(7));
+        assert!(RawDataStructure::take::<u64>(&data, 4).is_err());
+    }
+
+    let mut out: Vec<u8> = Vec::new();
+    data.write_range_to(2..4, &mut out).unwrap();
+    assert_eq!(out, [7, 0]);
+
+    let events: Vec<String> = EVENTS.lock().unwrap()
+        .iter()
+        .filter(|event| event.contains(&addr))
+        .cloned()
+        .collect();
+
+    assert_eq!(events, [
+        std::format!("write `2` bytes of `u16` at offset `2` of `{}`", addr),
+        std::format!("take `2` bytes of `u16` at offset `2` of `{}`", addr),
+    ]);
+
+    let sub: String = std::format!("{:p}", data.get(2..4).unwrap().as_ptr());
+    assert!(EVENTS.lock().unwrap().contains(&std::format!("read `2` bytes of `[u8]` at offset `0` of `{}`", sub)));
+}
+
+#[test]
+fn idx_error_kinds() {
+    use idx::IdxError;
+    use std::string::ToString;
+
+    let data: DataArray<8> = DataArray::zeroed();
+
+    let err: IdxError = data.read::<u32>(6).unwrap_err().error;
+    assert_eq!(err, IdxError::OutOfBounds { idx: 6, len: 8, size: 4 });
+    assert_eq!((err.idx(), err.data_len(), err.size()), (Some(6), Some(8), Some(4)));
+
+    assert_eq!(data.read::<u32>(usize::MAX).map_err(IdxError::from), Err(IdxError::Overflow { idx: usize::MAX, size: 4 }));
+    assert_eq!(IdxError::misaligned(core::ptr::without_provenance::<u8>(8), 16), IdxError::Misaligned { required: 16, actual: 8 });
+
+    for err in [err, IdxError::new(usize::MAX, 8, 4), IdxError::misaligned(core::ptr::without_provenance::<u8>(8), 16), IdxError::ZeroSize] {
+        assert!(!err.to_string().is_empty());
+    }
+}
+
+#[test]
+fn typed_idx_error() {
+    use idx::{Access, IdxError, TypedIdxError};
+    use std::string::ToString;
+
+    #[allow(dead_code)]
+    struct Header([u8; 12]);
+
+    let data: DataBoxed = DataBoxed::zeroed(4096).unwrap();
+
+    let err: TypedIdxError = RawDataStructure::read::<Header>(&data, 4090).unwrap_err();
+    assert_eq!(err.access, Access::Read);
+    assert_eq!(err.error, IdxError::OutOfBounds { idx: 4090, len: 4096, size: 12 });
+    assert!(err.type_name().ends_with("Header"));
+    assert!(err.to_string().ends_with("Header` (12 bytes) at offset 4090 of a 4096-byte buffer"));
+
+    let err: IdxError = unsafe { RawDataStructure::take::<u64>(&data, usize::MAX) }.unwrap_err().into();
+    assert_eq!(err, IdxError::Overflow { idx: usize::MAX, size: 8 });
+
+    let slice: &DataSlice = &data;
+    assert_eq!(slice.read::<Header>(4090).unwrap_err(), RawDataStructure::read::<Header>(&data, 4090).unwrap_err());
+    let err: TypedIdxError = unsafe { slice.take::<u64>(4092) }.unwrap_err();
+    assert_eq!((err.access, err.type_name()), (Access::Take, "u64"));
+}
+
+#[test]
+fn idx_error_into_io() {
+    use std::io::{Error, ErrorKind};
+
+    fn read_header(data: &DataBoxed) -> Result<u32, Error> {
+        Ok(unsafe { RawDataStructure::take::<u32>(data, 2)? })
+    }
+
+    let data: DataBoxed = DataBoxed::zeroed(4).unwrap();
+    assert_eq!(read_header(&data).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(Error::from(idx::IdxError::ZeroSize).kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn crate_error() {
+    use core::convert::TryFrom;
+
+    fn parse(bytes: &[u8]) -> Result<u64, Error> {
+        let array: DataArray<4> = DataArray::try_from(bytes)?;
+        let mut boxed: DataBoxed = DataBoxed::zeroed(array.size())?;
+        boxed.inner.copy_from_slice(&array.inner);
+
+        Ok(boxed.read_varint_u64(0)?.0)
+    }
+
+    assert_eq!(parse(&[5, 0, 0, 0]), Ok(5));
+    assert_eq!(parse(&[5]), Err(Error::DiferentSizes { expected: 4, gotten: 1 }));
+    assert!(matches!(parse(&[0xFF; 4]), Err(Error::Varint(_))));
+}
+
+#[test]
+fn exact_fit() {
+    let mut data: DataArray<32> = DataArray::zeroed();
+
+    unsafe {
+        assert!(data.write(0, ManuallyDrop::new([7_u8; 32])).is_ok());
+        assert_eq!(data.take::<[u8; 32]>(0), Ok([7; 32]));
+        assert_eq!(data.replace(28, ManuallyDrop::new(1_u32)), Ok(u32::from_ne_bytes([7; 4])));
+        assert!(data.write(29, ManuallyDrop::new(1_u32)).is_err());
+    }
+
+    assert!(data.read::<u64>(24).is_ok());
+    assert!(data.read::<u64>(25).is_err());
+    assert!(RawDataStructure::read_validity(&data, 0, 32).is_ok());
+    assert!(RawDataStructure::read_validity(&data, 1, 32).is_err());
+}
+
+#[test]
+fn zero_sized_accesses() {
+    let mut data: DataArray<4> = DataArray::zeroed();
+
+    unsafe {
+        assert!(data.write(4, ManuallyDrop::new(())).is_ok());
+        assert_eq!(RawDataStructure::take::<()>(&data, 4), Ok(()));
+        assert!(RawDataStructure::replace(&mut data, 4, ManuallyDrop::new(())).is_ok());
+        assert!(RawDataStructure::read_volatile::<()>(&data, 4).is_ok());
+        assert!(RawDataStructure::write(&mut data, 5, ManuallyDrop::new(())).is_err());
+
+        assert!(data.write_ones(4, 0).is_ok());
+        assert!(data.write_ones(5, 0).is_err());
+    }
+    assert_eq!(data.inner, [0; 4]);
+
+    assert_eq!(data.get(0..0).map(DataSlice::size), Some(0));
+    assert_eq!(data.get(4..4).map(DataSlice::size), Some(0));
+    assert_eq!(data.get(4..).map(DataSlice::size), Some(0));
+    assert!(data.get(5..5).is_none());
+}
+
+#[test]
+fn get_empty() {
+    fn is_empty<D: DataStructureSlice + ?Sized>(data: &D) -> bool {
+        data.get(..).map(DataSlice::size) == Some(0)
+    }
+
+    let mut data: DataBoxed = DataBoxed::zeroed(0).unwrap();
+
+    assert!(is_empty(&data));
+    assert!(is_empty(DataSlice::from_slice(&[])));
+    assert_eq!(data.get_const(core::ops::Bound::Unbounded, core::ops::Bound::Unbounded).map(DataSlice::size), Some(0));
+    assert_eq!(DataStructureSlice::get_mut(&mut data, 0..0).map(|slice| slice.size()), Some(0));
+    assert!(data.get(0..1).is_none());
+    assert!(data.get(0..=0).is_none());
+}
+
+#[test]
+fn resolve_bounds() {
+    use core::ops::Bound::*;
+    use idx::{resolve, IdxError};
+
+    assert_eq!(resolve((Included(1), Included(2)), 4), Ok(1..3));
+    assert_eq!(resolve((Excluded(0), Unbounded), 4), Ok(1..4));
+    assert_eq!(resolve((Unbounded, Excluded(0)), 0), Ok(0..0));
+    assert_eq!(resolve((Included(3), Included(1)), 4), Err(IdxError::Reversed { start: 3, end: 2 }));
+    assert_eq!(resolve((Included(2), Included(4)), 4), Err(IdxError::OutOfBounds { idx: 2, len: 4, size: 3 }));
+    assert_eq!(resolve((Unbounded, Included(usize::MAX)), 4), Err(IdxError::Overflow { idx: usize::MAX, size: 1 }));
+
+    let data: DataArray<4> = DataArray { inner: [1, 2, 3, 4] };
+    assert_eq!(data.get(1..=2).map(|slice| &slice.inner), Some(&[2, 3][..]));
+    assert_eq!(DataStructureSlice::get(&data, 1..=2).map(|slice| &slice.inner), Some(&[2, 3][..]));
+    assert_eq!(unsafe { &(*DataStructureSlice::get_unchecked(&data, 3..=3)).inner }, [4]);
+    assert!(data.get(..=4).is_none());
+}
+
+#[test]
+fn idx_resolve_method() {
+    use idx::{Idx, IdxError};
+
+    assert_eq!((2..=5).resolve(8), Ok(2..6));
+    assert_eq!((..).resolve(3), Ok(0..3));
+    assert_eq!(7.resolve(8), Ok(7..8));
+    assert_eq!((4..).resolve(4), Ok(4..4));
+    assert_eq!((3..9).resolve(8), Err(IdxError::OutOfBounds { idx: 3, len: 8, size: 6 }));
+}
+
+#[test]
+fn start_len() {
+    use idx::{Idx, IdxError, StartLen};
+
+    let data: DataArray<8> = DataArray { inner: [1, 2, 3, 4, 5, 6, 7, 8] };
+
+    assert_eq!(data.get(StartLen::new(2, 3)).map(|slice| &slice.inner), Some(&[3, 4, 5][..]));
+    assert_eq!(DataStructureSlice::get(&data, (6_usize, 2_usize)).map(|slice| &slice.inner), Some(&[7, 8][..]));
+    assert!(data.get((6_usize, 3_usize)).is_none());
+
+    assert_eq!(StartLen::new(usize::MAX, 2).resolve(8), Err(IdxError::Overflow { idx: usize::MAX, size: 2 }));
+    assert_eq!((8_usize, 0_usize).resolve(8), Ok(8..8));
+}
+
+#[test]
+fn from_end_idx() {
+    use idx::{from_end, FromEnd, Idx};
+
+    let mut data: DataBoxed = DataBoxed::zeroed(8).unwrap();
+    data.inner.copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    assert_eq!(data.get(from_end(4)..).map(|slice| &slice.inner), Some(&[5, 6, 7, 8][..]));
+    assert_eq!(data.get(..from_end(6)).map(|slice| &slice.inner), Some(&[1, 2][..]));
+    assert_eq!(data.get(from_end(3)..=from_end(2)).map(|slice| &slice.inner), Some(&[6, 7][..]));
+    assert_eq!(DataStructureSlice::get(&data, FromEnd(1)).map(|slice| &slice.inner), Some(&[8][..]));
+    assert!(data.get(from_end(9)..).is_none());
+
+    assert_eq!((from_end(2)..from_end(0)).resolve(8), Ok(6..8));
+    assert!(FromEnd(9).resolve(8).is_err());
+}
+
+#[test]
+fn strided_view() {
+    use idx::Strided;
+
+    // Two interleaved 16 bit channels.
+    let data: DataArray<12> = DataArray { inner: [1, 0, 9, 0, 2, 0, 9, 0, 3, 0, 9, 0] };
+
+    let left: strided::StridedView<'_> = data.get_strided(Strided::new(0, 4, 3), 2).unwrap();
+    assert_eq!(left.len(), 3);
+    assert_eq!(left.get(1).map(|slice| &slice.inner), Some(&[2, 0][..]));
+    assert!(left.get(3).is_none());
+    assert_eq!(left.iter().map(|slice| slice.inner[0]).collect::<std::vec::Vec<u8>>(), [1, 2, 3]);
+    assert_eq!(left.iter().next_back().map(|slice| slice.inner[0]), Some(3));
+
+    let right: strided::StridedView<'_> = data.get_strided(Strided::new(2, 4, 3), 2).unwrap();
+    assert!(right.into_iter().all(|slice| slice.inner == [9, 0]));
+
+    assert!(data.get_strided(Strided::new(2, 4, 3), 3).is_none());
+    assert!(data.get_strided(Strided::new(0, usize::MAX, 3), 1).is_none());
+    assert!(data.get_strided(Strided::new(12, 4, 0), 2).unwrap().is_empty());
+}
+
+#[test]
+fn get_disjoint() {
+    use idx::StartLen;
+
+    let data: DataArray<8> = DataArray { inner: [1, 2, 3, 4, 5, 6, 7, 8] };
+
+    let [header, payload, trailer] = data.get_disjoint([StartLen::new(0, 2), StartLen::new(2, 4), StartLen::new(6, 2)]).unwrap();
+    assert_eq!((&header.inner, &payload.inner, &trailer.inner), (&[1, 2][..], &[3, 4, 5, 6][..], &[7, 8][..]));
+
+    let [all, last] = data.get_disjoint([0..8, 7..8]).unwrap();
+    assert_eq!((all.size(), &last.inner), (8, &[8][..]));
+
+    assert!(data.get_disjoint([0..2, 6..9]).is_none());
+}
+
+#[test]
+fn get_disjoint_mut() {
+    let mut data: DataBoxed = DataBoxed::zeroed(8).unwrap();
+
+    let [header, payload] = data.get_disjoint_mut([0..2, 2..8]).unwrap();
+    header.inner.fill(1);
+    payload.inner.fill(2);
+    assert_eq!(data.inner[..], [1, 1, 2, 2, 2, 2, 2, 2]);
+
+    assert!(data.get_disjoint_mut([0..3, 2..4]).is_none());
+    assert!(data.get_disjoint_mut([0..8, 4..4]).is_some());
+    assert!(data.get_disjoint_mut([0..2, 7..9]).is_none());
+}
+
+#[test]
+fn range_bounds_idx() {
+    use crate::idx::{bounds, Idx};
+
+    struct Window { from: usize, to: usize }
+    impl core::ops::RangeBounds<usize> for Window {
+        fn start_bound(&self) -> core::ops::Bound<&usize> { core::ops::Bound::Included(&self.from) }
+        fn end_bound(&self) -> core::ops::Bound<&usize> { core::ops::Bound::Excluded(&self.to) }
+    }
+
+    fn sub<R: core::ops::RangeBounds<usize>>(data: &DataSlice, range: R) -> Option<&DataSlice> {
+        data.get(bounds(range))
+    }
+
+    let data: DataArray<8> = DataArray::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+
+    assert_eq!(bounds(2..=4).resolve(8), Ok(2..5));
+    assert_eq!(sub(&data, Window { from: 1, to: 3 }).map(#[inline] |slice| &slice.inner), Some(&[1, 2][..]));
+    assert_eq!(sub(&data, ..).map(#[inline] |slice| slice.size()), Some(8));
+    assert!(sub(&data, 4..9).is_none());
+}
+
+#[test]
+fn align_validity() {
+    let data: DataArray<16> = DataArray::zeroed();
+
+    let aligned: std::vec::Vec<usize> = (0..4).filter(#[inline] |&at| data.align_validity(at, 4).is_ok()).collect();
+    assert_eq!(aligned.len(), 1);
+    let aligned: usize = aligned[0];
+
+    assert_eq!(data.align_validity(16, 1), Ok(()));
+    assert!(data.align_validity(17, 1).is_err());
+    assert_eq!(data.align_validity(aligned, 3).map_err(#[inline] |err| err.required_align()), Err(Some(3)));
+
+    assert_eq!(data.typed_validity::<u32>(aligned), Ok(()));
+    assert_eq!(data.typed_validity::<u32>(aligned + 1).map_err(#[inline] |err| err.required_align()), Err(Some(4)));
+    assert!(matches!(data.typed_validity::<u32>(aligned + 12 + 4), Err(idx::IdxError::OutOfBounds { .. } | idx::IdxError::Overflow { .. })));
+    assert_eq!(data.typed_validity::<()>(16), Ok(()));
+}
+
+#[test]
+fn read_aligned() {
+    let mut data: DataArray<16> = DataArray::zeroed();
+    let aligned: usize = (0..4).find(#[inline] |&at| data.align_validity(at, 4).is_ok()).unwrap();
+    unsafe { data.write::<u32>(aligned, ManuallyDrop::new(0x1234_5678)) }.unwrap();
+
+    assert_eq!(unsafe { data.read_ref_aligned::<u32>(aligned) }.copied(), Ok(0x1234_5678));
+    let err: idx::TypedIdxError = data.read_aligned::<u32>(aligned + 1).unwrap_err();
+    assert_eq!(err.access, idx::Access::Read);
+    assert_eq!(err.error.required_align(), Some(4));
+    assert!(data.read_aligned::<u32>(14).is_err());
+}
+
+#[test]
+fn unaligned_values() {
+    let mut data: DataArray<16> = DataArray::zeroed();
+    let misaligned: usize = (0..8).find(#[inline] |&at| data.align_validity(at, 8).is_err()).unwrap();
+
+    unsafe {
+        data.write_unaligned_value::<u64>(misaligned, 0x0102_0304_0506_0708).unwrap();
+        assert_eq!(data.read_unaligned_value::<u64>(misaligned), Ok(0x0102_0304_0506_0708));
+        assert_eq!(data.inner[misaligned..misaligned + 8], 0x0102_0304_0506_0708_u64.to_ne_bytes());
+
+        let err: idx::TypedIdxError = data.write_unaligned_value::<u64>(9, 0).unwrap_err();
+        assert_eq!(err.access, idx::Access::Write);
+        assert!(data.read_unaligned_value::<u64>(9).is_err());
+    }
+}
+
+#[test]
+fn alignment_helpers() {
+    use crate::layout::{next_aligned, padding_needed};
+
+    assert_eq!(padding_needed(0, 8), 0);
+    assert_eq!(padding_needed(5, 8), 3);
+    assert_eq!(padding_needed(7, 3), 2);
+    assert_eq!(next_aligned(5, 4), Some(8));
+    assert_eq!(next_aligned(8, 4), Some(8));
+    assert_eq!(next_aligned(usize::MAX, 2), None);
+
+    const PADDED: usize = padding_needed(3, 4);
+    assert_eq!(PADDED, 1);
+
+    let data: DataArray<16> = DataArray::zeroed();
+    let offset: usize = data.align_offset::<u32>().unwrap();
+    assert!(offset < 4);
+    assert_eq!(data.typed_validity::<u32>(offset), Ok(()));
+    assert_eq!(data.align_offset::<u8>(), Some(0));
+    assert_eq!(DataArray::<0>::zeroed().align_offset::<u8>(), Some(0));
+}
+
+#[test]
+fn offset_builder() {
+    use crate::layout::OffsetBuilder;
+
+    const SIZE: usize = {
+        let mut builder: OffsetBuilder = OffsetBuilder::new();
+        builder.reserve::<u16>();
+        builder.reserve::<u64>();
+        builder.size()
+    };
+
+    let mut builder: OffsetBuilder = OffsetBuilder::default();
+    let tag: usize = builder.reserve::<u8>();
+    let len: usize = builder.reserve::<u32>();
+    let bytes: usize = builder.reserve_bytes(3, 1);
+    let end: usize = builder.reserve::<u16>();
+
+    assert_eq!((tag, len, bytes, end), (0, 4, 8, 12));
+    assert_eq!(builder.size(), 14);
+    assert_eq!(builder.align(), 4);
+    assert_eq!(SIZE, 16);
+
+    let mut data: DataArray<SIZE> = DataArray::zeroed();
+    unsafe {
+        data.write_unaligned_value::<u32>(len, 7).unwrap();
+        assert_eq!(data.read_unaligned_value::<u32>(len), Ok(7));
+    }
+}
+
+#[test]
+fn byte_offset() {
+    use crate::layout::{ByteOffset, OffsetBuilder};
+
+    let offset: ByteOffset = ByteOffset::new(3);
+    assert_eq!(offset.add(2), Some(ByteOffset(5)));
+    assert_eq!(offset.add_sized::<u32>(), Some(ByteOffset(7)));
+    assert_eq!(offset.align_up(4), Some(ByteOffset(4)));
+    assert_eq!(offset.padding_to(8), 5);
+    assert_eq!(ByteOffset(usize::MAX).add(1), None);
+    assert_eq!(ByteOffset(usize::MAX).align_up(2), None);
+    assert_eq!(usize::from(ByteOffset::from(9)), 9);
+
+    let mut builder: OffsetBuilder = OffsetBuilder::new();
+    builder.reserve::<u8>();
+    assert_eq!(builder.end(), ByteOffset(1));
+
+    let mut data: DataArray<8> = DataArray::zeroed();
+    let mut cursor: cursor::DataCursor = cursor::DataCursor::new(&mut data);
+    cursor.skip(3).unwrap();
+    assert_eq!(cursor.offset(), ByteOffset(3));
+}
+
+#[test]
+fn record_schema() {
+    use crate::schema::{Field, FieldId, RecordLayout, SchemaError};
+
+    let layout: RecordLayout = RecordLayout::new([
+        Field::of::<u8>("tag"),
+        Field::of::<u32>("len"),
+        Field::new("name", 3, 1),
+    ]).unwrap();
+
+    assert_eq!(layout.size(), 11);
+    assert_eq!(layout.align(), 4);
+    assert_eq!(layout.offset("len"), Some(4));
+    assert_eq!(layout.id("name"), Some(FieldId(2)));
+    assert_eq!(layout.offset(FieldId(3)), None);
+
+    let mut data: DataArray<16> = DataArray::zeroed();
+    unsafe {
+        layout.write::<u32>(&mut data, "len", 0xAABB_CCDD).unwrap();
+        layout.write::<u8>(&mut data, FieldId(0), 7).unwrap();
+        assert_eq!(layout.read::<u32>(&data, "len"), Ok(0xAABB_CCDD));
+        assert_eq!(layout.read::<u8>(&data, "tag"), Ok(7));
+        assert_eq!(layout.read::<u16>(&data, "len"), Err(SchemaError::DiferentSizes { expected: 4, gotten: 2 }));
+    }
+    layout.write_bytes(&mut data, "name", b"abc").unwrap();
+    assert_eq!(layout.get(&data, "name").map(#[inline] |field| &field.inner), Ok(&b"abc"[..]));
+    assert_eq!(layout.get(&data, "missing").err(), Some(SchemaError::UnknownField));
+    assert!(matches!(layout.get(data.get(..8).unwrap(), "name"), Err(SchemaError::Idx(_))));
+
+    assert_eq!(RecordLayout::new([Field::new("bad", 1, 3)]), Err(SchemaError::InvalidAlign { field: FieldId(0), align: 3 }));
+    assert_eq!(RecordLayout::new([Field::new("a", usize::MAX, 1), Field::new("b", 1, 1)]), Err(SchemaError::Overflow { field: FieldId(1) }));
+}
+
+#[test]
+fn layout_macro() {
+    crate::layout! {
+        /// The layout of a test packet.
+        struct Packet {
+            tag: u8,
+            count: u32,
+            payload: [u8; 5],
+            crc: u16,
+        }
+    }
+
+    assert_eq!((Packet::tag, Packet::count, Packet::payload, Packet::crc), (0, 4, 8, 14));
+    assert_eq!(Packet::SIZE, 16);
+    assert_eq!(Packet::ALIGN, 4);
+
+    let mut data: DataArray<16> = Packet::array();
+    Packet::check(&data);
+    Packet::check(&DataArray::<32>::zeroed());
+
+    unsafe {
+        data.write_unaligned_value::<u32>(Packet::count, 3).unwrap();
+        assert_eq!(data.read_unaligned_value::<u32>(Packet::count), Ok(3));
+    }
+}
+
+#[test]
+fn typed_idx_handles() {
+    use crate::idx::{Idx, TypedIdx};
+
+    let mut data: DataArray<16> = DataArray::zeroed();
+    let offset: usize = data.align_offset::<u32>().unwrap();
+
+    unsafe {
+        let handle: TypedIdx<u32> = RawDataStructure::write_typed(&mut data, offset, ManuallyDrop::new(42_u32)).unwrap();
+        assert_eq!(handle.idx(), offset);
+        assert_eq!(handle.resolve(16), Ok(offset..offset + 4));
+        assert_eq!(data.read_at(&handle), Ok(&42));
+        assert_eq!(data.take_at(handle), Ok(42));
+
+        let err: idx::TypedIdxError = RawDataStructure::write_typed(&mut data, 14, ManuallyDrop::new(0_u32)).unwrap_err().1;
+        assert_eq!(err.access, idx::Access::Write);
+    }
+}
+
+#[test]
+fn slot_map() {
+    use crate::slotmap::{DataSlotMap, Key};
+    use std::rc::Rc;
+
+    let mut map: DataSlotMap<u64, DataBoxed> = DataSlotMap::new(DataBoxed::zeroed(96).unwrap()).unwrap();
+    let capacity: usize = map.capacity();
+    assert!(capacity > 0);
+
+    let first: Key = map.insert(1).unwrap();
+    let second: Key = map.insert(2).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(first), Some(&1));
+    *map.get_mut(second).unwrap() += 10;
+    assert_eq!(map.get(second), Some(&12));
+
+    assert_eq!(map.remove(first), Some(1));
+    assert_eq!(map.remove(first), None);
+    assert_eq!(map.get(first), None);
+
+    let reused: Key = map.insert(3).unwrap();
+    assert_eq!(reused.idx(), first.idx());
+    assert_ne!(reused, first);
+    assert_eq!(map.get(first), None);
+    assert_eq!(map.get(reused), Some(&3));
+    assert_eq!(map.iter().map(#[inline] |(_, value)| *value).collect::<std::vec::Vec<u64>>(), [3, 12]);
+
+    while map.len() < capacity {
+        map.insert(0).unwrap();
+    }
+    assert_eq!(map.insert(4), Err(4));
+
+    let counter: Rc<()> = Rc::new(());
+    let mut owned: DataSlotMap<Rc<()>, DataBoxed> = DataSlotMap::new(DataBoxed::zeroed(64).unwrap()).unwrap();
+    owned.insert(counter.clone()).unwrap();
+    owned.insert(counter.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&counter), 3);
+    drop(owned);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn slot_map_moved() {
+    use crate::guarded::GuardedData;
+    use crate::slotmap::{DataSlotMap, Key};
+
+    #[repr(align(16))]
+    #[derive(Debug, PartialEq)]
+    struct Aligned(u64);
+
+    /// Bytes held inline, used from the given offset so the test can pick where they start.
+    struct Inline { bytes: [u8; 544], offset: usize }
+
+    impl core::ops::Deref for Inline {
+        type Target = DataSlice;
+        fn deref(&self) -> &DataSlice { DataSlice::from_slice(&self.bytes[self.offset..self.offset + 528]) }
+    }
+    impl core::ops::DerefMut for Inline {
+        fn deref_mut(&mut self) -> &mut DataSlice { DataSlice::from_slice_mut(&mut self.bytes[self.offset..self.offset + 528]) }
+    }
+
+    #[repr(C, align(16))]
+    struct Unshifted<M> { map: M }
+    #[repr(C, align(16))]
+    struct Shifted<M> { _pad: u64, map: M }
+
+    type Map = DataSlotMap<Aligned, GuardedData<Inline>>;
+
+    fn moved_away(map: &mut Map, key: Key) {
+        assert_eq!(map.get(key), None);
+        assert!(map.get_mut(key).is_none());
+        assert_eq!(map.insert(Aligned(2)), Err(Aligned(2)));
+        assert_eq!(map.remove(key), Some(Aligned(1)));
+        assert!(map.is_empty());
+    }
+
+    // The bytes are aligned from one of the offsets, since they only differ by 8 bytes.
+    let mut offset: usize = 0;
+    let map: Map = loop {
+        match DataSlotMap::new(GuardedData::new(Inline { bytes: [0; 544], offset }).ok().unwrap()) {
+            Ok(map) => break map,
+            Err(_) if offset == 0 => offset = 8,
+            Err(_) => panic!("The bytes are not aligned from either offset."),
+        }
+    };
+
+    // The map is aligned in exactly one of them, for the same reason.
+    let mut unshifted: std::boxed::Box<Unshifted<Map>> = std::boxed::Box::new(Unshifted { map });
+    match unshifted.map.insert(Aligned(1)) {
+        Ok(key) => {
+            assert_eq!(unshifted.map.get(key), Some(&Aligned(1)));
+            let mut shifted: std::boxed::Box<Shifted<Map>> = std::boxed::Box::new(Shifted { _pad: 0, map: unshifted.map });
+            moved_away(&mut shifted.map, key);
+        },
+        Err(value) => {
+            let mut shifted: std::boxed::Box<Shifted<Map>> = std::boxed::Box::new(Shifted { _pad: 0, map: unshifted.map });
+            let key: Key = shifted.map.insert(value).unwrap();
+            assert_eq!(shifted.map.get(key), Some(&Aligned(1)));
+            let mut unshifted: std::boxed::Box<Unshifted<Map>> = std::boxed::Box::new(Unshifted { map: shifted.map });
+            moved_away(&mut unshifted.map, key);
+        },
+    }
+}
+
+#[test]
+fn data_arena() {
+    use crate::arena::{ArenaError, DataArena};
+
+    let mut arena: DataArena<DataArray<32>> = DataArena::new(DataArray::zeroed());
+
+    let byte: &mut u8 = arena.alloc_value(1_u8).unwrap();
+    let word: &mut u32 = arena.alloc_value(2_u32).unwrap();
+    let bytes: &mut [u8] = arena.alloc_slice_copy(b"abc").unwrap();
+    *byte += 1;
+    *word += 1;
+    bytes[0] = b'x';
+
+    assert_eq!((*byte, *word, &*bytes), (2, 3, &b"xbc"[..]));
+    assert!((word as *mut u32).is_aligned());
+    assert!(arena.used() >= 8);
+
+    let remaining: usize = arena.remaining();
+    assert_eq!(arena.alloc_slice_copy(&[0_u8; 64]), Err(ArenaError { size: 64, align: 1, remaining }));
+    assert!(arena.alloc_bytes(1, 3).is_err());
+    assert!(arena.alloc_bytes(1, 1 << (usize::BITS - 1)).is_err());
+
+    arena.reset();
+    assert_eq!(arena.used(), 0);
+    assert_eq!(arena.alloc_slice_copy(&[7_u8; 32]).map(#[inline] |slice| slice.len()), Ok(32));
+    assert_eq!(arena.remaining(), 0);
+
+    // The data is stored inline, so it moves together with the arena.
+    arena.reset();
+    arena.alloc_value(1_u8).unwrap();
+    let mut arena: std::boxed::Box<DataArena<DataArray<32>>> = std::boxed::Box::new(arena);
+    let byte: *mut u8 = arena.alloc_value(9_u8).unwrap();
+    assert_eq!(byte, arena.get_mut().as_mut_ptr().wrapping_add(1));
+    assert_eq!(arena.into_inner().inner[..2], [1, 9]);
+}
+
+#[test]
+fn data_heap() {
+    use crate::heap::{DataHeap, FreeError, HeapStats, OomError, SubAllocator};
+
+    let mut heap: DataHeap<DataBoxed> = DataHeap::new(DataBoxed::zeroed(264).unwrap()).unwrap();
+    assert_eq!(heap.stats(), HeapStats { total: 264, free: 256, largest_free: 256, free_blocks: 1, ..HeapStats::default() });
+
+    let first: usize = heap.alloc(10, 1).unwrap();
+    let second: usize = heap.alloc(4, 32).unwrap();
+    let third: usize = heap.alloc(24, 8).unwrap();
+    assert_eq!(second % 32, 0);
+    assert!(first + 10 <= second && second + 4 <= third);
+    heap.get_mut().inner[second..second + 4].copy_from_slice(&[1, 2, 3, 4]);
+
+    assert_eq!(heap.stats().allocations, 3);
+    assert_eq!(heap.alloc(1024, 1), Err(OomError { size: 1024, align: 1 }));
+    assert_eq!(heap.free(second + 1), Err(FreeError { offset: second + 1 }));
+
+    heap.free(first).unwrap();
+    assert_eq!(heap.free(first), Err(FreeError { offset: first }));
+    let stats: HeapStats = heap.stats();
+    assert!(stats.free_blocks >= 2 && stats.fragmentation() > 0.0);
+
+    // Reopening the same data keeps the allocations.
+    let mut heap: DataHeap<DataBoxed> = DataHeap::open(heap.into_inner()).unwrap();
+    assert_eq!(heap.get_ref().inner[second..second + 4], [1, 2, 3, 4]);
+    assert_eq!(heap.stats().allocations, 2);
+
+    heap.free(third).unwrap();
+    heap.free(second).unwrap();
+    assert_eq!(heap.stats(), HeapStats { total: 264, free: 256, largest_free: 256, free_blocks: 1, ..HeapStats::default() });
+    assert_eq!(heap.free(second), Err(FreeError { offset: second }));
+    assert_eq!(heap.free(third), Err(FreeError { offset: third }));
+
+    // A header that looks used inside of a free block is not freed.
+    heap.get_mut().inner[64..72].copy_from_slice(&17_u64.to_le_bytes());
+    assert_eq!(heap.free(72), Err(FreeError { offset: 72 }));
+    assert_eq!(heap.alloc(248, 8), Ok(16));
+    assert!(DataHeap::new(DataArray::<16>::zeroed()).is_err());
+}
+
+#[test]
+fn buddy_alloc() {
+    use crate::buddy::BuddyAlloc;
+    use crate::heap::{FreeError, OomError, SubAllocator};
+
+    let mut buddy: BuddyAlloc<DataBoxed> = BuddyAlloc::new(DataBoxed::zeroed(400).unwrap()).unwrap();
+    assert_eq!(buddy.pool_size(), 256);
+    assert_eq!(buddy.free_bytes(), 256);
+
+    let small: usize = buddy.alloc(10, 1).unwrap();
+    let big: usize = buddy.alloc(100, 1).unwrap();
+    let aligned: usize = buddy.alloc(4, 64).unwrap();
+    assert_eq!(aligned % 64, 0);
+    assert_eq!(big % 128, 0);
+    assert_eq!(buddy.free_bytes(), 256 - 16 - 128 - 64);
+    assert_eq!(buddy.alloc(128, 1), Err(OomError { size: 128, align: 1 }));
+    assert_eq!(buddy.alloc(512, 1), Err(OomError { size: 512, align: 1 }));
+
+    buddy.get_mut().inner[big] = 9;
+    assert_eq!(buddy.free(small + 1), Err(FreeError { offset: small + 1 }));
+    buddy.free(small).unwrap();
+    assert_eq!(buddy.free(small), Err(FreeError { offset: small }));
+
+    let mut buddy: BuddyAlloc<DataBoxed> = BuddyAlloc::open(buddy.into_inner()).unwrap();
+    assert_eq!(buddy.get_ref().inner[big], 9);
+    buddy.free(aligned).unwrap();
+    buddy.free(big).unwrap();
+    assert_eq!(buddy.free_bytes(), 256);
+    assert_eq!(buddy.alloc(256, 1), Ok(0));
+    assert!(BuddyAlloc::new(DataArray::<16>::zeroed()).is_err());
+}
+
+#[test]
+fn data_pool() {
+    use crate::pool::DataPool;
+
+    // 8 blocks and their byte of bitmap, then 2 blocks and another byte.
+    let mut pool: DataPool<4, DataBoxed> = DataPool::new(DataBoxed::zeroed(33 + 9).unwrap()).unwrap();
+    assert_eq!(pool.capacity(), 10);
+    assert_eq!(pool.used(), 0);
+
+    let (first, block) = pool.acquire().unwrap();
+    assert_eq!((first, block.size()), (0, 4));
+    block.inner.copy_from_slice(&[1, 2, 3, 4]);
+
+    for idx in 1..10 {
+        assert_eq!(pool.acquire().map(|(idx, _)| idx), Some(idx));
+    }
+    assert!(pool.acquire().is_none());
+    assert_eq!(pool.used(), 10);
+
+    assert!(pool.release(3));
+    assert!(!pool.release(3));
+    assert!(!pool.release(10));
+    assert!(pool.get(3).is_none());
+    assert_eq!(pool.get(0).unwrap().inner, [1, 2, 3, 4]);
+    assert_eq!(pool.acquire().map(|(idx, _)| idx), Some(3));
+
+    pool.release(7);
+    let pool: DataPool<4, DataBoxed> = DataPool::open(pool.into_inner()).unwrap();
+    assert_eq!(pool.used(), 9);
+    assert!(!pool.is_used(7));
+
+    assert!(DataPool::<0, DataBoxed>::new(DataBoxed::zeroed(8).unwrap()).is_err());
+    assert!(DataPool::<8, DataBoxed>::new(DataBoxed::zeroed(8).unwrap()).is_err());
+}
+
+#[test]
+fn static_data_heap() {
+    use crate::heap::StaticDataHeap;
+    use core::alloc::{GlobalAlloc, Layout};
+
+    static HEAP: StaticDataHeap<256> = StaticDataHeap::new();
+
+    unsafe {
+        let layout: Layout = Layout::from_size_align(24, 16).unwrap();
+        let first: *mut u8 = HEAP.alloc(layout);
+        assert!(!first.is_null());
+        assert_eq!(first.addr() % 16, 0);
+        first.write_bytes(7, 24);
+
+        let second: *mut u8 = HEAP.alloc(Layout::new::<u64>());
+        assert!(!second.is_null());
+        assert_eq!(HEAP.stats().allocations, 2);
+
+        assert!(HEAP.alloc(Layout::from_size_align(1024, 1).unwrap()).is_null());
+        assert!(HEAP.alloc(Layout::from_size_align(8, 32).unwrap()).is_null());
+
+        HEAP.dealloc(first, layout);
+        HEAP.dealloc(second, Layout::new::<u64>());
+        assert_eq!(HEAP.stats().allocations, 0);
+        assert_eq!(HEAP.stats().free_blocks, 1);
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+#[test]
+fn allocator_impls() {
+    use crate::arena::DataArena;
+    use crate::heap::{DataHeap, DataHeapCell, StaticDataHeap};
+    use std::boxed::Box;
+    use std::vec::Vec;
+
+    let mut data: DataBoxed = DataBoxed::zeroed(256).unwrap();
+    {
+        let arena: DataArena<&mut DataSlice> = DataArena::new(&mut data);
+        let mut vec: Vec<u32, &DataArena<&mut DataSlice>> = Vec::new_in(&arena);
+        vec.extend([1, 2, 3]);
+        assert_eq!(vec, [1, 2, 3]);
+        assert!(arena.used() >= 12);
+    }
+
+    let heap: DataHeapCell<DataBoxed> = DataHeapCell::new(DataHeap::new(data).unwrap());
+    {
+        let boxed: Box<u64, &DataHeapCell<DataBoxed>> = Box::new_in(5, &heap);
+        let mut vec: Vec<u8, &DataHeapCell<DataBoxed>> = Vec::new_in(&heap);
+        vec.extend_from_slice(b"typeless");
+        assert_eq!(*boxed, 5);
+        assert_eq!(vec, b"typeless");
+        assert_eq!(heap.stats().allocations, 2);
+        assert!(Vec::<u8, _>::try_with_capacity_in(1024, &heap).is_err());
+    }
+    assert_eq!(heap.stats().allocations, 0);
+
+    static HEAP: StaticDataHeap<128> = StaticDataHeap::new();
+    let boxed: Box<[u16; 4], &StaticDataHeap<128>> = Box::new_in([1, 2, 3, 4], &HEAP);
+    assert_eq!(*boxed, [1, 2, 3, 4]);
+    drop(boxed);
+    assert_eq!(HEAP.stats().allocations, 0);
+}
+
+#[test]
+fn usage_reports() {
+    use crate::arena::{ArenaStats, DataArena};
+    use crate::heap::{DataHeap, SubAllocator};
+    use crate::pool::DataPool;
+
+    let mut data: DataBoxed = DataBoxed::zeroed(64).unwrap();
+    {
+        let mut arena: DataArena<&mut DataSlice> = DataArena::new(&mut data);
+        arena.alloc_bytes(10, 1).unwrap();
+        arena.alloc_bytes(6, 1).unwrap();
+        arena.reset();
+        arena.alloc_bytes(4, 1).unwrap();
+        assert_eq!(arena.report(), ArenaStats { allocated: 4, peak: 16, allocations: 1, largest_free: 60 });
+    }
+
+    let mut heap: DataHeap<DataBoxed> = DataHeap::new(data).unwrap();
+    let first: usize = heap.alloc(8, 8).unwrap();
+    let second: usize = heap.alloc(16, 8).unwrap();
+    heap.free(first).unwrap();
+    let report: ArenaStats = heap.report();
+    assert_eq!((report.allocated, report.peak, report.allocations), (24, 40, 1));
+    heap.free(second).unwrap();
+    assert_eq!(heap.report().largest_free, 48);
+    assert_eq!(DataHeap::open(heap.into_inner()).unwrap().report().peak, 0);
+
+    let mut pool: DataPool<4, DataBoxed> = DataPool::new(DataBoxed::zeroed(33).unwrap()).unwrap();
+    pool.acquire();
+    pool.acquire();
+    pool.release(0);
+    assert_eq!(pool.report(), ArenaStats { allocated: 4, peak: 8, allocations: 1, largest_free: 4 });
+}
+
+#[test]
+fn data_stack() {
+    use crate::stack::DataStack;
+
+    let mut stack: DataStack<DataBoxed> = DataStack::new(DataBoxed::zeroed(64).unwrap());
+    assert!(stack.push_value(7u8).is_ok());
+    assert!(stack.push_value(0x1234_5678u32).is_ok());
+    assert!(stack.push_value([1u16, 2, 3]).is_ok());
+    assert_eq!(stack.len(), 3);
+    assert_eq!(stack.peek_size(), Some(6));
+    assert_eq!(stack.push_value([0u8; 64]), Err([0u8; 64]));
+
+    unsafe {
+        assert_eq!(stack.pop_value::<u32>(), None);
+        assert_eq!(stack.peek_ref::<[u16; 3]>(), Some(&[1, 2, 3]));
+        stack.peek_mut::<[u16; 3]>().unwrap()[0] = 9;
+        assert_eq!(stack.pop_value::<[u16; 3]>(), Some([9, 2, 3]));
+        assert_eq!(stack.pop_value::<u32>(), Some(0x1234_5678));
+        assert!(stack.discard());
+        assert!(!stack.discard());
+        assert_eq!(stack.pop_value::<u8>(), None);
+    }
+    assert_eq!(stack.used(), 0);
+    assert!(stack.is_empty());
+
+    stack.push_value(std::string::String::from("owned")).unwrap();
+    assert_eq!(unsafe { stack.pop_value::<std::string::String>() }.as_deref(), Some("owned"));
+}
+
+#[test]
+fn data_ring() {
+    use crate::ring::{DataRing, RingFullError};
+
+    let mut ring: DataRing<DataArray<10>> = DataRing::new(DataArray::zeroed());
+    let mut out: [u8; 10] = [0; 10];
+
+    ring.push_bytes(b"abcdef").unwrap();
+    assert_eq!(ring.pop_bytes(&mut out[..4]), 4);
+    assert_eq!(&out[..4], b"abcd");
+
+    // Straddles the end of the data.
+    ring.push_bytes(b"ghijkl").unwrap();
+    assert_eq!(ring.as_slices(), (&b"efghij"[..], &b"kl"[..]));
+    assert_eq!(ring.push_bytes(b"xyz"), Err(RingFullError { needed: 3, remaining: 2 }));
+    assert_eq!(ring.peek_bytes(&mut out[..3]), 3);
+    assert_eq!(ring.pop_bytes(&mut out), 8);
+    assert_eq!(&out[..8], b"efghijkl");
+    assert!(ring.is_empty());
+
+    ring.push_bytes(b"1234567").unwrap();
+    ring.pop_bytes(&mut out[..7]);
+    ring.push_frame(b"hey").unwrap();
+    assert_eq!(ring.push_frame(b"no"), Err(RingFullError { needed: 6, remaining: 3 }));
+    assert_eq!(ring.peek_frame_len(), Some(3));
+    assert_eq!(ring.pop_frame(&mut out[..2]), None);
+    assert_eq!(ring.pop_frame(&mut out), Some(3));
+    assert_eq!(&out[..3], b"hey");
+    assert_eq!(ring.pop_frame(&mut out), None);
+
+    ring.push_frame(b"").unwrap();
+    assert_eq!(ring.pop_frame(&mut out), Some(0));
+    assert!(DataRing::new(DataArray::<0>::zeroed()).push_bytes(b"").is_ok());
+}
+
+#[test]
+fn data_queue() {
+    use crate::queue::DataQueue;
+    use std::vec::Vec;
+
+    let mut queue: DataQueue<DataBoxed> = DataQueue::new(DataBoxed::zeroed(64).unwrap());
+    assert!(queue.enqueue_value(1u8).is_ok());
+    assert!(queue.enqueue_tagged(7, 0x0102_0304u32).is_ok());
+    assert!(queue.enqueue_tagged(9, [5u16; 4]).is_ok());
+    assert_eq!(queue.enqueue_value([0u8; 32]), Err([0u8; 32]));
+    assert_eq!(queue.len(), 3);
+
+    let pending: Vec<(u32, usize)> = queue.iter().map(|(tag, bytes)| (tag, bytes.size())).collect();
+    assert_eq!(pending, [(0, 1), (7, 4), (9, 8)]);
+
+    unsafe {
+        assert_eq!(queue.dequeue_value::<u32>(), None);
+        assert_eq!(queue.peek_ref::<u8>(), Some(&1));
+        assert_eq!(queue.dequeue_value::<u8>(), Some(1));
+        assert_eq!(queue.peek_tag(), Some(7));
+        assert_eq!(queue.dequeue_value::<u32>(), Some(0x0102_0304));
+
+        // Wraps around to the start of the data.
+        assert!(queue.enqueue_tagged(3, [6u8; 20]).is_ok());
+        assert_eq!(queue.iter().map(|(tag, _)| tag).collect::<Vec<u32>>(), [9, 3]);
+        assert_eq!(queue.dequeue_value::<[u16; 4]>(), Some([5; 4]));
+        assert_eq!(queue.peek_size(), Some(20));
+        assert_eq!(queue.dequeue_value::<[u8; 20]>(), Some([6; 20]));
+        assert!(!queue.discard());
+    }
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn double_ended() {
+    use crate::arena::{ArenaError, DoubleEnded};
+
+    let mut region: DoubleEnded<DataArray<32>> = DoubleEnded::new(DataArray::zeroed());
+    assert_eq!(region.alloc_front(3, 1), Ok(0));
+    assert_eq!(region.alloc_front(4, 4), Ok(4));
+    assert_eq!(region.alloc_back(5, 1), Ok(27));
+    assert_eq!(region.alloc_back(4, 8), Ok(16));
+    assert_eq!(region.remaining(), 8);
+
+    assert!(region.can_alloc_front(8, 1));
+    assert!(!region.can_alloc_back(8, 16));
+    assert_eq!(region.alloc_front(9, 1), Err(ArenaError { size: 9, align: 1, remaining: 8 }));
+    assert!(region.alloc_back(1, 3).is_err());
+
+    let mark: usize = region.front();
+    region.alloc_front(8, 1).unwrap();
+    assert_eq!(region.remaining(), 0);
+    region.reset_front_to(mark);
+    assert_eq!(region.front(), 8);
+
+    region.reset_back();
+    assert_eq!(region.alloc_back(24, 1), Ok(8));
+    region.reset_front();
+    region.reset_back_to(32);
+    assert_eq!(region.remaining(), 32);
+}
+
+#[test]
+fn data_chain() {
+    use crate::chain::DataChain;
+
+    let mut first: DataBoxed = DataBoxed::zeroed(3).unwrap();
+    let mut second: DataBoxed = DataBoxed::zeroed(0).unwrap();
+    let mut third: DataBoxed = DataBoxed::zeroed(5).unwrap();
+    let mut chain: DataChain<&mut DataSlice> = std::vec![&mut *first, &mut *second, &mut *third].into_iter().collect();
+    assert_eq!((chain.len(), chain.size()), (3, 8));
+    assert_eq!(chain.locate(3), Some((2, 0)));
+    assert_eq!(chain.locate(8), None);
+
+    chain.write_bytes(1, b"abcd").unwrap();
+    assert!(chain.write_bytes(6, b"xyz").is_err());
+    let mut out: [u8; 4] = [0; 4];
+    chain.read_bytes(1, &mut out).unwrap();
+    assert_eq!(&out, b"abcd");
+    assert_eq!(chain.segment(0).unwrap().inner, [0, b'a', b'b']);
+
+    unsafe {
+        chain.write_value::<u32>(2, 0x0403_0201).unwrap();
+        assert_eq!(chain.read_value::<u32>(2), Ok(0x0403_0201));
+        assert_eq!(chain.segment(2).unwrap().inner[..3], [2, 3, 4]);
+
+        // Regions that straddle two segments are not valid for pointers.
+        assert!(chain.read_validity(2, 2).is_err());
+        assert!(chain.read_validity(3, 5).is_ok());
+        assert_eq!(chain.read_unaligned_value::<u16>(4), Ok(0x0403));
+        assert!(chain.write_ones(1, 4).is_err());
+        chain.write_ones_unchecked(1, 4);
+    }
+    assert_eq!(chain.into_segments().len(), 3);
+    assert_eq!(first.inner[..], [0, 0xFF, 0xFF]);
+    assert_eq!(third.inner[..3], [0xFF, 0xFF, 4]);
+}
+
+#[test]
+fn gather_view() {
+    use crate::chain::GatherView;
+
+    let header: DataArray<4> = DataArray::from_array([0xAA, 2, 0, 1]);
+    let mut body: DataArray<6> = DataArray::from_array(*b"\x02\x03hell");
+    let mut view: GatherView = GatherView::new();
+    view.push(&header);
+    view.push_mut(&mut body);
+    assert_eq!((view.len(), view.size()), (2, 10));
+
+    unsafe {
+        // The length straddles the header and the body.
+        assert_eq!(view.read_value::<u32>(1), Ok(0x0201_0002));
+        assert_eq!(view.take_value::<[u8; 3]>(3), Ok([1, 2, 3]));
+        assert!(view.take_value::<u32>(8).is_err());
+
+        assert!(view.read_validity(2, 4).is_err());
+        assert!(view.read_validity(0, 4).is_ok());
+        assert!(view.write_validity(0, 4).is_err());
+        assert!(view.read_mut::<u16>(0).is_err());
+        assert!(view.read_mut::<u16>(4).is_ok());
+        assert!(view.write(6, ManuallyDrop::new(*b"HE")).is_ok());
+        assert_eq!(view.read_unaligned_value::<[u8; 4]>(6), Ok(*b"HEll"));
+    }
+
+    let mut out: [u8; 10] = [0; 10];
+    view.read_bytes(0, &mut out).unwrap();
+    assert_eq!(&out[4..], b"\x02\x03HEll");
+    // Cloning into the view only writes it's unique parts.
+    let ones: DataArray<10> = DataArray::from_array([1; 10]);
+    let source: GatherView = [ones.get(..4).unwrap(), ones.get(4..).unwrap()].iter().copied().collect();
+    unsafe { view.clone_from(&source) }.unwrap();
+    view.read_bytes(0, &mut out).unwrap();
+    assert_eq!(out, [0xAA, 2, 0, 1, 1, 1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn data_rope() {
+    use crate::rope::DataRope;
+
+    let mut rope: DataRope = DataRope::new();
+    assert!(rope.is_empty());
+    rope.insert_bytes(0, DataSlice::from_slice(b"world")).unwrap();
+    rope.insert_bytes(0, DataSlice::from_slice(b"hello ")).unwrap();
+    rope.insert_bytes(5, DataSlice::from_slice(b",")).unwrap();
+    rope.insert_bytes(12, DataSlice::from_slice(b"!")).unwrap();
+    assert!(rope.insert_bytes(14, DataSlice::from_slice(b"?")).is_err());
+    assert_eq!((rope.size(), rope.chunks()), (13, 5));
+    assert_eq!(&rope.flatten().unwrap().inner[..], b"hello, world!");
+
+    let mut out: [u8; 7] = [0; 7];
+    let view = rope.slice(3..10).unwrap();
+    view.read_bytes(0, &mut out).unwrap();
+    assert_eq!(&out, b"lo, wor");
+    assert_eq!(view.len(), 4);
+    assert!(rope.slice(3..14).is_err());
+
+    rope.remove(4..7).unwrap();
+    assert_eq!(&rope.flatten().unwrap().inner[..], b"hellworld!");
+    rope.remove(..).unwrap();
+    assert!(rope.is_empty());
+
+    for n in 0..200u8 {
+        rope.insert_bytes(rope.size() / 2, DataSlice::from_slice(&[n])).unwrap();
+    }
+    assert_eq!(rope.size(), 200);
+    rope.remove(50..150).unwrap();
+    assert_eq!(rope.size(), 100);
+
+    // Splitting the same chunk over and over keeps the bytes in order.
+    let bytes: std::vec::Vec<u8> = (0..=255).collect();
+    let mut rope: DataRope = DataRope::new();
+    rope.insert_bytes(0, DataSlice::from_slice(&bytes)).unwrap();
+    for at in 0..255 {
+        rope.insert_bytes(at * 2 + 1, DataSlice::from_slice(&[0])).unwrap();
+    }
+    assert_eq!(rope.chunks(), 511);
+    let flat: DataBoxed = rope.flatten().unwrap();
+    assert_eq!(flat.inner.iter().step_by(2).copied().collect::<std::vec::Vec<u8>>(), bytes);
+    assert!(flat.inner.iter().skip(1).step_by(2).all(#[inline] |byte| *byte == 0));
+}
+
+#[test]
+fn overlay_data() {
+    use crate::overlay::OverlayData;
+    use std::vec::Vec;
+
+    let base: DataArray<12> = DataArray::from_array(*b"hello, world");
+    let mut overlay: OverlayData = OverlayData::new(&base);
+    assert!(!overlay.is_modified());
+
+    overlay.write_bytes(0, b"J").unwrap();
+    overlay.write_bytes(7, b"W").unwrap();
+    overlay.write_bytes(8, b"O").unwrap();
+    assert!(overlay.write_bytes(11, b"!!").is_err());
+    assert_eq!(overlay.modified_bytes(), 3);
+
+    let mut out: [u8; 12] = [0; 12];
+    overlay.read_bytes(0, &mut out).unwrap();
+    assert_eq!(&out, b"Jello, WOrld");
+    assert_eq!(base.inner, *b"hello, world");
+
+    // Overlapping the first change and reaching into the base between them.
+    overlay.write_bytes(0, b"ye").unwrap();
+    overlay.write_bytes(6, b"_").unwrap();
+    assert_eq!(
+        overlay.diff().map(#[inline] |(start, bytes)| (start, bytes.inner.to_vec())).collect::<Vec<_>>(),
+        std::vec![(0, b"ye".to_vec()), (6, b"_WO".to_vec())],
+    );
+    unsafe {
+        assert_eq!(overlay.read_value::<[u8; 4]>(5), Ok(*b",_WO"));
+        overlay.write_value::<u8>(11, b'D').unwrap();
+    }
+
+    let mut target: DataArray<12> = DataArray::from_array(*b"hello, world");
+    overlay.commit(&mut target).unwrap();
+    assert_eq!(target.inner, *b"yello,_WOrlD");
+    assert!(overlay.commit(&mut DataArray::<4>::from_array([0; 4])).is_err());
+
+    overlay.discard();
+    overlay.read_bytes(0, &mut out).unwrap();
+    assert_eq!(&out, b"hello, world");
+    // Writing right before, between and after changes joins them into one.
+    overlay.write_bytes(4, b"O").unwrap();
+    overlay.write_bytes(8, b"R").unwrap();
+    for (idx, byte) in b"HELL".iter().enumerate() {
+        overlay.write_bytes(idx, &[*byte]).unwrap();
+    }
+    overlay.write_bytes(3, b"LO, WO").unwrap();
+    overlay.write_bytes(9, b"RLD").unwrap();
+    assert_eq!(
+        overlay.diff().map(#[inline] |(start, bytes)| (start, bytes.inner.to_vec())).collect::<Vec<_>>(),
+        std::vec![(0, b"HELLO, WORLD".to_vec())],
+    );
+}
+
+#[test]
+fn write_vectored() {
+    let mut frame: DataArray<10> = DataArray::from_array([0; 10]);
+    let header: &DataSlice = DataSlice::from_slice(&[0xAA, 3]);
+    let body: &DataSlice = DataSlice::from_slice(b"abc");
+
+    unsafe {
+        assert_eq!(frame.write_vectored(1, &[header, body, DataSlice::from_slice(&[]), header]), Ok(7));
+        assert_eq!(frame.inner, [0, 0xAA, 3, b'a', b'b', b'c', 0xAA, 3, 0, 0]);
+
+        assert!(frame.write_vectored(5, &[body, body]).is_err());
+        assert_eq!(frame.inner[5..], [b'c', 0xAA, 3, 0, 0]);
+        assert_eq!(frame.write_vectored(10, &[]), Ok(0));
+    }
+}
+
+#[test]
+fn data_patch() {
+    use crate::patch::{self, DataPatch};
+    use std::vec::Vec;
+
+    let old: DataArray<40> = DataArray::from_array([0; 40]);
+    let mut new: DataArray<40> = DataArray::from_array([0; 40]);
+    new.inner[2] = 1;
+    new.inner[5] = 2;
+    new.inner[39] = 3;
+
+    let patch: DataPatch = patch::diff(&old, &new);
+    assert_eq!(
+        patch.changes().map(#[inline] |(start, bytes)| (start, bytes.inner.to_vec())).collect::<Vec<_>>(),
+        std::vec![(2, std::vec![1, 0, 0, 2]), (39, std::vec![3])],
+    );
+    assert_eq!((patch.len(), patch.patched_bytes(), patch.required_size()), (2, 5, 40));
+    assert!(patch::diff(&new, &new).is_empty());
+
+    let mut target: DataArray<40> = DataArray::from_array([0; 40]);
+    patch.apply(&mut target).unwrap();
+    assert_eq!(target.inner, new.inner);
+    assert!(patch.apply(&mut DataArray::<20>::from_array([0; 20])).is_err());
+
+    // The bytes past the end of the old snapshot are changed.
+    let grown: DataArray<3> = DataArray::from_array([0, 0, 0]);
+    let mut short: DataArray<3> = DataArray::from_array([9, 9, 9]);
+    patch::diff(DataSlice::from_slice(&[0]), &grown).apply(&mut short).unwrap();
+    assert_eq!(short.inner, [9, 0, 0]);
+}
+
+#[test]
+fn watched_data() {
+    use crate::watch::{WatchedData, WatchId};
+    use core::cell::RefCell;
+    use std::vec::Vec;
+
+    let hits: RefCell<Vec<(usize, usize)>> = RefCell::new(Vec::new());
+    let dirty: core::cell::Cell<bool> = core::cell::Cell::new(false);
+    let mut data: WatchedData<DataArray<128>> = WatchedData::new(DataArray::from_array([0; 128]));
+
+    let id: WatchId = data.watch(0x40..0x48, #[inline] |idx, size| hits.borrow_mut().push((idx, size))).unwrap();
+    data.watch(.., #[inline] |_, _| dirty.set(true)).unwrap();
+    assert!(data.watch(0x70..0x90, #[inline] |_, _| {}).is_err());
+    assert_eq!(data.watchers(), 2);
+
+    unsafe {
+        data.write_unaligned_value::<u32>(0x10, 1).unwrap();
+        assert!(dirty.get());
+        data.write_unaligned_value::<u32>(0x3E, 1).unwrap();
+        data.write_zeroes(0x47, 4).unwrap();
+        data.write_vectored(0x48, &[DataSlice::from_slice(&[1, 2])]).unwrap();
+        data.write_ones(0x30, 0x10).unwrap();
+        assert_eq!(data.read_unaligned_value::<u32>(0x40), Ok(0));
+    }
+    assert_eq!(*hits.borrow(), std::vec![(0x3E, 4), (0x47, 4)]);
+
+    assert!(data.unwatch(id));
+    assert!(!data.unwatch(id));
+    unsafe { data.write_zeroes(0x40, 1).unwrap(); }
+    assert_eq!(hits.borrow().len(), 2);
+    assert_eq!(data.into_inner().inner[0x30], 0xFF);
+}
+
+#[test]
+fn watched_slice_like() {
+    use crate::watch::WatchedData;
+    use std::vec::Vec;
+
+    let hits: core::cell::RefCell<Vec<(usize, usize)>> = core::cell::RefCell::new(Vec::new());
+    let mut data: WatchedData<DataArray<16>> = WatchedData::new(DataArray::zeroed());
+    data.watch(4..8, #[inline] |idx, size| hits.borrow_mut().push((idx, size))).unwrap();
+
+    unsafe {
+        data.read_slice_like_ref_mut::<[u8]>(0, 8).unwrap()[6] = 1;
+        data.read_slice_like_ref_mut::<[u8]>(8, 8).unwrap()[0] = 1;
+        assert!(data.read_slice_like_mut::<[u16]>(4, 8).is_err());
+    }
+    assert_eq!(*hits.borrow(), std::vec![(0, 8)]);
+}
+
+#[test]
+fn erased_column() {
+    use crate::erased::Column;
+    use std::rc::Rc;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    #[repr(align(32))]
+    struct Position(f32, f32);
+
+    let mut positions: Column = Column::of::<Position>();
+    for n in 0..10 {
+        let value = core::mem::ManuallyDrop::new(Position(n as f32, 0.0));
+        unsafe { positions.push_raw((&*value as *const Position).cast::<u8>()).unwrap(); }
+    }
+    assert_eq!(positions.len(), 10);
+    assert!(positions.capacity() >= 10);
+    assert!(positions.get_ptr(10).is_none());
+    assert!(positions.get_ptr(3).unwrap().cast::<Position>().is_aligned());
+
+    assert!(positions.swap_remove(2));
+    assert!(!positions.swap_remove(9));
+    let mut out: core::mem::MaybeUninit<Position> = core::mem::MaybeUninit::uninit();
+    unsafe {
+        assert_eq!(*positions.get_ptr(2).unwrap().cast::<Position>(), Position(9.0, 0.0));
+        assert!(positions.swap_remove_raw(0, out.as_mut_ptr().cast::<u8>()));
+        assert_eq!(out.assume_init(), Position(0.0, 0.0));
+        assert_eq!(*positions.get_ptr(0).unwrap().cast::<Position>(), Position(8.0, 0.0));
+    }
+    assert_eq!(positions.len(), 8);
+
+    // The values that need to be dropped are dropped once.
+    let counter: Rc<()> = Rc::new(());
+    let mut shared: Column = Column::of::<Rc<()>>();
+    for _ in 0..5 {
+        let value = core::mem::ManuallyDrop::new(counter.clone());
+        unsafe { shared.push_raw((&*value as *const Rc<()>).cast::<u8>()).unwrap(); }
+    }
+    assert_eq!(Rc::strong_count(&counter), 6);
+    shared.swap_remove(1);
+    assert_eq!(Rc::strong_count(&counter), 5);
+    drop(shared);
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    let mut empty: Column = Column::of::<[u64; 0]>();
+    unsafe { empty.push_raw(core::ptr::NonNull::<u8>::dangling().as_ptr()).unwrap(); }
+    assert_eq!((empty.len(), empty.capacity()), (1, usize::MAX));
+}
+
+#[test]
+fn command_buffer() {
+    use crate::command::{self, CommandBuffer};
+    use std::vec::Vec;
+
+    const CLEAR: u32 = 0;
+    const DRAW: u32 = 1;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Draw {
+        mesh: u32,
+        at: [f32; 2],
+    }
+
+    let mut data: DataArray<64> = DataArray::from_array([0; 64]);
+    let mut buffer: CommandBuffer<&mut DataSlice> = CommandBuffer::new(&mut data);
+    assert!(buffer.push_bytes(CLEAR, &[0x20, 0x20, 0x20]));
+    unsafe {
+        assert_eq!(buffer.push_value(DRAW, Draw { mesh: 7, at: [1.0, 2.0] }), Ok(()));
+        assert_eq!(buffer.push_value(DRAW, [0u8; 40]), Err([0u8; 40]));
+    }
+    assert_eq!((buffer.len(), buffer.used()), (2, 8 + 3 + 8 + 12));
+
+    let commands: Vec<(u32, &DataSlice)> = buffer.iter().collect();
+    assert_eq!((commands[0].0, &commands[0].1.inner), (CLEAR, &[0x20, 0x20, 0x20][..]));
+    unsafe {
+        assert_eq!(command::decode::<Draw>(DRAW, commands[1]), Some(Draw { mesh: 7, at: [1.0, 2.0] }));
+        assert_eq!(command::decode::<Draw>(CLEAR, commands[1]), None);
+        assert_eq!(command::decode::<u8>(CLEAR, commands[0]), None);
+    }
+
+    buffer.clear();
+    assert_eq!(buffer.iter().count(), 0);
+    assert!(buffer.push_bytes(DRAW, &[0; 56]));
+    assert!(!buffer.push_bytes(DRAW, &[]));
+}
+
+#[test]
+fn task_queue() {
+    use crate::task::TaskQueue;
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
+
+    let log: Arc<Mutex<Vec<u32>>> = Arc::new(Mutex::new(Vec::new()));
+    let mut data: DataArray<256> = DataArray::from_array([0; 256]);
+    let mut queue: TaskQueue<&mut DataSlice> = TaskQueue::new(&mut data);
+
+    for n in 0..3 {
+        let log: Arc<Mutex<Vec<u32>>> = log.clone();
+        assert!(queue.push(move || log.lock().unwrap().push(n)).is_ok());
+    }
+    let big: [u64; 32] = [1; 32];
+    assert!(queue.push(move || assert_eq!(big.len(), 32)).is_err());
+    assert_eq!(queue.len(), 3);
+
+    assert!(queue.run_one());
+    assert_eq!(queue.drain(), 2);
+    assert!(!queue.run_one());
+    assert_eq!(*log.lock().unwrap(), std::vec![0, 1, 2]);
+    assert_eq!(queue.remaining(), 256);
+
+    // The tasks left in the queue are dropped without being run.
+    let kept: Arc<Mutex<Vec<u32>>> = log.clone();
+    assert!(queue.push(move || kept.lock().unwrap().push(3)).is_ok());
+    assert_eq!(Arc::strong_count(&log), 2);
+    drop(queue);
+    assert_eq!(Arc::strong_count(&log), 1);
+    assert_eq!(log.lock().unwrap().len(), 3);
+
+    // The bytes of the tasks (padding included) are zeroed once they are taken out.
+    let mut queue: TaskQueue<&mut DataSlice> = TaskQueue::new(&mut data);
+    let padded: (u8, u64) = (1, 2);
+    assert!(queue.push(move || assert_eq!(padded, (1, 2))).is_ok());
+    assert_eq!(queue.drain(), 1);
+    drop(queue);
+    assert_eq!(data.inner, [0; 256]);
+}
+
+#[test]
+fn checked_data() {
+    use crate::checked::{AnyError, CheckedData, HEADER};
+    use core::any::TypeId;
+    use std::string::String;
+
+    let mut data: CheckedData<DataBoxed> = CheckedData::new(DataBoxed::zeroed(256).unwrap());
+    assert!(data.write_any::<u64>(0, 7).is_ok());
+    assert!(data.write_any(64, String::from("hello")).is_ok());
+    assert_eq!(data.write_any::<u32>(8, 1).unwrap_err().1, AnyError::Overlap { idx: 0 });
+    assert!(matches!(data.write_any::<u64>(250, 1), Err((1, AnyError::Idx(_)))));
+    assert!(matches!(data.write_any::<u64>(1, 1), Err((1, AnyError::Idx(idx::IdxError::Misaligned { .. })))));
+    assert_eq!(data.len(), 2);
+
+    assert_eq!(data.read_any::<u64>(0), Ok(&7));
+    assert_eq!(data.read_any::<i64>(0), Err(AnyError::WrongType { idx: 0, size: 8 }));
+    assert_eq!(data.read_any::<u64>(HEADER), Err(AnyError::Empty { idx: HEADER }));
+    assert_eq!(data.type_at(64), Some(TypeId::of::<String>()));
+    data.read_any_mut::<String>(64).unwrap().push_str(", world");
+
+    assert_eq!(data.take_any::<String>(64).as_deref(), Ok("hello, world"));
+    assert_eq!(data.type_at(64), None);
+    assert!(data.write_any::<[u8; 3]>(64, *b"abc").is_ok());
+    assert_eq!(data.read_any::<[u8; 3]>(64), Ok(b"abc"));
+
+    // None of the values written have padding, so their bytes can be read.
+    assert_eq!(unsafe { data.get_ref() }.inner[64 + HEADER..64 + HEADER + 3], *b"abc");
+}
+
+#[test]
+fn data_map() {
+    use crate::map::{DataMap, MapFullError};
+    use std::vec::Vec;
+
+    let mut map: DataMap<DataArray<96>> = DataMap::new(DataArray::from_array([0; 96]), 4).unwrap();
+    assert!(DataMap::new(DataArray::from_array([0; 16]), 4).is_err());
+    assert_eq!(map.remaining(), 96 - 12 - 32);
+
+    assert_eq!(map.insert(b"name", b"flash"), Ok(false));
+    assert_eq!(map.insert(b"id", &[1, 2]), Ok(false));
+    assert_eq!(map.insert(b"id", &[3, 4]), Ok(true));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(b"id").unwrap().inner, [3, 4]);
+    assert_eq!(map.get(b"name").unwrap().inner, *b"flash");
+    assert!(map.get(b"none").is_none());
+
+    assert_eq!(map.insert(b"name", b"page"), Ok(true));
+    assert_eq!(map.insert(b"big", &[0; 32]), Err(MapFullError::Bytes { needed: 43, remaining: 52 - 17 - 12 - 16 }));
+    assert!(map.remove(b"id"));
+    assert!(!map.remove(b"id"));
+
+    map.compact();
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.remaining(), 52 - 16);
+    assert_eq!(map.get(b"name").unwrap().inner, *b"page");
+
+    for key in [b"a", b"b", b"c"] {
+        assert!(map.insert(key, key).is_ok());
+    }
+    assert_eq!(map.insert(b"d", b"d"), Err(MapFullError::Slots));
+
+    let map: DataMap<DataArray<96>> = DataMap::open(map.into_inner()).unwrap();
+    let mut entries: Vec<(Vec<u8>, Vec<u8>)> = map.iter().map(#[inline] |(key, value)| (key.inner.to_vec(), value.inner.to_vec())).collect();
+    entries.sort();
+    assert_eq!(entries, [(b"a".to_vec(), b"a".to_vec()), (b"b".to_vec(), b"b".to_vec()), (b"c".to_vec(), b"c".to_vec()), (b"name".to_vec(), b"page".to_vec())]);
+    assert!(DataMap::open(DataArray::from_array([0; 96])).is_err());
+}
+
+#[test]
+#[cfg(feature = "ptr_metadata")]
+fn write_unsized_with_meta() {
+    let mut data: DataBoxed = DataBoxed::zeroed(64).unwrap();
+    let words: [u16; 3] = [1, 2, 3];
+    let number: u32 = 7;
+
+    unsafe {
+        assert!(data.write_unsized_with_meta::<[u16]>(0, &ManuallyDrop::new(words) as &ManuallyDrop<[u16]>).is_ok());
+        assert!(data.write_unsized_with_meta::<dyn core::fmt::Debug>(32, &ManuallyDrop::new(number) as &ManuallyDrop<dyn core::fmt::Debug>).is_ok());
+        assert!(data.write_unsized_with_meta::<[u16]>(60, &ManuallyDrop::new(words) as &ManuallyDrop<[u16]>).is_err());
+
+        assert_eq!(data.read_unaligned_value::<usize>(0), Ok(3));
+        assert_eq!(&*data.read_unsized_self_described::<[u16]>(0).unwrap(), &[1, 2, 3]);
+        assert_eq!(std::format!("{:?}", &*data.read_unsized_self_described::<dyn core::fmt::Debug>(32).unwrap()), "7");
+    }
+}
+
+#[test]
+fn slice_of() {
+    let mut data: DataBoxed = DataBoxed::zeroed(16).unwrap();
+
+    unsafe {
+        assert!(data.write_slice_of::<u16>(4, &[1, 2, 3]).is_ok());
+        assert!(data.write_slice_of::<u32>(8, &[4, 5, 6]).is_err());
+
+        assert_eq!(&*data.read_slice_of::<u16>(4, 3).unwrap(), &[1, 2, 3]);
+        assert_eq!(data.read_slice_of::<u16>(4, 0).map(|ptr| ptr.len()), Ok(0));
+        assert!(data.read_slice_of::<u16>(4, 8).is_err());
+        assert!(data.read_slice_of::<u16>(0, usize::MAX).is_err());
+    }
+}
+
+#[test]
+fn str_storage() {
+    let mut data: DataArray<16> = DataArray::zeroed();
+
+    unsafe {
+        assert!(data.write_str(2, "héllo").is_ok());
+        assert!(data.write_str(12, "hello").is_err());
+
+        assert_eq!(data.read_str(2, 6), Ok("héllo"));
+        assert_eq!(data.read_str_unchecked(2, 6), "héllo");
+        assert!(matches!(data.read_str(2, 2), Err(idx::Utf8OrIdxError::Utf8(_))));
+        assert!(matches!(data.read_str(12, 6), Err(idx::Utf8OrIdxError::Idx(_))));
+    }
+}
+
+#[test]
+fn cstr_storage() {
+    let mut data: DataArray<8> = DataArray::filled(b'x');
+    let abc: &core::ffi::CStr = core::ffi::CStr::from_bytes_with_nul(b"abc\0").unwrap();
+
+    unsafe {
+        assert!(data.write_cstr(1, abc).is_ok());
+        assert!(data.write_cstr(5, abc).is_err());
+
+        assert_eq!(data.read_cstr(1), Ok(abc));
+        assert_eq!(data.read_cstr(4), Ok(Default::default()));
+        assert_eq!(data.read_cstr(5), Err(idx::IdxError::OutOfBounds { idx: 5, len: 8, size: 4 }));
+        assert!(data.read_cstr(9).is_err());
+    }
+}
+
+#[test]
+#[cfg(feature = "ptr_metadata")]
+fn get_size_of() {
+    #[repr(C)]
+    struct Packet {
+        len: u16,
+        payload: [u8],
+    }
+
+    // SAFETY: The size is the one of the header and the payload, padded to the alignment of the header.
+    unsafe impl GetSizeOf<Packet> for usize {
+        fn size(&self) -> usize {
+            core::mem::size_of::<u16>().saturating_add(*self)
+                .checked_next_multiple_of(core::mem::align_of::<u16>())
+                .unwrap_or(usize::MAX)
+        }
+    }
+
+    let mut data: DataBoxed = DataBoxed::zeroed(8).unwrap();
+
+    unsafe {
+        data.write_unaligned_value::<u16>(0, 3).unwrap();
+        data.write_slice_of::<u8>(2, &[1, 2, 3]).unwrap();
+
+        let packet: &Packet = data.read_unsized_ref::<Packet>(0, 3).unwrap();
+        assert_eq!(packet.len, 3);
+        assert_eq!(&packet.payload, &[1, 2, 3]);
+        assert!(data.read_unsized::<Packet>(0, 7).is_err());
+        assert!(data.read_unsized::<[u16]>(0, 4).is_ok());
+        assert!(data.read_unsized::<[u16]>(0, 5).is_err());
+    }
+}
+
+#[test]
+#[cfg(feature = "ptr_metadata")]
+fn dyn_storage() {
+    trait Shape {
+        fn area(&self) -> u32;
+    }
+
+    struct Square(u32);
+    impl Shape for Square {
+        fn area(&self) -> u32 { self.0 * self.0 }
+    }
+
+    struct Rect(u16, u16);
+    impl Shape for Rect {
+        fn area(&self) -> u32 { self.0 as u32 * self.1 as u32 }
+    }
+
+    let mut data: DataBoxed = DataBoxed::zeroed(32).unwrap();
+
+    unsafe {
+        assert!(data.write_dyn::<dyn Shape>(0, &ManuallyDrop::new(Square(3)) as &ManuallyDrop<dyn Shape>).is_ok());
+        assert!(data.write_dyn_boxed::<dyn Shape>(16, std::boxed::Box::new(Rect(2, 5))).is_ok());
+        assert!(data.write_dyn_boxed::<dyn Shape>(24, std::boxed::Box::new(Rect(2, 5))).is_err());
+
+        assert_eq!(data.read_dyn::<dyn Shape>(0).unwrap().area(), 9);
+        assert_eq!(data.read_dyn::<dyn Shape>(16).unwrap().area(), 10);
+        assert!(data.read_dyn::<dyn Shape>(28).is_err());
+    }
+}
+
+#[test]
+fn read_slice_like() {
+    let mut data: DataBoxed = DataBoxed::zeroed(16).unwrap();
+
+    unsafe {
+        data.write_slice_of::<u16>(0, &[1, 2, 3]).unwrap();
+        data.write_str(8, "text").unwrap();
+
+        assert_eq!(data.read_slice_like_ref::<[u16]>(0, 3), Ok(&[1u16, 2, 3] as &[u16]));
+        assert_eq!(data.read_slice_like_ref::<str>(8, 4), Ok("text"));
+        assert!(data.read_slice_like::<[u16]>(8, 5).is_err());
+        assert!(data.read_slice_like::<str>(8, 9).is_err());
+
+        data.read_slice_like_ref_mut::<[u16]>(0, 3).unwrap()[1] = 7;
+        assert_eq!(&*data.read_slice_of::<u16>(0, 3).unwrap(), &[1, 7, 3]);
+        assert_eq!((*data.read_slice_like::<DataSlice>(8, 4).unwrap()).size(), 4);
+    }
+}
+
+#[test]
+fn hex_text() {
+    const ARRAY: Result<DataArray<3>, hex::HexError> = DataArray::from_hex("0aFf10");
+
+    let data: DataArray<3> = ARRAY.unwrap();
+    let mut text: std::string::String = std::string::String::new();
+    data.encode_hex(&mut text).unwrap();
+    assert_eq!(text, "0aff10");
+
+    let mut out: DataArray<6> = DataArray::zeroed();
+    let mut writer: fmt::FmtWriter = fmt::FmtWriter::new(&mut out);
+    data.encode_hex(&mut writer).unwrap();
+    assert_eq!(writer.as_str(), "0aff10");
+    assert!(data.encode_hex(&mut writer).is_err());
+
+    let boxed: DataBoxed = DataBoxed::from_hex("DEADbeef").unwrap();
+    assert_eq!(&*boxed.inner, &[0xDE, 0xAD, 0xBE, 0xEF]);
+
+    assert_eq!(DataArray::<2>::from_hex("0a1").err(), Some(hex::HexError::OddLength { len: 3 }));
+    assert_eq!(DataArray::<2>::from_hex("0a").err(), Some(hex::HexError::DiferentSizes { expected: 2, gotten: 1 }));
+    assert_eq!(DataArray::<2>::from_hex("0a1g").err(), Some(hex::HexError::InvalidDigit { idx: 3, byte: b'g' }));
+    assert_eq!(DataBoxed::from_hex("xy").map(|_| ()), Err(Error::Hex(hex::HexError::InvalidDigit { idx: 0, byte: b'x' })));
+}
+
+#[test]
+fn display_hexdump() {
+    let data: DataArray<18> = DataArray::from_array(*b"Hello, world!\n\0\x01\x02\x03");
+
+    assert_eq!(
+        std::format!("{}", data),
+        "00000000: 48 65 6C 6C 6F 2C 20 77 6F 72 6C 64 21 0A 00 01 | Hello, world!...\n\
+         00000010: 02 03                                           | ..",
+    );
+    assert_eq!(
+        std::format!("{:4.6}", data),
+        "00000000: 48 65 6C 6C | Hell\n\
+         00000004: 6F 2C       | o,",
+    );
+    assert_eq!(std::format!("{}", DataArray::<0>::zeroed()), "");
+}
+
+#[test]
+fn hex_formatting() {
+    let data: DataArray<3> = DataArray::from_array([0x0A, 0x1B, 0x2C]);
+    let boxed: DataBoxed = DataBoxed::from_hex("ff00").unwrap();
+
+    assert_eq!(std::format!("{:x}", data), "0a1b2c");
+    assert_eq!(std::format!("{:X}", data), "0A1B2C");
+    assert_eq!(std::format!("{:#x}", boxed), "0xff00");
+    assert_eq!(std::format!("{:#X}", &*boxed), "0xFF00");
+    assert_eq!(std::format!("{:x}", DataArray::<0>::zeroed()), "");
+}
+
+#[test]
+fn binary_formatting() {
+    let data: DataArray<3> = DataArray::from_array([0x0A, 0x1B, 0x2C]);
+
+    assert_eq!(std::format!("{:b}", data), "00001010 00011011 00101100");
+    assert_eq!(std::format!("{:2b}", data), "00001010 00011011\n00101100");
+    assert_eq!(std::format!("{:b}", DataBoxed::from_hex("80").unwrap()), "10000000");
+}
+
+#[test]
+fn debug_offsets_and_groups() {
+    let data: DataArray<10> = DataArray { inner: core::array::from_fn(|idx| (idx + 1) as u8) };
+
+    assert_eq!(std::format!("{:?}", data), "01 02 03 04 05 06 07 08 09 0A");
+    assert_eq!(std::format!("{:.4?}", data), "01 02 03 04  05 06 07 08  09 0A");
+    assert_eq!(
+        std::format!("{:+8.4?}", data),
+        "00000000: 01 02 03 04  05 06 07 08\n\
+         00000008: 09 0A",
+    );
+    assert_eq!(
+        std::format!("{:>+8.4?}", data),
+        "00000000: 01 02 03 04  05 06 07 08\n\
+         00000008:                    09 0A",
+    );
+    assert_eq!(std::format!("{:>5?}", data), "01 02 03 04 05\n06 07 08 09 0A");
+}
+
+#[test]
+fn alternate_debug() {
+    let data: DataArray<6> = DataArray::from_array(*b"ab\0cd\n");
+
+    assert_eq!(std::format!("{:?}", data), "61 62 00 63 64 0A");
+    assert_eq!(std::format!("{:#?}", data), "61 62 00 63 64 0A | ab.cd.");
+    assert_eq!(
+        std::format!("{:#4.2?}", data),
+        "61 62  00 63 | ab.c\n\
+         64 0A        | d.",
+    );
+    assert_eq!(
+        std::format!("{:>+#4?}", data),
+        "00000000: 61 62 00 63 | ab.c\n\
+         00000004:       64 0A |   d.",
+    );
+}
+
+#[test]
+#[cfg(feature = "ufmt")]
+fn ufmt_impls() {
+    struct Text(std::string::String);
+
+    impl ufmt::uWrite for Text {
+        type Error = core::convert::Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    let data: DataArray<3> = DataArray::from_array([0x0A, 0x1B, 0x2C]);
+    let mut text: Text = Text(std::string::String::new());
+
+    ufmt::uwrite!(text, "{:?}", data).unwrap();
+    assert_eq!(text.0, "0A 1B 2C");
+
+    text.0.clear();
+    ufmt::uwrite!(text, "{}", DataBoxed::from_hex("ff00").unwrap()).unwrap();
+    assert_eq!(text.0, "ff00");
+}
+
+#[test]
+fn checksums() {
+    use checksum::{Crc8, Crc16, Crc32};
+
+    const CHECK: &DataSlice = DataSlice::from_slice(b"123456789");
+    const CRC32: u32 = Crc32::ISO_HDLC.compute(CHECK);
+
+    assert_eq!(CRC32, 0xCBF43926);
+    assert_eq!(Crc32::ISCSI.compute(CHECK), 0xE3069283);
+    assert_eq!(Crc16::ARC.compute(CHECK), 0xBB3D);
+    assert_eq!(Crc16::MODBUS.compute(CHECK), 0x4B37);
+    assert_eq!(Crc16::CCITT_FALSE.compute(CHECK), 0x29B1);
+    assert_eq!(Crc16::KERMIT.compute(CHECK), 0x2189);
+    assert_eq!(Crc8::SMBUS.compute(CHECK), 0xF4);
+    assert_eq!(Crc8::MAXIM.compute(CHECK), 0xA1);
+
+    assert_eq!(checksum::fletcher16(DataSlice::from_slice(b"abcde")), 0xC8F0);
+    assert_eq!(checksum::internet(DataSlice::from_slice(&[0x00, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7])), !0xDDF2);
+    assert_eq!(checksum::internet(DataSlice::from_slice(&[0x01])), !0x0100);
+
+    let mut data: DataArray<13> = DataArray::zeroed();
+    data.inner[..9].copy_from_slice(b"123456789");
+    assert_eq!(data.write_crc32_at(9), Ok(0xCBF43926));
+    assert_eq!(data.inner[9..], [0x26, 0x39, 0xF4, 0xCB]);
+    assert_eq!(data.check_crc32_at(9), Ok(true));
+    data.inner[0] = b'0';
+    assert_eq!(data.check_crc32_at(9), Ok(false));
+    assert!(data.write_crc32_at(10).is_err());
+    assert_eq!(Crc32::ISO_HDLC.compute(data.get(1..9).unwrap()), Crc32::ISO_HDLC.compute(DataSlice::from_slice(b"23456789")));
+}
+
+#[test]
+fn region_hashes() {
+    const EMPTY: u64 = DataSlice::from_slice(&[]).hash_fnv1a();
+
+    assert_eq!(EMPTY, 0xCBF29CE484222325);
+    assert_eq!(DataSlice::from_slice(b"a").hash_fnv1a(), 0xAF63DC4C8601EC8C);
+    assert_eq!(DataSlice::from_slice(b"foobar").hash_fnv1a(), 0x85944171F73967E8);
+
+    let data: DataArray<8> = DataArray::from_array(*b"xfoobarx");
+    assert_eq!(data.get(1..7).unwrap().hash_fnv1a(), DataSlice::from_slice(b"foobar").hash_fnv1a());
+    assert_ne!(data.get(0..6).unwrap().hash_fnv1a(), DataSlice::from_slice(b"foobar").hash_fnv1a());
+
+    #[cfg(feature = "xxhash")]
+    {
+        assert_eq!(DataSlice::from_slice(&[]).hash_xx64(0), 0xEF46DB3751D8E999);
+        assert_eq!(data.get(1..7).unwrap().hash_xx64(7), DataSlice::from_slice(b"foobar").hash_xx64(7));
+        assert_ne!(data.hash_xx64(0), data.hash_xx64(1));
+    }
+}
+
+#[test]
+#[cfg(feature = "getrandom")]
+fn random_fill() {
+    let mut data: DataArray<32> = DataArray::zeroed();
+    data.fill_random().unwrap();
+    let first: [u8; 32] = data.inner;
+    data.fill_random().unwrap();
+    // Two 256 bit random values being the same (or zero) is not going to happen.
+    assert_ne!(data.inner, first);
+    assert_ne!(first, [0; 32]);
+
+    let boxed: DataBoxed = DataBoxed::random(32).unwrap();
+    assert_eq!(boxed.size(), 32);
+    assert_ne!(boxed.inner[..], [0; 32]);
+    assert_eq!(DataBoxed::random(0).unwrap().size(), 0);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn rand_fill() {
+    use rand::Fill;
+
+    /// Gives the bytes `seed, seed + 1, ...`, so the output is known.
+    struct Counter(u8);
+
+    impl rand::RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            let mut bytes: [u8; 4] = [0; 4];
+            self.fill_bytes(&mut bytes);
+            u32::from_le_bytes(bytes)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut bytes: [u8; 8] = [0; 8];
+            self.fill_bytes(&mut bytes);
+            u64::from_le_bytes(bytes)
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for byte in dst {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    let mut data: DataArray<4> = DataArray::zeroed();
+    data.fill(&mut Counter(10));
+    assert_eq!(data.inner, [10, 11, 12, 13]);
+
+    let data: DataArray<3> = DataArray::random(&mut Counter(0));
+    assert_eq!(data.inner, [0, 1, 2]);
+
+    let mut rng: Counter = Counter(254);
+    let boxed: DataBoxed = DataBoxed::random_with(3, &mut rng).unwrap();
+    assert_eq!(boxed.inner[..], [254, 255, 0]);
+    assert_eq!(rng.0, 1);
+}
+
+#[test]
+fn secure_erase() {
+    use secure::ZeroizeOnDrop;
+
+    let mut data: DataArray<4> = DataArray::filled(0xAA);
+    data.secure_erase();
+    assert_eq!(data.inner, [0; 4]);
+
+    let mut data: DataArray<4> = DataArray::zeroed();
+    {
+        let mut secret: ZeroizeOnDrop<&mut DataSlice> = ZeroizeOnDrop::new(&mut data);
+        secret.inner.copy_from_slice(b"key!");
+        assert_eq!(secret.inner, *b"key!");
+        assert_eq!(std::format!("{:?}", secret), "ZeroizeOnDrop { size: 4, .. }");
+    }
+    assert_eq!(data.inner, [0; 4]);
+
+    #[cfg(feature = "zeroize")]
+    {
+        use zeroize::Zeroize;
+        use vec::DataVec;
+
+        let mut data: DataArray<4> = DataArray::filled(0xAA);
+        data.zeroize();
+        assert_eq!(data.inner, [0; 4]);
+
+        let mut boxed: DataBoxed = DataBoxed::filled(4, 0xAA).unwrap();
+        boxed.zeroize();
+        assert_eq!(boxed.inner[..], [0; 4]);
+
+        let mut vec: DataVec = DataVec::new();
+        vec.inner.extend_from_slice(b"secret");
+        vec.zeroize();
+        assert_eq!(vec.size(), 0);
+        assert!(vec.inner.capacity() >= 6);
+    }
+}
+
+#[test]
+fn masked_equality() {
+    let captured: DataArray<4> = DataArray::from_array([0x01, 0x2A, 0x00, 0xF0]);
+    let expected: DataArray<4> = DataArray::from_array([0x01, 0x00, 0x00, 0xFF]);
+    // The second byte is a sequence number and the low nibble of the last one is a flag that does not matter.
+    let mask: DataArray<4> = DataArray::from_array([0xFF, 0x00, 0xFF, 0xF0]);
+
+    assert_eq!(captured.eq_masked(&expected, &mask), Ok(true));
+    assert_eq!(captured.eq_masked(&expected, DataSlice::from_slice(&[0xFF; 4])), Ok(false));
+    assert_eq!(captured.eq_masked(&expected, DataSlice::from_slice(&[0x00; 4])), Ok(true));
+
+    assert_eq!(captured.eq_masked(DataSlice::from_slice(&[0x01]), &mask), Err(idx::SizeMismatch { expected: 4, gotten: 1 }));
+    assert_eq!(captured.eq_masked(&expected, DataSlice::from_slice(&[])), Err(idx::SizeMismatch { expected: 4, gotten: 0 }));
+    assert_eq!(
+        Error::from(idx::SizeMismatch { expected: 4, gotten: 1 }),
+        Error::DiferentSizes { expected: 4, gotten: 1 },
+    );
+}
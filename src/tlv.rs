@@ -0,0 +1,223 @@
+
+/*!
+This module provides the [`TlvWriter`] and [`TlvReader`] and all their associated
+functions, methods and items.
+
+TLV (type-length-value) is a framing format where each entry is made of a tag,
+the length of the value and then the value it'self. In this crate both the tag
+and the length are stored as LEB128 variable length integers (go to [`varint`](crate::varint)),
+followed by exactly `length` bytes of value.
+
+Since a value is just a [`DataSlice`], a value can it'self hold more TLV entries,
+those can be written with [`TlvWriter::push_nested`] and read by making a new
+[`TlvReader`] over the value.
+ */
+
+use crate::{
+    idx,
+    cursor::DataCursor,
+    slice::DataSlice,
+    varint::{
+        self,
+        VarintError,
+    },
+};
+
+/// A writer that appends TLV entries to a [`DataSlice`].
+#[must_use]
+pub struct TlvWriter<'data> {
+    cursor: DataCursor<'data>,
+}
+
+impl<'data> TlvWriter<'data> {
+    /// Constructs a new [`TlvWriter`] that starts writing at the begining of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data mut DataSlice) -> TlvWriter<'data> {
+        TlvWriter { cursor: DataCursor::new(data) }
+    }
+
+    /// Get's the amount of bytes written so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Get's the amount of bytes left to be written.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.cursor.remaining()
+    }
+
+    /// Gives back the amount of bytes written.
+    #[inline]
+    pub const fn finish(self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Gives back the underlying slice.
+    #[inline]
+    pub const fn into_inner(self) -> &'data mut DataSlice {
+        self.cursor.into_inner()
+    }
+
+    /// Writes the (already checked to fit) tag and length header at the given position.
+    /// Returns the amount of bytes the header took.
+    fn write_header(&mut self, at: usize, tag: u64, len: usize) -> usize {
+        let data: &mut DataSlice = self.cursor.get_mut();
+        let tag_len: usize = data.write_varint_u64(at, tag).unwrap_or(0);
+        tag_len + data.write_varint_u64(at + tag_len, len as u64).unwrap_or(0)
+    }
+
+    /// Appends an entry with the given tag and value.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the whole entry does not fit in the remaining bytes,
+    /// in which case nothing is written.
+    pub fn push(&mut self, tag: u64, value: &DataSlice) -> Result<(), idx::IdxError> {
+        let size: usize = varint::encoded_len_u64(tag) + varint::encoded_len_u64(value.size() as u64) + value.size();
+
+        let at: usize = self.cursor.claim(size)?;
+        let header: usize = self.write_header(at, tag, value.size());
+        let start: usize = at + header;
+
+        self.cursor.get_mut().inner[start..start + value.size()].copy_from_slice(&value.inner);
+
+        Ok(())
+    }
+
+    /// Appends an entry with the given tag whose value is made of the entries written by `f`.
+    /// 
+    /// Because the length of the value is not known in advance, `f` is only given the space left
+    /// after reserving the biggest possible header, the value is moved back into place once `f` is done.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the header does not fit in the remaining bytes or if `f` returns an error,
+    /// in which case the position of this writer is not moved (but the bytes after it may be changed).
+    pub fn push_nested(&mut self, tag: u64, f: impl FnOnce(&mut TlvWriter<'_>) -> Result<(), idx::IdxError>) -> Result<(), idx::IdxError> {
+        let reserved: usize = varint::encoded_len_u64(tag) + varint::MAX_LEN_U64;
+
+        if reserved > self.remaining() {
+            return Err(idx::IdxError::new(self.position(), self.cursor.get_ref().size(), reserved))
+        }
+
+        let at: usize = self.position();
+        let body_start: usize = at + reserved;
+        let mut nested: TlvWriter<'_> = TlvWriter::new(DataSlice::from_slice_mut(&mut self.cursor.get_mut().inner[body_start..]));
+        f(&mut nested)?;
+        let body_len: usize = nested.finish();
+
+        let header: usize = self.write_header(at, tag, body_len);
+        self.cursor.get_mut().inner.copy_within(body_start..body_start + body_len, at + header);
+        self.cursor.skip(header + body_len)
+    }
+}
+
+impl core::fmt::Debug for TlvWriter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TlvWriter")
+            .field("position", &self.cursor.position())
+            .field("size", &self.cursor.get_ref().size())
+            .finish()
+    }
+}
+
+/// A reader that iterates over the TLV entries of a [`DataSlice`].
+/// 
+/// Each item is the tag and the value of an entry, after the first error the iterator stops.
+#[must_use]
+#[derive(Clone, Copy)]
+pub struct TlvReader<'data> {
+    data: &'data DataSlice,
+    pos: usize,
+    failed: bool,
+}
+
+impl<'data> TlvReader<'data> {
+    /// Constructs a new [`TlvReader`] that starts reading at the begining of the given [`DataSlice`].
+    #[inline]
+    pub const fn new(data: &'data DataSlice) -> TlvReader<'data> {
+        TlvReader { data, pos: 0, failed: false }
+    }
+
+    /// Get's the amount of bytes read so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Reads the next entry.
+    /// 
+    /// Returns [None] once all the data was read.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the header of the entry is malformed
+    /// or if the value gets out of the slice.
+    pub fn read_entry(&mut self) -> Option<Result<(u64, &'data DataSlice), VarintError>> {
+        if self.pos >= self.data.size() {
+            return None
+        }
+
+        let (tag, tag_len) = match self.data.read_varint_u64(self.pos) {
+            Ok(read) => read,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let (len, len_len) = match self.data.read_varint_u64(self.pos + tag_len) {
+            Ok(read) => read,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let start: usize = self.pos + tag_len + len_len;
+
+        if len > usize::MAX as u64 {
+            return Some(Err(VarintError::Overflow))
+        }
+
+        let end: usize = match start.checked_add(len as usize) {
+            Some(end) if end <= self.data.size() => end,
+            _ => return Some(Err(VarintError::OutOfBounds(
+                idx::IdxError::new(start, self.data.size(), len as usize)
+            ))),
+        };
+
+        self.pos = end;
+
+        Some(Ok((tag, DataSlice::from_slice(&self.data.inner[start..end]))))
+    }
+
+    /// Finds the value of the first entry with the given tag.
+    pub fn find(mut self, tag: u64) -> Option<&'data DataSlice> {
+        while let Some(Ok((entry_tag, value))) = self.read_entry() {
+            if entry_tag == tag {
+                return Some(value)
+            }
+        }
+
+        None
+    }
+}
+
+impl<'data> Iterator for TlvReader<'data> {
+    type Item = Result<(u64, &'data DataSlice), VarintError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None
+        }
+
+        let entry = self.read_entry();
+        self.failed = matches!(entry, Some(Err(_)));
+        entry
+    }
+}
+
+impl core::iter::FusedIterator for TlvReader<'_> {}
+
+impl core::fmt::Debug for TlvReader<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TlvReader")
+            .field("position", &self.pos)
+            .field("size", &self.data.size())
+            .finish()
+    }
+}
@@ -0,0 +1,64 @@
+
+/*!
+This module provides helper items for the LEB128 variable length integer
+encoding used by [`DataSlice::write_varint_u64`](crate::slice::DataSlice::write_varint_u64),
+[`DataSlice::read_varint_u64`](crate::slice::DataSlice::read_varint_u64) and their signed
+and cursor counterparts.
+
+Each byte stores 7 bits of the value (least significant group first) and uses the
+most significant bit to mark that more bytes follow, the same way protobuf does.
+Signed values are first mapped to unsigned ones with zig-zag encoding so small negative
+numbers also take few bytes.
+ */
+
+use crate::idx;
+
+/// The maximum amount of bytes a `u64` can take when encoded.
+pub const MAX_LEN_U64: usize = 10;
+
+/// The error given when decoding a variable length integer fails.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VarintError {
+    /// The encoded integer does not end before the end of the data structure.
+    OutOfBounds(idx::IdxError),
+    /// The encoded integer does not fit in 64 bits.
+    Overflow,
+}
+
+impl core::error::Error for VarintError {}
+impl core::fmt::Display for VarintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VarintError::OutOfBounds(err) => core::fmt::Display::fmt(err, f),
+            VarintError::Overflow => f.write_str("The encoded variable length integer does not fit in 64 bits."),
+        }
+    }
+}
+
+impl From<idx::IdxError> for VarintError {
+    #[inline] fn from(err: idx::IdxError) -> VarintError {
+        VarintError::OutOfBounds(err)
+    }
+}
+
+/// Get's the amount of bytes the given value takes when encoded.
+#[inline]
+pub const fn encoded_len_u64(value: u64) -> usize {
+    match value {
+        0 => 1,
+        value => (64 - value.leading_zeros() as usize).div_ceil(7),
+    }
+}
+
+/// Maps a signed integer to an unsigned one so that values close to `0` stay small.
+#[inline]
+pub const fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Reverses [zigzag_encode].
+#[inline]
+pub const fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
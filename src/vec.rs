@@ -0,0 +1,373 @@
+
+/*!
+This module provides the [`DataVec`] data structure and all it's associated
+functions, methods and items.
+
+[`DataVec`] is made to be used when the size of the data structure changes often,
+for example when it is used as an output buffer that keeps getting written to.
+
+Under the surface a [`DataVec`] is just a vector of bytes, so unlike [`DataBoxed`](crate::boxed::DataBoxed)
+it keeps some spare capacity around to make growing cheap.
+ */
+
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::{
+    Allocator,
+    Global,
+};
+
+use crate::alloc::{
+    collections::TryReserveError,
+    vec::Vec,
+};
+use crate::slice::DataSlice;
+
+/// A growable typeless chunk of data.
+/// 
+/// In case the size of the data changes often, like when it is used as a buffer.
+/// 
+/// This struct is just a `Vec<u8>` underneeth the hood.
+#[must_use]
+#[cfg(feature = "allocator_api")]
+pub struct DataVec<A: Allocator = Global> {
+    pub(crate) inner: Vec<u8, A>
+}
+
+/// A growable typeless chunk of data.
+/// 
+/// In case the size of the data changes often, like when it is used as a buffer.
+/// 
+/// This struct is just a `Vec<u8>` underneeth the hood.
+#[must_use]
+#[cfg(not(feature = "allocator_api"))]
+pub struct DataVec {
+    pub(crate) inner: Vec<u8>
+}
+
+impl DataVec {
+    /// Initializes a new [DataVec] without allocating any data.
+    #[inline]
+    pub const fn new() -> DataVec {
+        DataVec { inner: Vec::new() }
+    }
+
+    /// Initializes a new empty [DataVec] with space for at least `capacity` bytes.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation fails.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Result<DataVec, TryReserveError> {
+        let mut data: DataVec = DataVec::new();
+        data.inner.try_reserve_exact(capacity)?;
+        Ok(data)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> DataVec<A> {
+    /// Initializes a new [DataVec] in the given allocator without allocating any data.
+    #[inline]
+    pub const fn new_in(alloc: A) -> DataVec<A> {
+        DataVec { inner: Vec::new_in(alloc) }
+    }
+
+    /// Get's the allocator of the data structure.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
+}
+
+macro_rules! impl_data_vec {
+    (
+        $(
+            $( $attr:meta )*
+            $func:item
+        )*
+    ) => {
+        #[cfg(feature = "allocator_api")]
+        impl<A: Allocator> DataVec<A> {
+            $(
+                $( $attr )*
+                $func
+            )*
+        }
+
+        #[cfg(not(feature = "allocator_api"))]
+        impl DataVec {
+            $(
+                $( $attr )*
+                $func
+            )*
+        }
+    };
+}
+
+impl_data_vec!{
+    #[inline]
+    /// Get's the current size of the data structure.
+    pub const fn size(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    /// Get's the amount of bytes the data structure can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline]
+    /// Reserves space for at least `additional` more bytes.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation fails.
+    pub fn reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    /// Changes the size of the data structure, filling any new bytes with the given byte.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation fails, in which case the size is not changed.
+    pub fn resize(&mut self, size: usize, byte: u8) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(size.saturating_sub(self.inner.len()))?;
+        self.inner.resize(size, byte);
+        Ok(())
+    }
+
+    /// Appends the bytes of the given [`DataSlice`] to the end of the data structure.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the allocation fails, in which case nothing is appended.
+    pub fn extend_from_data(&mut self, data: &DataSlice) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(data.size())?;
+        self.inner.extend_from_slice(&data.inner);
+        Ok(())
+    }
+
+    #[inline]
+    /// Shortens the data structure to the given size, does nothing if it is already shorter.
+    pub fn truncate(&mut self, size: usize) {
+        self.inner.truncate(size)
+    }
+
+    #[inline]
+    /// Removes all the bytes of the data structure, keeping it's capacity.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> DataVec<A> {
+    /// Turns the [DataVec] into a [DataBoxed](crate::boxed::DataBoxed), dropping any spare capacity.
+    #[inline]
+    pub fn into_data_boxed(self) -> crate::boxed::DataBoxed<A> {
+        crate::boxed::DataBoxed { inner: self.inner.into_boxed_slice() }
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl DataVec {
+    /// Turns the [DataVec] into a [DataBoxed](crate::boxed::DataBoxed), dropping any spare capacity.
+    #[inline]
+    pub fn into_data_boxed(self) -> crate::boxed::DataBoxed {
+        crate::boxed::DataBoxed { inner: self.inner.into_boxed_slice() }
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::ops::Deref for DataVec<A> {
+    type Target = crate::slice::DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        crate::slice::DataSlice::from_slice(&self.inner)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::ops::Deref for DataVec {
+    type Target = crate::slice::DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        crate::slice::DataSlice::from_slice(&self.inner)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::ops::DerefMut for DataVec<A> {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        crate::slice::DataSlice::from_slice_mut(&mut self.inner)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::ops::DerefMut for DataVec {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        crate::slice::DataSlice::from_slice_mut(&mut self.inner)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::Debug for DataVec<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::Debug for DataVec {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::Display for DataVec<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::Display for DataVec {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::LowerHex for DataVec<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::LowerHex for DataVec {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::UpperHex for DataVec<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::UpperHex>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::UpperHex for DataVec {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::UpperHex>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> core::fmt::Binary for DataVec<A> {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Binary>::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl core::fmt::Binary for DataVec {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <crate::slice::DataSlice as core::fmt::Binary>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", feature = "allocator_api"))]
+impl<A: Allocator> ufmt::uDebug for DataVec<A> {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDebug>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", not(feature = "allocator_api")))]
+impl ufmt::uDebug for DataVec {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDebug>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", feature = "allocator_api"))]
+impl<A: Allocator> ufmt::uDisplay for DataVec<A> {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "ufmt", not(feature = "allocator_api")))]
+impl ufmt::uDisplay for DataVec {
+    #[inline] fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        <crate::slice::DataSlice as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator + Default> Default for DataVec<A> {
+    #[inline] fn default() -> Self { DataVec::new_in(A::default()) }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl Default for DataVec {
+    #[inline] fn default() -> Self { DataVec::new() }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg(feature = "allocator_api")]
+impl<A: Allocator> zeroize::Zeroize for DataVec<A> {
+    /// Erases the whole capacity (not only the length) with volatile writes,
+    /// leaving the [DataVec] empty like [`Vec`]'s own implementation does.
+    fn zeroize(&mut self) {
+        self.inner.clear();
+
+        for byte in self.inner.spare_capacity_mut() {
+            // SAFETY: The byte is inside of the allocation.
+            unsafe { byte.as_mut_ptr().write_volatile(0x00) }
+        }
+
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg(not(feature = "allocator_api"))]
+impl zeroize::Zeroize for DataVec {
+    /// Erases the whole capacity (not only the length) with volatile writes,
+    /// leaving the [DataVec] empty like [`Vec`]'s own implementation does.
+    fn zeroize(&mut self) {
+        self.inner.clear();
+
+        for byte in self.inner.spare_capacity_mut() {
+            // SAFETY: The byte is inside of the allocation.
+            unsafe { byte.as_mut_ptr().write_volatile(0x00) }
+        }
+
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(all(feature = "std", feature = "allocator_api"))]
+impl<A: Allocator> crate::std::io::Write for DataVec<A> {
+    #[inline] fn write(&mut self, buf: &[u8]) -> crate::std::io::Result<usize> {
+        self.extend_from_data(DataSlice::from_slice(buf))
+            .map_err(|_| crate::std::io::ErrorKind::OutOfMemory)?;
+        Ok(buf.len())
+    }
+
+    #[inline] fn flush(&mut self) -> crate::std::io::Result<()> { Ok(()) }
+}
+
+#[cfg(all(feature = "std", not(feature = "allocator_api")))]
+impl crate::std::io::Write for DataVec {
+    #[inline] fn write(&mut self, buf: &[u8]) -> crate::std::io::Result<usize> {
+        self.extend_from_data(DataSlice::from_slice(buf))
+            .map_err(|_| crate::std::io::ErrorKind::OutOfMemory)?;
+        Ok(buf.len())
+    }
+
+    #[inline] fn flush(&mut self) -> crate::std::io::Result<()> { Ok(()) }
+}
@@ -0,0 +1,180 @@
+
+/*!
+This module provides the [`VolatileData`] data structure and all it's associated
+functions, methods and items.
+
+[`VolatileData`] is made to be used for memory mapped I/O, like the register block
+of a peripheral, where every read and write has to actually happen, in the same order
+and with the same size as written in the code.
+
+Unlike the other data structures [`VolatileData`] does NOT dereference to a
+[`DataSlice`](crate::slice::DataSlice), since any non volatile access to the region
+could be merged, reordered or removed by the compiler, instead it has it's own
+set of methods that compile to [`read_volatile`](core::ptr::read_volatile) and
+[`write_volatile`](core::ptr::write_volatile).
+ */
+
+use crate::idx;
+use core::ptr::NonNull;
+
+/// A typeless chunk of memory that is only ever accessed with volatile operations.
+/// 
+/// This struct does not own the memory, it does nothing when dropped.
+#[must_use]
+pub struct VolatileData {
+    ptr: NonNull<u8>,
+    size: usize,
+}
+
+impl VolatileData {
+    /// Constructs a new [`VolatileData`] over the `size` bytes starting at `base`.
+    /// 
+    /// # Safety
+    /// - Make sure the region is valid for volatile reads and writes for as long as the [`VolatileData`] lives.
+    /// - Make sure the region is not accessed through anything else in a way
+    ///   that breaks the borrowing rules of the [`VolatileData`].
+    #[inline]
+    pub const unsafe fn from_raw_parts(base: *mut u8, size: usize) -> VolatileData {
+        VolatileData {
+            // SAFETY: The caller guarantees the pointer is valid, so not null.
+            ptr: NonNull::new_unchecked(base),
+            size,
+        }
+    }
+
+    /// Get's the size of the region.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get's the base pointer of the region.
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Checks weather `size` bytes starting from `idx` are inside of the region.
+    /// 
+    /// # ERRORS
+    /// Will return an error if they get out of the region.
+    #[inline]
+    pub const fn validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        match idx.checked_add(size) {
+            Some(end) if end <= self.size => Ok(()),
+            _ => Err(idx::IdxError::new(idx, self.size, size)),
+        }
+    }
+
+    /// Reads a byte with a volatile read.
+    /// 
+    /// Returns [None] if the index is out of bounds.
+    #[inline]
+    pub fn read_byte(&self, idx: usize) -> Option<u8> {
+        match self.validity(idx, 1) {
+            // SAFETY: The byte is inside of the region.
+            Ok(()) => Some(unsafe { self.ptr.as_ptr().add(idx).read_volatile() }),
+            Err(_) => None,
+        }
+    }
+
+    /// Writes a byte with a volatile write.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the index is out of bounds.
+    #[inline]
+    pub fn write_byte(&mut self, idx: usize, byte: u8) -> Result<(), idx::IdxError> {
+        self.validity(idx, 1)?;
+
+        // SAFETY: The byte is inside of the region.
+        unsafe { self.ptr.as_ptr().add(idx).write_volatile(byte) }
+        Ok(())
+    }
+
+    /// Reads a value of type `T` at the given index with a single volatile read.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the region.
+    /// 
+    /// # Safety
+    /// - Make sure `base + idx` is aligned for `T`.
+    /// - Make sure the data gotten from inside is a valid T
+    #[inline]
+    pub unsafe fn volatile_read<T: Copy>(&self, idx: usize) -> Result<T, idx::IdxError> {
+        self.validity(idx, core::mem::size_of::<T>())?;
+
+        Ok(self.volatile_read_unchecked(idx))
+    }
+
+    /// Reads a value of type `T` at the given index with a single volatile read,
+    /// without checking the bounds.
+    /// 
+    /// # Safety
+    /// - Make sure the value is fully inside of the region.
+    /// - Make sure `base + idx` is aligned for `T`.
+    /// - Make sure the data gotten from inside is a valid T
+    #[inline]
+    pub unsafe fn volatile_read_unchecked<T: Copy>(&self, idx: usize) -> T {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+        debug_check_align!(self.ptr.as_ptr().add(idx).cast::<T>());
+
+        self.ptr.as_ptr().add(idx).cast::<T>().read_volatile()
+    }
+
+    /// Writes a value of type `T` at the given index with a single volatile write.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the region, in which case nothing is written.
+    /// 
+    /// # Safety
+    /// Make sure `base + idx` is aligned for `T`.
+    #[inline]
+    pub unsafe fn volatile_write<T: Copy>(&mut self, idx: usize, value: T) -> Result<(), idx::IdxError> {
+        self.validity(idx, core::mem::size_of::<T>())?;
+
+        self.volatile_write_unchecked(idx, value);
+        Ok(())
+    }
+
+    /// Writes a value of type `T` at the given index with a single volatile write,
+    /// without checking the bounds.
+    /// 
+    /// # Safety
+    /// - Make sure the value is fully inside of the region.
+    /// - Make sure `base + idx` is aligned for `T`.
+    #[inline]
+    pub unsafe fn volatile_write_unchecked<T: Copy>(&mut self, idx: usize, value: T) {
+        debug_check_bounds!(self, idx, core::mem::size_of::<T>());
+        debug_check_align!(self.ptr.as_ptr().add(idx).cast::<T>());
+
+        self.ptr.as_ptr().add(idx).cast::<T>().write_volatile(value)
+    }
+
+    /// Reads the value of type `T` at the given index, changes it with `f` and writes it back,
+    /// with one volatile read and one volatile write.
+    /// 
+    /// This is NOT atomic, it is just a shorthand for read-modify-write register updates.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the value gets out of the region.
+    /// 
+    /// # Safety
+    /// - Make sure `base + idx` is aligned for `T`.
+    /// - Make sure the data gotten from inside is a valid T
+    #[inline]
+    pub unsafe fn volatile_modify<T: Copy>(&mut self, idx: usize, f: impl FnOnce(T) -> T) -> Result<(), idx::IdxError> {
+        let value: T = self.volatile_read(idx)?;
+
+        self.volatile_write_unchecked(idx, f(value));
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for VolatileData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VolatileData")
+            .field("base", &self.ptr)
+            .field("size", &self.size)
+            .finish()
+    }
+}
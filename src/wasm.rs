@@ -0,0 +1,134 @@
+
+/*!
+This module provides the [`DataWasm`] data structure and all it's associated
+functions, methods and items.
+
+[`DataWasm`] is made to be used for a region of the wasm linear memory that is
+shared with javascript, for example a buffer javascript allocated through an
+exported function and handed back as an offset and a length.
+
+Under the surface a [`DataWasm`] is just a pointer and a size, javascript can
+see the same bytes (without any copying) through [`DataWasm::view`].
+
+## Note
+An [`ArrayBuffer`](js_sys::ArrayBuffer) or [`SharedArrayBuffer`](js_sys::SharedArrayBuffer)
+that was not made from the wasm memory lives outside of it, so it can not be used
+in place, [`DataWasm::copy_from_buffer`] and [`DataWasm::copy_to_buffer`] can be used
+to move the bytes between the two with a single copy.
+ */
+
+use crate::slice::DataSlice;
+use core::ptr::NonNull;
+
+/// A typeless chunk of data in the wasm linear memory that is shared with javascript.
+/// 
+/// This struct does not own the memory, it does nothing when dropped.
+#[must_use]
+pub struct DataWasm {
+    ptr: NonNull<u8>,
+    size: usize,
+}
+
+impl DataWasm {
+    /// Constructs a new [`DataWasm`] over the `size` bytes starting at `ptr`.
+    /// 
+    /// On wasm the pointer is the same as the offset into the linear memory
+    /// javascript sees, so `offset as *mut u8` can be used for offsets given by javascript.
+    /// 
+    /// # Safety
+    /// - Make sure the region is inside the linear memory and is valid for reads and writes.
+    /// - Make sure the region is not used through anything else (javascript included)
+    ///   while the [`DataWasm`] is being used, and that it stays valid for as long
+    ///   as the [`DataWasm`] lives.
+    #[inline]
+    pub const unsafe fn from_raw_parts(ptr: *mut u8, size: usize) -> DataWasm {
+        DataWasm {
+            // SAFETY: The caller guarantees the pointer is valid, so not null.
+            ptr: NonNull::new_unchecked(ptr),
+            size,
+        }
+    }
+
+    /// Get's the size of the region.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get's the offset of the region in the linear memory, to be given to javascript.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.ptr.as_ptr().addr()
+    }
+
+    /// Get's a javascript [`Uint8Array`](js_sys::Uint8Array) that looks at the same bytes as this region.
+    /// 
+    /// # Safety
+    /// The view becomes detached (it's length becomes `0`) if the wasm memory grows,
+    /// so make sure nothing allocates while javascript is using it,
+    /// and that the bytes are not used through the [`DataWasm`] while javascript writes to them.
+    #[inline]
+    pub unsafe fn view(&self) -> js_sys::Uint8Array {
+        js_sys::Uint8Array::view_mut_raw(self.ptr.as_ptr(), self.size)
+    }
+
+    /// Copies the whole given [`ArrayBuffer`](js_sys::ArrayBuffer) or
+    /// [`SharedArrayBuffer`](js_sys::SharedArrayBuffer) into this region.
+    /// 
+    /// # ERRORS
+    /// Will return an error with the sizes of this region and of the buffer
+    /// if they are not the same, in which case nothing is copied.
+    pub fn copy_from_buffer(&mut self, buffer: &js_sys::wasm_bindgen::JsValue) -> Result<(), (usize, usize)> {
+        let array: js_sys::Uint8Array = js_sys::Uint8Array::new(buffer);
+
+        if array.length() as usize != self.size {
+            return Err((self.size, array.length() as usize))
+        }
+
+        array.copy_to(&mut self.inner);
+        Ok(())
+    }
+
+    /// Copies this whole region into the given [`ArrayBuffer`](js_sys::ArrayBuffer) or
+    /// [`SharedArrayBuffer`](js_sys::SharedArrayBuffer).
+    /// 
+    /// # ERRORS
+    /// Will return an error with the sizes of this region and of the buffer
+    /// if they are not the same, in which case nothing is copied.
+    pub fn copy_to_buffer(&self, buffer: &js_sys::wasm_bindgen::JsValue) -> Result<(), (usize, usize)> {
+        let array: js_sys::Uint8Array = js_sys::Uint8Array::new(buffer);
+
+        if array.length() as usize != self.size {
+            return Err((self.size, array.length() as usize))
+        }
+
+        array.copy_from(&self.inner);
+        Ok(())
+    }
+}
+
+impl core::ops::Deref for DataWasm {
+    type Target = DataSlice;
+
+    #[inline] fn deref(&self) -> &Self::Target {
+        DataSlice::from_slice(unsafe {
+            // SAFETY: The region is valid for `size` bytes as guaranteed at construction.
+            core::slice::from_raw_parts(self.ptr.as_ptr(), self.size)
+        })
+    }
+}
+
+impl core::ops::DerefMut for DataWasm {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target {
+        DataSlice::from_slice_mut(unsafe {
+            // SAFETY: The region is valid for `size` bytes as guaranteed at construction.
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.size)
+        })
+    }
+}
+
+impl core::fmt::Debug for DataWasm {
+    #[inline] fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <DataSlice as core::fmt::Debug>::fmt(self, f)
+    }
+}
@@ -0,0 +1,229 @@
+
+/*!
+This module provides the [`WatchedData`] data structure and all it's associated
+functions, methods and items.
+
+[`WatchedData`] wraps any [`RawDataStructure`] and calls back the watchers registered
+for a range of bytes after every write that overlaps it, with the region that was written,
+so a write that keeps clobbering some offset can be caught (eg: with a breakpoint in the callback)
+and dirty tracking can be built on top of it.
+
+Since the [`RawDataStructure`] methods that give out a mutable pointer can not know when (or if)
+it is written through, giving one out counts as a write of the region it points to, and the watchers
+are called right before it is given out. For an unsized value the size is not known from it's pointer,
+so the region is everything from it's index to the end of the data structure, except for the
+[`SliceLike`](crate::SliceLike) values whose size is known from their length.
+The data structure can not be sliced mutably, since that would get around the watchers.
+ */
+
+use crate::{
+    idx::{
+        self,
+        Idx,
+    },
+    RawDataStructure,
+};
+use crate::alloc::{
+    boxed::Box,
+    vec::Vec,
+};
+
+/// The handle of a watcher of a [`WatchedData`], used to remove it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WatchId(usize);
+
+/// A range of bytes and the callback called after it is written to.
+struct Watch<'a> {
+    id: WatchId,
+    range: core::ops::Range<usize>,
+    callback: Box<dyn FnMut(usize, usize) + 'a>,
+}
+
+/// A data structure that calls back watchers after the ranges they watch are written to.
+/// 
+/// Go to the [module level documentation](self) for more details.
+#[must_use]
+pub struct WatchedData<'a, D: RawDataStructure> {
+    data: D,
+    watches: Vec<Watch<'a>>,
+    next: usize,
+}
+
+impl<'a, D: RawDataStructure> WatchedData<'a, D> {
+    /// Constructs a new [`WatchedData`] with no watchers over the given data structure.
+    #[inline]
+    pub fn new(data: D) -> WatchedData<'a, D> {
+        WatchedData { data, watches: Vec::new(), next: 0 }
+    }
+
+    /// Registers the callback to be called after every write that overlaps the range,
+    /// with the index and the size of the region that was written.
+    /// 
+    /// # ERRORS
+    /// Will return an error if the range is not valid for the data structure.
+    pub fn watch(&mut self, range: impl Idx, callback: impl FnMut(usize, usize) + 'a) -> Result<WatchId, idx::IdxError> {
+        let range: core::ops::Range<usize> = range.resolve(self.data.size())?;
+        let id: WatchId = WatchId(self.next);
+
+        self.next += 1;
+        self.watches.push(Watch { id, range, callback: Box::new(callback) });
+        Ok(id)
+    }
+
+    /// Removes the watcher with the given handle.
+    /// 
+    /// Will return false if there is no such watcher.
+    pub fn unwatch(&mut self, id: WatchId) -> bool {
+        match self.watches.iter().position(#[inline] |watch| watch.id == id) {
+            Some(at) => {
+                self.watches.remove(at);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Get's the amount of registered watchers.
+    #[inline]
+    pub fn watchers(&self) -> usize {
+        self.watches.len()
+    }
+
+    /// Get's the watched data structure, which can only be read through this.
+    #[inline]
+    pub fn get_ref(&self) -> &D {
+        &self.data
+    }
+
+    /// Gives back the underlying data structure, removing all the watchers.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    /// Calls back the watchers of the ranges that overlap the written region.
+    fn notify(&mut self, idx: usize, size: usize) {
+        let end: usize = idx.saturating_add(size);
+
+        for watch in self.watches.iter_mut() {
+            if size != 0 && idx < watch.range.end && watch.range.start < end {
+                (watch.callback)(idx, size);
+            }
+        }
+    }
+}
+
+unsafe impl<D: RawDataStructure> RawDataStructure for WatchedData<'_, D> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.data.size()
+    }
+
+    #[inline]
+    fn read_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        self.data.read_validity(idx, size)
+    }
+
+    #[inline]
+    fn write_validity(&self, idx: usize, size: usize) -> Result<(), idx::IdxError> {
+        self.data.write_validity(idx, size)
+    }
+
+    unsafe fn write_zeroes_unchecked(&mut self, idx: usize, size: usize) {
+        self.data.write_zeroes_unchecked(idx, size);
+        self.notify(idx, size);
+    }
+
+    unsafe fn write_ones_unchecked(&mut self, idx: usize, size: usize) {
+        self.data.write_ones_unchecked(idx, size);
+        self.notify(idx, size);
+    }
+
+    unsafe fn write_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>) {
+        let size: usize = core::mem::size_of_val(&*value);
+
+        self.data.write_unsized_unchecked(idx, value);
+        self.notify(idx, size);
+    }
+
+    #[inline]
+    unsafe fn read_unchecked<T: Sized>(&self, idx: usize) -> *const T {
+        self.data.read_unchecked(idx)
+    }
+
+    unsafe fn read_mut_unchecked<T: Sized>(&mut self, idx: usize) -> *mut T {
+        self.notify(idx, core::mem::size_of::<T>());
+        self.data.read_mut_unchecked(idx)
+    }
+
+    #[inline]
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_unchecked<T: ?Sized + core::ptr::Pointee>(&self, idx: usize, meta: T::Metadata) -> *const T {
+        self.data.read_unsized_unchecked(idx, meta)
+    }
+
+    #[cfg(feature = "ptr_metadata")]
+    unsafe fn read_unsized_mut_unchecked<T: ?Sized + core::ptr::Pointee>(&mut self, idx: usize, meta: T::Metadata) -> *mut T {
+        self.notify(idx, self.data.size().saturating_sub(idx));
+        self.data.read_unsized_mut_unchecked(idx, meta)
+    }
+
+    fn read_slice_like_mut<T: ?Sized + crate::SliceLike>(&mut self, idx: usize, len: usize) -> Result<*mut T, idx::IdxError>
+    where usize: crate::GetSizeOf<T> {
+        let ptr: *mut T = self.data.read_slice_like_mut::<T>(idx, len)?;
+
+        self.notify(idx, crate::GetSizeOf::<T>::size(&len));
+        Ok(ptr)
+    }
+
+    #[inline]
+    unsafe fn take_unchecked<T: Sized>(&self, idx: usize) -> T {
+        self.data.take_unchecked(idx)
+    }
+
+    unsafe fn replace_unsized_unchecked<T: ?Sized>(&mut self, idx: usize, value: *const core::mem::ManuallyDrop<T>, out: *mut T) {
+        let size: usize = core::mem::size_of_val(&*value);
+
+        self.data.replace_unsized_unchecked(idx, value, out);
+        self.notify(idx, size);
+    }
+
+    unsafe fn clone_from_unchecked(&mut self, data: &Self) {
+        self.data.clone_from_unchecked(&data.data);
+        self.notify(0, self.data.size());
+    }
+
+    unsafe fn copy_range_from_unchecked<S: RawDataStructure<DataByte = Self::DataByte> + ?Sized>(&mut self, idx: usize, data: &S, data_idx: usize, size: usize) {
+        self.data.copy_range_from_unchecked(idx, data, data_idx, size);
+        self.notify(idx, size);
+    }
+
+    unsafe fn write_vectored(&mut self, idx: usize, bufs: &[&crate::slice::DataSlice]) -> Result<usize, idx::IdxError> {
+        let size: usize = self.data.write_vectored(idx, bufs)?;
+
+        self.notify(idx, size);
+        Ok(size)
+    }
+
+    type DataByte = D::DataByte;
+
+    #[inline]
+    unsafe fn get_at_idx(&self, idx: usize) -> Self::DataByte {
+        self.data.get_at_idx(idx)
+    }
+
+    unsafe fn set_at_idx(&mut self, idx: usize, byte: Self::DataByte) {
+        self.data.set_at_idx(idx, byte);
+        self.notify(idx, 1);
+    }
+}
+
+impl<D: RawDataStructure + core::fmt::Debug> core::fmt::Debug for WatchedData<'_, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WatchedData")
+            .field("data", &self.data)
+            .field("watchers", &self.watches.len())
+            .finish()
+    }
+}